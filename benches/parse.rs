@@ -0,0 +1,119 @@
+//! Benchmarks for parsing networks, comparing the single-pass parser against
+//! the previous `split`-and-`collect` implementation.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use netaddr2::{NetAddr, Netv4Addr, Netv6Addr};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The implementations of `FromStr` as they were before the single-pass parser.
+mod legacy {
+	use super::*;
+
+	pub fn netv4addr(string: &str) -> Option<Netv4Addr> {
+		let split: Vec<&str> = string.split(['/', ' ']).collect();
+
+		let lhs: &str = split[0];
+		let rhs: &str = split.get(1)?;
+
+		let address = lhs.parse::<Ipv4Addr>();
+		let cidr = rhs.parse::<u32>();
+		let right_addr = rhs.parse::<Ipv4Addr>();
+
+		match (address, cidr, right_addr) {
+			(Ok(addr), Ok(cidr), _) => {
+				let mask: u32 = u32::MAX ^ u32::MAX.checked_shr(cidr).unwrap_or(0_u32);
+				Some(Netv4Addr::new(addr, mask.into()))
+			}
+			(Ok(addr), Err(_), Ok(mask)) => Some(Netv4Addr::new(addr, mask)),
+			(Ok(addr), Err(_), Err(_)) => Some(Netv4Addr::from(addr)),
+			(Err(_), _, _) => None,
+		}
+	}
+
+	pub fn netv6addr(string: &str) -> Option<Netv6Addr> {
+		let split: Vec<&str> = string.split(['/', ' ']).collect();
+
+		let lhs: &str = split[0];
+		let rhs: &str = split.get(1)?;
+
+		let address = lhs.parse::<Ipv6Addr>();
+		let cidr = rhs.parse::<u32>();
+		let right_addr = rhs.parse::<Ipv6Addr>();
+
+		match (address, cidr, right_addr) {
+			(Ok(addr), Ok(cidr), _) => {
+				let mask: u128 = u128::MAX ^ u128::MAX.checked_shr(cidr).unwrap_or(0_u128);
+				Some(Netv6Addr::new(addr, mask.into()))
+			}
+			(Ok(addr), Err(_), Ok(mask)) => Some(Netv6Addr::new(addr, mask)),
+			(Ok(addr), Err(_), Err(_)) => Some(Netv6Addr::from(addr)),
+			(Err(_), _, _) => None,
+		}
+	}
+
+	pub fn netaddr(string: &str) -> Option<NetAddr> {
+		match (netv4addr(string), netv6addr(string)) {
+			(Some(v4), _) => Some(NetAddr::V4(v4)),
+			(_, Some(v6)) => Some(NetAddr::V6(v6)),
+			(None, None) => None,
+		}
+	}
+}
+
+const INPUTS: &[(&str, &str)] = &[
+	("v4_cidr", "203.0.113.0/24"),
+	("v4_extended", "203.0.113.0 255.255.255.0"),
+	("v6_cidr", "2001:db8:dead:beef::/64"),
+	("v6_extended", "2001:db8:dead:beef::/ffff:ffff:ffff:ffff::"),
+];
+
+fn netaddr(c: &mut Criterion) {
+	let mut group = c.benchmark_group("NetAddr");
+
+	for (name, input) in INPUTS {
+		group.bench_with_input(BenchmarkId::new("legacy", name), input, |b, input| {
+			b.iter(|| legacy::netaddr(black_box(input)))
+		});
+		group.bench_with_input(BenchmarkId::new("from_str", name), input, |b, input| {
+			b.iter(|| black_box(input).parse::<NetAddr>())
+		});
+		group.bench_with_input(BenchmarkId::new("parse_ascii", name), input, |b, input| {
+			b.iter(|| NetAddr::parse_ascii(black_box(input.as_bytes())))
+		});
+	}
+
+	group.finish();
+}
+
+fn netv4addr(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Netv4Addr");
+
+	for (name, input) in INPUTS.iter().filter(|(name, _)| name.starts_with("v4")) {
+		group.bench_with_input(BenchmarkId::new("legacy", name), input, |b, input| {
+			b.iter(|| legacy::netv4addr(black_box(input)))
+		});
+		group.bench_with_input(BenchmarkId::new("from_str", name), input, |b, input| {
+			b.iter(|| black_box(input).parse::<Netv4Addr>())
+		});
+	}
+
+	group.finish();
+}
+
+fn netv6addr(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Netv6Addr");
+
+	for (name, input) in INPUTS.iter().filter(|(name, _)| name.starts_with("v6")) {
+		group.bench_with_input(BenchmarkId::new("legacy", name), input, |b, input| {
+			b.iter(|| legacy::netv6addr(black_box(input)))
+		});
+		group.bench_with_input(BenchmarkId::new("from_str", name), input, |b, input| {
+			b.iter(|| black_box(input).parse::<Netv6Addr>())
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, netaddr, netv4addr, netv6addr);
+criterion_main!(benches);
@@ -0,0 +1,332 @@
+//! Ordered lists of allow and deny rules matching packets by their source and
+//! destination addresses.
+
+use crate::{Contains, NetAddr};
+use core::iter::FromIterator;
+use core::net::IpAddr;
+
+/// What to do with a packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+	/// Let the packet through.
+	Allow,
+	/// Drop the packet.
+	Deny,
+}
+
+/// A rule matching packets by their source and destination addresses.
+///
+/// A side without a network matches any address, of either family.
+///
+/// # Parsing and display
+///
+/// A rule is written as its action, `allow` or `deny`, optionally followed by
+/// `from` and a source and `to` and a destination.  Each of those is a network,
+/// a single address, or `any`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Action, Rule};
+/// let rule: Rule = "deny from 10.0.0.0/8 to 192.0.2.1".parse().unwrap();
+///
+/// assert_eq!(rule.action(), Action::Deny);
+/// assert!(rule.matches("10.1.2.3".parse().unwrap(), "192.0.2.1".parse().unwrap()));
+/// assert!(!rule.matches("10.1.2.3".parse().unwrap(), "192.0.2.2".parse().unwrap()));
+/// assert_eq!(rule.to_string(), "deny from 10.0.0.0/8 to 192.0.2.1/32");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rule {
+	action: Action,
+	source: Option<NetAddr>,
+	destination: Option<NetAddr>,
+}
+
+impl Rule {
+	/// Create a rule taking `action` on every packet.
+	pub const fn new(action: Action) -> Self {
+		Self {
+			action,
+			source: None,
+			destination: None,
+		}
+	}
+
+	/// Create a rule allowing every packet.
+	pub const fn allow() -> Self {
+		Self::new(Action::Allow)
+	}
+
+	/// Create a rule denying every packet.
+	pub const fn deny() -> Self {
+		Self::new(Action::Deny)
+	}
+
+	/// Restrict to packets from `source`.
+	pub fn with_source<N: Into<NetAddr>>(self, source: N) -> Self {
+		Self {
+			source: Some(source.into()),
+			..self
+		}
+	}
+
+	/// Restrict to packets to `destination`.
+	pub fn with_destination<N: Into<NetAddr>>(self, destination: N) -> Self {
+		Self {
+			destination: Some(destination.into()),
+			..self
+		}
+	}
+
+	/// Get the action taken on matching packets.
+	pub const fn action(&self) -> Action {
+		self.action
+	}
+
+	/// Get the source network, or `None` for any source.
+	pub const fn source(&self) -> Option<NetAddr> {
+		self.source
+	}
+
+	/// Get the destination network, or `None` for any destination.
+	pub const fn destination(&self) -> Option<NetAddr> {
+		self.destination
+	}
+
+	/// Determine whether a packet from `source` to `destination` matches this
+	/// rule.
+	pub fn matches(&self, source: IpAddr, destination: IpAddr) -> bool {
+		self.source.is_none_or(|net| net.contains(&source))
+			&& self
+				.destination
+				.is_none_or(|net| net.contains(&destination))
+	}
+
+	/// Determine whether some packet matches both this rule and `other`.
+	pub fn overlaps(&self, other: &Self) -> bool {
+		let side = |a: Option<NetAddr>, b: Option<NetAddr>| match (a, b) {
+			(Some(a), Some(b)) => a.overlaps(&b),
+			(_, _) => true,
+		};
+
+		side(self.source, other.source) && side(self.destination, other.destination)
+	}
+}
+
+impl Contains<Rule> for Rule {
+	/// Determine whether every packet matching `other` matches this rule,
+	/// regardless of their actions.
+	fn contains(&self, other: &Rule) -> bool {
+		let side = |a: Option<NetAddr>, b: Option<NetAddr>| match (a, b) {
+			(None, _) => true,
+			(Some(a), Some(b)) => a.contains(&b),
+			// No single network covers both families.
+			(Some(_), None) => false,
+		};
+
+		side(self.source, other.source) && side(self.destination, other.destination)
+	}
+}
+
+/// The outcome of evaluating an [`Acl`] for a packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Verdict {
+	action: Action,
+	rule: Option<usize>,
+}
+
+impl Verdict {
+	/// Get the action taken on the packet.
+	pub const fn action(&self) -> Action {
+		self.action
+	}
+
+	/// Get the index of the rule which matched the packet, or `None` if no rule
+	/// did and the default action was taken.
+	pub const fn rule(&self) -> Option<usize> {
+		self.rule
+	}
+}
+
+/// An ordered list of rules, where the first rule matching a packet decides
+/// what happens to it, and a default action for packets matching no rule.
+///
+/// Evaluating an `Acl` checks each rule in turn; for many rules, [`compile`]
+/// it into a [`CompiledAcl`], which finds the first match without checking
+/// every rule.  [`lint`] finds rules which never match, which could be
+/// removed without changing any outcome, or whose order matters.
+///
+/// # Parsing and display
+///
+/// An `Acl` is written as one [`Rule`] per line, and a `default` line with the
+/// default action, which is `deny` if there is no such line.  Blank lines and
+/// lines starting with `#` are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Acl, Action};
+/// let acl: Acl = "
+///     deny from 10.0.0.0/24
+///     allow from 10.0.0.0/8 to 192.0.2.0/24
+///     default deny
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// let verdict = acl.evaluate("10.1.2.3".parse().unwrap(), "192.0.2.1".parse().unwrap());
+/// assert_eq!(verdict.action(), Action::Allow);
+/// assert_eq!(verdict.rule(), Some(1));
+///
+/// let verdict = acl.evaluate("10.0.0.1".parse().unwrap(), "192.0.2.1".parse().unwrap());
+/// assert_eq!(verdict.action(), Action::Deny);
+/// assert_eq!(verdict.rule(), Some(0));
+/// ```
+///
+/// [`compile`]: Acl::compile
+/// [`lint`]: Acl::lint
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Acl {
+	rules: Vec<Rule>,
+	default: Action,
+}
+
+impl Acl {
+	/// Create an `Acl` with no rules, taking `default` on every packet.
+	pub const fn new(default: Action) -> Self {
+		Self {
+			rules: Vec::new(),
+			default,
+		}
+	}
+
+	/// Add `rule` after the existing rules.
+	pub fn push(&mut self, rule: Rule) {
+		self.rules.push(rule);
+	}
+
+	/// Get the rules, in order.
+	pub fn rules(&self) -> &[Rule] {
+		&self.rules
+	}
+
+	/// Get the action taken on packets matching no rule.
+	pub const fn default_action(&self) -> Action {
+		self.default
+	}
+
+	/// Decide what happens to a packet from `source` to `destination`, by
+	/// checking each rule in turn.
+	pub fn evaluate(&self, source: IpAddr, destination: IpAddr) -> Verdict {
+		match self
+			.rules
+			.iter()
+			.position(|rule| rule.matches(source, destination))
+		{
+			Some(index) => Verdict {
+				action: self.rules[index].action,
+				rule: Some(index),
+			},
+			None => Verdict {
+				action: self.default,
+				rule: None,
+			},
+		}
+	}
+}
+
+impl Default for Acl {
+	/// Create an `Acl` with no rules, denying every packet.
+	fn default() -> Self {
+		Self::new(Action::Deny)
+	}
+}
+
+impl FromIterator<Rule> for Acl {
+	/// Collect rules into an `Acl` denying packets which match none of them.
+	fn from_iter<I: IntoIterator<Item = Rule>>(iter: I) -> Self {
+		Self {
+			rules: iter.into_iter().collect(),
+			default: Action::Deny,
+		}
+	}
+}
+
+impl Extend<Rule> for Acl {
+	fn extend<I: IntoIterator<Item = Rule>>(&mut self, iter: I) {
+		self.rules.extend(iter);
+	}
+}
+
+mod compiled;
+mod display;
+mod fromstr;
+mod lint;
+
+pub use compiled::*;
+pub use lint::*;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ip(addr: &str) -> IpAddr {
+		addr.parse().unwrap()
+	}
+
+	#[test]
+	fn rules_match() {
+		let rule = Rule::allow().with_source(pu!("10.0.0.0/8" # NetAddr));
+
+		assert!(rule.matches(ip("10.1.2.3"), ip("::1")));
+		assert!(!rule.matches(ip("11.1.2.3"), ip("::1")));
+		assert!(Rule::deny().matches(ip("::1"), ip("192.0.2.1")));
+	}
+
+	#[test]
+	fn rules_contain() {
+		let any = Rule::allow();
+		let net = Rule::deny().with_source(pu!("10.0.0.0/8" # NetAddr));
+		let subnet = Rule::deny()
+			.with_source(pu!("10.1.0.0/16" # NetAddr))
+			.with_destination(pu!("192.0.2.0/24" # NetAddr));
+
+		assert!(any.contains(&net) && any.contains(&subnet));
+		assert!(net.contains(&subnet) && !subnet.contains(&net));
+		assert!(!net.contains(&any));
+		assert!(net.overlaps(&subnet) && net.overlaps(&any));
+		assert!(!net.overlaps(&Rule::deny().with_source(pu!("::/0" # NetAddr))));
+	}
+
+	#[test]
+	fn first_match_wins() {
+		let acl: Acl = vec![
+			Rule::deny().with_source(pu!("10.0.0.0/24" # NetAddr)),
+			Rule::allow().with_source(pu!("10.0.0.0/8" # NetAddr)),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(
+			acl.evaluate(ip("10.0.0.1"), ip("192.0.2.1")),
+			Verdict {
+				action: Action::Deny,
+				rule: Some(0)
+			}
+		);
+		assert_eq!(
+			acl.evaluate(ip("10.0.1.1"), ip("192.0.2.1")),
+			Verdict {
+				action: Action::Allow,
+				rule: Some(1)
+			}
+		);
+		assert_eq!(
+			acl.evaluate(ip("192.0.2.1"), ip("10.0.0.1")),
+			Verdict {
+				action: Action::Deny,
+				rule: None
+			}
+		);
+	}
+}
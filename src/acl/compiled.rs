@@ -0,0 +1,294 @@
+use super::{Acl, Action, Rule, Verdict};
+use crate::NetAddr;
+use core::net::IpAddr;
+
+/// A binary trie of prefixes, holding at each prefix the indices of the rules
+/// whose source is that prefix, in increasing order.
+#[derive(Clone, Debug)]
+struct PrefixTrie {
+	bits: u32,
+	nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+	children: [Option<usize>; 2],
+	rules: Vec<usize>,
+}
+
+impl PrefixTrie {
+	fn new(bits: u32) -> Self {
+		Self {
+			bits,
+			nodes: vec![Node::default()],
+		}
+	}
+
+	/// Add `rule` at the prefix of the top `len` bits of `addr`.
+	fn insert(&mut self, addr: u128, len: u32, rule: usize) {
+		let mut node: usize = 0;
+
+		for depth in 0..len {
+			let bit: usize = ((addr >> (self.bits - 1 - depth)) & 1) as usize;
+
+			node = match self.nodes[node].children[bit] {
+				Some(child) => child,
+				None => {
+					self.nodes.push(Node::default());
+					let child: usize = self.nodes.len() - 1;
+					self.nodes[node].children[bit] = Some(child);
+					child
+				}
+			};
+		}
+
+		self.nodes[node].rules.push(rule);
+	}
+
+	/// Iterate over the rules at each prefix containing `addr`, from the
+	/// shortest prefix to the longest.
+	fn lookup(&self, addr: u128) -> impl Iterator<Item = &[usize]> + '_ {
+		let mut node: Option<usize> = Some(0);
+		let mut depth: u32 = 0;
+
+		core::iter::from_fn(move || {
+			let current: &Node = &self.nodes[node?];
+
+			node = if depth < self.bits {
+				current.children[((addr >> (self.bits - 1 - depth)) & 1) as usize]
+			} else {
+				None
+			};
+			depth += 1;
+
+			Some(current.rules.as_slice())
+		})
+	}
+}
+
+/// An [`Acl`] indexed for evaluating many packets.
+///
+/// Rules are indexed by the prefix of their source network in a binary trie,
+/// so evaluating a packet only checks the destinations of rules whose source
+/// contains the packet's source, plus rules with non-contiguous source masks,
+/// which cannot be indexed by prefix.  This always gives the same [`Verdict`]
+/// as [`Acl::evaluate`].
+///
+/// Created by [`Acl::compile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Acl, Action};
+/// let acl: Acl = "allow from 10.0.0.0/8 to 192.0.2.0/24".parse().unwrap();
+/// let compiled = acl.compile();
+///
+/// let verdict = compiled.evaluate("10.1.2.3".parse().unwrap(), "192.0.2.1".parse().unwrap());
+/// assert_eq!(verdict.action(), Action::Allow);
+/// assert_eq!(verdict.rule(), Some(0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompiledAcl {
+	rules: Vec<Rule>,
+	default: Action,
+	v4: PrefixTrie,
+	v6: PrefixTrie,
+	unindexed: Vec<usize>,
+}
+
+impl CompiledAcl {
+	/// Decide what happens to a packet from `source` to `destination`.
+	pub fn evaluate(&self, source: IpAddr, destination: IpAddr) -> Verdict {
+		let (trie, addr): (&PrefixTrie, u128) = match source {
+			IpAddr::V4(addr) => (&self.v4, u32::from(addr).into()),
+			IpAddr::V6(addr) => (&self.v6, addr.into()),
+		};
+
+		// Each list is in increasing order, so only its first match can be the
+		// first match overall.
+		let mut first: Option<usize> = None;
+		for rules in trie
+			.lookup(addr)
+			.chain(core::iter::once(self.unindexed.as_slice()))
+		{
+			for &index in rules {
+				if first.is_some_and(|first| index >= first) {
+					break;
+				}
+
+				if self.rules[index].matches(source, destination) {
+					first = Some(index);
+					break;
+				}
+			}
+		}
+
+		match first {
+			Some(index) => Verdict {
+				action: self.rules[index].action,
+				rule: Some(index),
+			},
+			None => Verdict {
+				action: self.default,
+				rule: None,
+			},
+		}
+	}
+
+	/// Get the rules, in order.
+	pub fn rules(&self) -> &[Rule] {
+		&self.rules
+	}
+}
+
+impl Acl {
+	/// Index the rules for evaluating many packets.
+	pub fn compile(&self) -> CompiledAcl {
+		let mut compiled = CompiledAcl {
+			rules: self.rules.clone(),
+			default: self.default,
+			v4: PrefixTrie::new(32),
+			v6: PrefixTrie::new(128),
+			unindexed: Vec::new(),
+		};
+
+		for (index, rule) in self.rules.iter().enumerate() {
+			match rule.source {
+				None => {
+					compiled.v4.insert(0, 0, index);
+					compiled.v6.insert(0, 0, index);
+				}
+				Some(NetAddr::V4(net)) if net.is_cidr() => {
+					let len: u32 = u32::from(net.mask()).leading_ones();
+					compiled.v4.insert(u32::from(net.addr()).into(), len, index);
+				}
+				Some(NetAddr::V6(net)) if net.is_cidr() => {
+					let len: u32 = u128::from(net.mask()).leading_ones();
+					compiled.v6.insert(net.addr().into(), len, index);
+				}
+				Some(_) => compiled.unindexed.push(index),
+			}
+		}
+
+		compiled
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use proptest::prelude::*;
+
+	fn ip(addr: &str) -> IpAddr {
+		addr.parse().unwrap()
+	}
+
+	#[test]
+	fn trie_lookup() {
+		let mut trie = PrefixTrie::new(32);
+		trie.insert(0, 0, 0);
+		trie.insert(0x0a00_0000, 8, 1);
+		trie.insert(0x0a01_0000, 16, 2);
+		trie.insert(0x0a00_0000, 8, 3);
+
+		let found: Vec<&[usize]> = trie.lookup(0x0a01_0203).collect();
+		assert_eq!(
+			found,
+			vec![
+				&[0][..],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[1, 3],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[],
+				&[2]
+			]
+		);
+
+		// The path shares seven bits with 10.0.0.0/8 before leaving the trie.
+		let found: Vec<&[usize]> = trie.lookup(0x0b00_0000).collect();
+		assert_eq!(found, vec![&[0][..], &[], &[], &[], &[], &[], &[], &[]]);
+	}
+
+	#[test]
+	fn first_match_wins() {
+		let acl: Acl = "
+			deny from 10.0.0.0/255.0.255.0
+			allow from 10.0.0.0/8 to 192.0.2.0/24
+			deny to 192.0.2.1
+			allow from 10.1.0.0/16
+			allow
+		"
+		.parse()
+		.unwrap();
+		let compiled = acl.compile();
+
+		for (source, destination, rule) in &[
+			("10.0.0.1", "192.0.2.1", Some(0)),
+			("10.1.1.1", "192.0.2.1", Some(1)),
+			("11.1.0.1", "192.0.2.1", Some(2)),
+			("10.1.1.1", "198.51.100.1", Some(3)),
+			("::1", "::1", Some(4)),
+		] {
+			let verdict = compiled.evaluate(ip(source), ip(destination));
+			assert_eq!(verdict.rule(), *rule);
+			assert_eq!(verdict, acl.evaluate(ip(source), ip(destination)));
+		}
+	}
+
+	#[test]
+	fn default_action() {
+		let acl: Acl = "allow from 10.0.0.0/8\ndefault deny".parse().unwrap();
+
+		assert_eq!(
+			acl.compile().evaluate(ip("::1"), ip("::1")),
+			Verdict {
+				action: Action::Deny,
+				rule: None
+			}
+		);
+	}
+
+	fn rule() -> impl Strategy<Value = Rule> {
+		(
+			prop_oneof![Just(Action::Allow), Just(Action::Deny)],
+			proptest::option::of(strategy::netaddr()),
+			proptest::option::of(strategy::netaddr()),
+		)
+			.prop_map(|(action, source, destination)| Rule {
+				action,
+				source,
+				destination,
+			})
+	}
+
+	proptest! {
+		#[test]
+		fn agrees_with_linear_evaluation(
+			rules in proptest::collection::vec(rule(), 0..16),
+			packets in proptest::collection::vec((any::<IpAddr>(), any::<IpAddr>()), 16),
+		) {
+			let acl: Acl = rules.iter().copied().collect();
+			let compiled = acl.compile();
+
+			// Check packets from within the rules as well as random ones, which
+			// rarely match anything.
+			let inside = rules.iter().filter_map(|rule| Some((rule.source?.addr(), rule.destination?.addr())));
+
+			for (source, destination) in packets.into_iter().chain(inside) {
+				prop_assert_eq!(compiled.evaluate(source, destination), acl.evaluate(source, destination));
+			}
+		}
+	}
+}
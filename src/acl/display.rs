@@ -0,0 +1,77 @@
+use super::{Acl, Action, Rule};
+use core::fmt;
+
+impl fmt::Display for Action {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Allow => f.write_str("allow"),
+			Self::Deny => f.write_str("deny"),
+		}
+	}
+}
+
+impl fmt::Display for Rule {
+	/// Write the rule as its action, followed by its source and destination
+	/// unless they match any address.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.action)?;
+
+		if let Some(source) = self.source {
+			write!(f, " from {}", source)?;
+		}
+
+		if let Some(destination) = self.destination {
+			write!(f, " to {}", destination)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for Acl {
+	/// Write each rule on its own line, followed by the default action.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for rule in &self.rules {
+			writeln!(f, "{}", rule)?;
+		}
+
+		writeln!(f, "default {}", self.default)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NetAddr;
+
+	#[test]
+	fn rules() {
+		assert_eq!(Rule::allow().to_string(), "allow");
+		assert_eq!(
+			Rule::deny()
+				.with_destination(pu!("2001:db8::/32" # NetAddr))
+				.to_string(),
+			"deny to 2001:db8::/32"
+		);
+		assert_eq!(
+			Rule::allow()
+				.with_source(pu!("10.0.0.0/8" # NetAddr))
+				.with_destination(pu!("192.0.2.0/24" # NetAddr))
+				.to_string(),
+			"allow from 10.0.0.0/8 to 192.0.2.0/24"
+		);
+	}
+
+	#[test]
+	fn acls() {
+		let mut acl = Acl::new(Action::Allow);
+		acl.push(Rule::deny().with_source(pu!("10.0.0.0/8" # NetAddr)));
+		acl.push(Rule::deny().with_source(pu!("::/0" # NetAddr)));
+
+		assert_eq!(
+			acl.to_string(),
+			"deny from 10.0.0.0/8\ndeny from ::/0\ndefault allow\n"
+		);
+		assert_eq!(Acl::default().to_string(), "default deny\n");
+	}
+}
@@ -0,0 +1,165 @@
+use super::{Acl, Action, Rule};
+use crate::{Error, NetAddr, Result};
+use core::net::IpAddr;
+use core::str::FromStr;
+
+impl FromStr for Action {
+	type Err = Error;
+
+	/// Parse an `Action` from `allow` or `deny`, in any case.
+	fn from_str(string: &str) -> Result<Self> {
+		if string.eq_ignore_ascii_case("allow") {
+			Ok(Self::Allow)
+		} else if string.eq_ignore_ascii_case("deny") {
+			Ok(Self::Deny)
+		} else {
+			Err(Error::ParseError("expected allow or deny"))
+		}
+	}
+}
+
+/// Parse one side of a rule, which is `any`, a network, or a single address.
+fn side(string: &str) -> Result<Option<NetAddr>> {
+	if string.eq_ignore_ascii_case("any") {
+		Ok(None)
+	} else if string.contains('/') {
+		string.parse().map(Some)
+	} else {
+		Ok(Some(string.parse::<IpAddr>()?.into()))
+	}
+}
+
+impl FromStr for Rule {
+	type Err = Error;
+
+	/// Parse a `Rule`; see its documentation for the format.
+	fn from_str(string: &str) -> Result<Self> {
+		let mut words = string.split_whitespace();
+
+		let mut rule: Self = match words.next() {
+			Some(action) => Self::new(action.parse()?),
+			None => return Err(Error::ParseError("expected allow or deny")),
+		};
+
+		let (mut from, mut to): (bool, bool) = (false, false);
+		while let Some(keyword) = words.next() {
+			let net = words
+				.next()
+				.ok_or(Error::ParseError("expected a network after from or to"))?;
+
+			match keyword {
+				"from" if !from => {
+					from = true;
+					rule.source = side(net)?;
+				}
+				"to" if !to => {
+					to = true;
+					rule.destination = side(net)?;
+				}
+				"from" | "to" => return Err(Error::ParseError("repeated from or to")),
+				_ => return Err(Error::ParseError("expected from or to")),
+			}
+		}
+
+		Ok(rule)
+	}
+}
+
+impl FromStr for Acl {
+	type Err = Error;
+
+	/// Parse an `Acl`; see its documentation for the format.
+	fn from_str(string: &str) -> Result<Self> {
+		let mut acl: Self = Self::default();
+		let mut default: Option<Action> = None;
+
+		for line in string.lines().map(str::trim) {
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let mut words = line.split_whitespace();
+			if words.next() != Some("default") {
+				acl.push(line.parse()?);
+			} else if default.is_some() {
+				return Err(Error::ParseError("repeated default"));
+			} else {
+				default = Some(words.next().unwrap_or_default().parse()?);
+
+				if words.next().is_some() {
+					return Err(Error::ParseError("expected only an action after default"));
+				}
+			}
+		}
+
+		acl.default = default.unwrap_or(Action::Deny);
+		Ok(acl)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn actions() {
+		assert_eq!("allow".parse(), Ok(Action::Allow));
+		assert_eq!("DENY".parse(), Ok(Action::Deny));
+		assert!("drop".parse::<Action>().is_err());
+	}
+
+	#[test]
+	fn rules() {
+		assert_eq!("allow".parse(), Ok(Rule::allow()));
+		assert_eq!("deny from any to any".parse(), Ok(Rule::deny()));
+		assert_eq!(
+			"deny  to 2001:db8::/32  from 10.0.0.1".parse(),
+			Ok(
+				Rule::deny()
+					.with_source(pu!("10.0.0.1/32" # NetAddr))
+					.with_destination(pu!("2001:db8::/32" # NetAddr))
+			)
+		);
+		assert_eq!(
+			"allow from 10.0.0.0/255.0.255.0".parse(),
+			Ok(Rule::allow().with_source(pu!("10.0.0.0/255.0.255.0" # NetAddr)))
+		);
+	}
+
+	#[test]
+	fn rule_errors() {
+		assert!("".parse::<Rule>().is_err());
+		assert!("allow from".parse::<Rule>().is_err());
+		assert!("allow from 10.0.0.0/8 from 11.0.0.0/8"
+			.parse::<Rule>()
+			.is_err());
+		assert!("allow via 10.0.0.0/8".parse::<Rule>().is_err());
+		assert!("allow from zoop".parse::<Rule>().is_err());
+	}
+
+	#[test]
+	fn acls() {
+		let acl: Acl = "
+			# Keep the lab out.
+			deny from 10.0.0.0/24
+
+			allow from 10.0.0.0/8
+			default allow
+		"
+		.parse()
+		.unwrap();
+
+		assert_eq!(acl.rules().len(), 2);
+		assert_eq!(acl.default_action(), Action::Allow);
+		assert_eq!(acl.to_string().parse(), Ok(acl));
+
+		assert_eq!(
+			"".parse::<Acl>().map(|acl| acl.default_action()),
+			Ok(Action::Deny)
+		);
+		assert!("default allow\ndefault deny".parse::<Acl>().is_err());
+		assert!("default".parse::<Acl>().is_err());
+		assert!("default allow now".parse::<Acl>().is_err());
+		assert!("allow\nzoop".parse::<Acl>().is_err());
+	}
+}
@@ -0,0 +1,535 @@
+use super::{Acl, Rule};
+use crate::{Contains, NetAddr, Netv4Addr, Netv6Addr};
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// A problem with a rule of an [`Acl`], found by [`Acl::lint`].
+///
+/// Rules are referred to by their indices in [`Acl::rules`].  `Display` gives
+/// an explanation of the problem.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Finding {
+	rule: usize,
+	kind: FindingKind,
+}
+
+impl Finding {
+	/// Get the index of the rule with the problem.
+	pub const fn rule(&self) -> usize {
+		self.rule
+	}
+
+	/// Get the problem.
+	pub const fn kind(&self) -> &FindingKind {
+		&self.kind
+	}
+}
+
+/// The kinds of problem found by [`Acl::lint`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+	/// The rule never matches, since the earlier rules `by` together match
+	/// every packet it does, and some of them take a different action.
+	Shadowed {
+		/// The earlier rules matching the packets of this one
+		by: Vec<usize>,
+	},
+	/// The rule never matches, since the earlier rules `by` together match
+	/// every packet it does, and all take the same action.
+	Redundant {
+		/// The earlier rules matching the packets of this one
+		by: Vec<usize>,
+	},
+	/// The rule can be removed, since the later rule `by` matches every packet
+	/// it does with the same action, and no rule between them matches any of
+	/// those packets with a different action.
+	RedundantWithLater {
+		/// The later rule matching the packets of this one
+		by: usize,
+	},
+	/// The rule can be removed, since it takes the default action, and no later
+	/// rule matches any of its packets with a different action.
+	RedundantWithDefault,
+	/// The rule and the earlier rule `with` both match some packets, but not all
+	/// of each other's, with different actions, so their order matters.
+	Conflict {
+		/// The earlier rule sharing packets with this one
+		with: usize,
+	},
+}
+
+/// A set of packets, by their source and destination networks.
+type Region = (NetAddr, NetAddr);
+
+/// Get the networks matched by one side of a rule.
+fn side(net: Option<NetAddr>) -> Vec<NetAddr> {
+	match net {
+		Some(net) => vec![net],
+		None => vec![
+			Netv4Addr::new(Ipv4Addr::UNSPECIFIED, Ipv4Addr::UNSPECIFIED).into(),
+			Netv6Addr::new(Ipv6Addr::UNSPECIFIED, Ipv6Addr::UNSPECIFIED).into(),
+		],
+	}
+}
+
+/// Split the packets of `region` which do not match `rule` into disjoint
+/// regions, or give `None` if no packet of `region` matches `rule`.
+fn subtract((source, destination): Region, rule: &Rule) -> Option<Vec<Region>> {
+	let shared_source: NetAddr = match rule.source {
+		Some(net) => source.intersection(&net)?,
+		None => source,
+	};
+
+	if let Some(net) = rule.destination {
+		destination.intersection(&net)?;
+	}
+
+	// Packets from outside the rule's source, then packets from inside it but
+	// to outside its destination.
+	let mut rest: Vec<Region> = Vec::new();
+	if let Some(net) = rule.source {
+		rest.extend(
+			source
+				.difference(&net)
+				.into_iter()
+				.map(|source| (source, destination)),
+		);
+	}
+	if let Some(net) = rule.destination {
+		rest.extend(
+			destination
+				.difference(&net)
+				.into_iter()
+				.map(|destination| (shared_source, destination)),
+		);
+	}
+
+	Some(rest)
+}
+
+impl Acl {
+	/// Find rules which never match, rules which could be removed without
+	/// changing the outcome for any packet, and pairs of rules whose order
+	/// matters.
+	///
+	/// Findings are in order of the rule they are about.  Whether earlier rules
+	/// together cover a rule is worked out exactly, including for
+	/// non-contiguous netmasks.  Each finding holds on its own: removing one
+	/// redundant rule may make another necessary.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Acl, FindingKind};
+	/// let acl: Acl = "
+	///     allow from 10.0.0.0/9
+	///     allow from 10.128.0.0/9
+	///     deny from 10.1.0.0/16
+	///     deny from 192.0.2.0/24 to 198.51.100.0/24
+	///     allow to 198.51.100.1
+	/// "
+	/// .parse()
+	/// .unwrap();
+	///
+	/// let findings = acl.lint();
+	///
+	/// assert_eq!(findings[0].rule(), 2);
+	/// assert_eq!(findings[0].kind(), &FindingKind::Shadowed { by: vec![0] });
+	/// assert_eq!(findings[1].rule(), 4);
+	/// assert_eq!(findings[1].kind(), &FindingKind::Conflict { with: 3 });
+	/// assert_eq!(
+	///     findings[1].to_string(),
+	///     "rule 4 conflicts with rule 3: each matches packets the other does not, \
+	///      but they share some with different actions, so their order matters",
+	/// );
+	/// ```
+	pub fn lint(&self) -> Vec<Finding> {
+		let covered: Vec<Option<FindingKind>> = (0..self.rules.len())
+			.map(|index| self.covered(index))
+			.collect();
+		let reachable = |index: &usize| covered[*index].is_none();
+
+		let mut findings: Vec<Finding> = Vec::new();
+
+		for (index, rule) in self.rules.iter().enumerate() {
+			if let Some(kind) = &covered[index] {
+				findings.push(Finding {
+					rule: index,
+					kind: kind.clone(),
+				});
+				continue;
+			}
+
+			// The first later rule to take any of this rule's packets with a
+			// different action, or to take all of them with the same action.  A
+			// later rule which never matches still counts as acting differently,
+			// since removing this rule might let it match, but does not count as
+			// taking the packets, so that duplicates are not each redundant with
+			// the other.
+			let decisive = (index + 1..self.rules.len())
+				.map(|later| (later, &self.rules[later]))
+				.filter(|(_, other)| other.overlaps(rule))
+				.find(|(later, other)| {
+					other.action != rule.action || (reachable(later) && other.contains(rule))
+				});
+
+			let redundancy: Option<FindingKind> = match decisive {
+				Some((later, other)) if other.action == rule.action => {
+					Some(FindingKind::RedundantWithLater { by: later })
+				}
+				None if rule.action == self.default => Some(FindingKind::RedundantWithDefault),
+				_ => None,
+			};
+
+			findings.extend(redundancy.map(|kind| Finding { rule: index, kind }));
+
+			findings.extend(
+				(0..index)
+					.filter(reachable)
+					.filter(|&earlier| {
+						let earlier: &Rule = &self.rules[earlier];
+						earlier.action != rule.action && earlier.overlaps(rule) && !rule.contains(earlier)
+					})
+					.map(|earlier| Finding {
+						rule: index,
+						kind: FindingKind::Conflict { with: earlier },
+					}),
+			);
+		}
+
+		findings
+	}
+
+	/// Determine whether the rules before `index` together match every packet
+	/// that it does, by taking away the packets each matches in turn.
+	fn covered(&self, index: usize) -> Option<FindingKind> {
+		let rule: &Rule = &self.rules[index];
+
+		let mut regions: Vec<Region> = side(rule.source)
+			.into_iter()
+			.flat_map(|source| {
+				side(rule.destination)
+					.into_iter()
+					.map(move |destination| (source, destination))
+			})
+			.collect();
+		let mut by: Vec<usize> = Vec::new();
+
+		for (earlier, other) in self.rules[..index].iter().enumerate() {
+			if regions.is_empty() {
+				break;
+			}
+
+			let mut taken: bool = false;
+			regions = regions
+				.into_iter()
+				.flat_map(|region| match subtract(region, other) {
+					Some(rest) => {
+						taken = true;
+						rest
+					}
+					None => vec![region],
+				})
+				.collect();
+
+			if taken {
+				by.push(earlier);
+			}
+		}
+
+		if !regions.is_empty() {
+			None
+		} else if by
+			.iter()
+			.any(|&earlier| self.rules[earlier].action != rule.action)
+		{
+			Some(FindingKind::Shadowed { by })
+		} else {
+			Some(FindingKind::Redundant { by })
+		}
+	}
+}
+
+/// Write `rule 1`, `rules 1 and 2`, or `rules 1, 2, and 3`.
+fn write_rules(f: &mut fmt::Formatter<'_>, rules: &[usize]) -> fmt::Result {
+	match rules {
+		[rule] => write!(f, "rule {}", rule),
+		[first, second] => write!(f, "rules {} and {}", first, second),
+		[rest @ .., last] => {
+			f.write_str("rules ")?;
+			for rule in rest {
+				write!(f, "{}, ", rule)?;
+			}
+			write!(f, "and {}", last)
+		}
+		[] => f.write_str("no rules"),
+	}
+}
+
+/// Pick the form of a verb agreeing with the number of `rules`.
+fn verb<'a>(rules: &[usize], singular: &'a str, plural: &'a str) -> &'a str {
+	if rules.len() == 1 {
+		singular
+	} else {
+		plural
+	}
+}
+
+impl fmt::Display for Finding {
+	/// Explain the problem.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.kind {
+			FindingKind::Shadowed { by } => {
+				write!(f, "rule {} never matches: ", self.rule)?;
+				write_rules(f, by)?;
+				write!(f, " before it {} every packet it does, and ", verb(by, "matches", "match"))?;
+				write!(f, "{} differently on some", verb(by, "acts", "act"))
+			}
+			FindingKind::Redundant { by } => {
+				write!(f, "rule {} never matches: ", self.rule)?;
+				write_rules(f, by)?;
+				write!(f, " before it {} every packet it does, with the same action", verb(by, "matches", "match"))
+			}
+			FindingKind::RedundantWithLater { by } => write!(
+				f,
+				"rule {} is redundant: rule {} matches every packet it does, with the same action, and no rule between them acts differently on any of those packets",
+				self.rule, by
+			),
+			FindingKind::RedundantWithDefault => write!(
+				f,
+				"rule {} is redundant: it takes the default action, and no later rule acts differently on any of its packets",
+				self.rule
+			),
+			FindingKind::Conflict { with } => write!(
+				f,
+				"rule {} conflicts with rule {}: each matches packets the other does not, but they share some with different actions, so their order matters",
+				self.rule, with
+			),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::Action;
+	use super::*;
+	use core::net::IpAddr;
+	use proptest::prelude::*;
+
+	fn lint(acl: &str) -> Vec<(usize, FindingKind)> {
+		acl
+			.parse::<Acl>()
+			.unwrap()
+			.lint()
+			.into_iter()
+			.map(|finding| (finding.rule, finding.kind))
+			.collect()
+	}
+
+	#[test]
+	fn shadowed_by_one() {
+		assert_eq!(
+			lint("deny from 10.0.0.0/8\nallow from 10.1.0.0/16 to 192.0.2.0/24"),
+			vec![(1, FindingKind::Shadowed { by: vec![0] })]
+		);
+	}
+
+	#[test]
+	fn shadowed_by_union() {
+		assert_eq!(
+			lint(
+				"
+				allow from 10.0.0.0/9
+				deny from 10.128.0.0/9 to 0.0.0.0/1
+				allow from 10.128.0.0/9 to 128.0.0.0/1
+				deny from 10.0.0.0/8 to 192.0.2.0/24
+				default allow
+				"
+			),
+			vec![(3, FindingKind::Shadowed { by: vec![0, 2] })]
+		);
+	}
+
+	#[test]
+	fn shadowed_by_non_contiguous() {
+		// Even and odd third octets, between them, cover the whole network.
+		assert_eq!(
+			lint(
+				"
+				allow from 10.0.0.0/255.255.1.0
+				allow from 10.0.1.0/255.255.1.0
+				allow from 10.0.2.0/24
+				deny
+				"
+			),
+			vec![
+				(2, FindingKind::Redundant { by: vec![0] }),
+				(3, FindingKind::RedundantWithDefault),
+			]
+		);
+		assert_eq!(
+			lint(
+				"
+				allow from 10.0.0.0/255.255.1.0
+				allow from 10.0.1.0/255.255.1.0
+				allow from 10.0.0.0/16 to ::/0
+				"
+			),
+			vec![(2, FindingKind::Redundant { by: vec![0, 1] })]
+		);
+	}
+
+	#[test]
+	fn any_covers_both_families() {
+		assert_eq!(
+			lint("allow from 0.0.0.0/0\nallow from ::/0\ndeny\ndeny from 10.0.0.0/8\ndefault allow"),
+			vec![
+				(2, FindingKind::Shadowed { by: vec![0, 1] }),
+				(3, FindingKind::Shadowed { by: vec![0] }),
+			]
+		);
+		assert_eq!(
+			lint("allow\ndeny from ::/0\ndefault deny"),
+			vec![(1, FindingKind::Shadowed { by: vec![0] })]
+		);
+	}
+
+	#[test]
+	fn redundant_with_later() {
+		assert_eq!(
+			lint("allow from 10.1.0.0/16\nallow from 10.0.0.0/8"),
+			vec![(0, FindingKind::RedundantWithLater { by: 1 })]
+		);
+		// A rule between with the same action is fine.
+		assert_eq!(
+			lint("allow from 10.1.0.0/16\nallow from 10.1.2.0/24\nallow from 10.0.0.0/8"),
+			vec![
+				(0, FindingKind::RedundantWithLater { by: 2 }),
+				(1, FindingKind::Redundant { by: vec![0] }),
+			]
+		);
+		// A rule between acting differently on the same packets is not, even if
+		// it never matches, since removing the first rule would let it.
+		assert_eq!(
+			lint("allow from 10.1.0.0/16\ndeny from 10.1.2.0/24\nallow from 10.0.0.0/8"),
+			vec![(1, FindingKind::Shadowed { by: vec![0] })]
+		);
+		// Nor are duplicates redundant with each other.
+		assert_eq!(
+			lint("allow from 10.1.0.0/16\nallow from 10.1.0.0/16"),
+			vec![(1, FindingKind::Redundant { by: vec![0] })]
+		);
+	}
+
+	#[test]
+	fn redundant_with_default() {
+		assert_eq!(
+			lint("deny from 10.0.0.0/8\nallow from 192.0.2.0/24"),
+			vec![(0, FindingKind::RedundantWithDefault)]
+		);
+		assert_eq!(
+			lint("deny from 10.0.0.0/8\nallow from 10.1.0.0/16"),
+			vec![(1, FindingKind::Shadowed { by: vec![0] })]
+		);
+	}
+
+	#[test]
+	fn generalizations_do_not_conflict() {
+		assert_eq!(lint("deny from 10.1.0.0/16\nallow from 10.0.0.0/8"), vec![]);
+	}
+
+	#[test]
+	fn conflicts() {
+		assert_eq!(
+			lint("deny from 10.0.0.0/8\nallow to 192.0.2.0/24"),
+			vec![(1, FindingKind::Conflict { with: 0 })]
+		);
+	}
+
+	#[test]
+	fn explanations() {
+		let finding = |rule, kind| Finding { rule, kind }.to_string();
+
+		assert_eq!(
+			finding(3, FindingKind::Shadowed { by: vec![0, 1, 2] }),
+			"rule 3 never matches: rules 0, 1, and 2 before it match every packet it does, and act differently on some"
+		);
+		assert_eq!(
+			finding(1, FindingKind::Redundant { by: vec![0] }),
+			"rule 1 never matches: rule 0 before it matches every packet it does, with the same action"
+		);
+		assert_eq!(
+			finding(0, FindingKind::RedundantWithLater { by: 4 }),
+			"rule 0 is redundant: rule 4 matches every packet it does, with the same action, and no rule between them acts differently on any of those packets"
+		);
+		assert_eq!(
+			finding(2, FindingKind::RedundantWithDefault),
+			"rule 2 is redundant: it takes the default action, and no later rule acts differently on any of its packets"
+		);
+	}
+
+	/// Generate a side of a rule within `10.0.0.0/8`, varying only the second
+	/// octet, so that rules often overlap.
+	fn side() -> impl Strategy<Value = Option<NetAddr>> {
+		prop_oneof![
+			Just(None),
+			(any::<u8>(), any::<u8>()).prop_map(|(addr, mask)| Some(
+				Netv4Addr::new(
+					Ipv4Addr::new(10, addr, 0, 0),
+					Ipv4Addr::new(255, mask, 0, 0)
+				)
+				.into()
+			)),
+		]
+	}
+
+	fn rule() -> impl Strategy<Value = Rule> {
+		(
+			prop_oneof![Just(Action::Allow), Just(Action::Deny)],
+			side(),
+			side(),
+		)
+			.prop_map(|(action, source, destination)| Rule {
+				action,
+				source,
+				destination,
+			})
+	}
+
+	proptest! {
+		#[test]
+		fn findings_hold(
+			rules in proptest::collection::vec(rule(), 1..8),
+			default in prop_oneof![Just(Action::Allow), Just(Action::Deny)],
+			packets in proptest::collection::vec((any::<u8>(), any::<u8>()), 64),
+		) {
+			let mut acl = Acl::new(default);
+			acl.extend(rules);
+
+			let packets: Vec<(IpAddr, IpAddr)> = packets
+				.into_iter()
+				.map(|(source, destination)| (Ipv4Addr::new(10, source, 0, 0).into(), Ipv4Addr::new(10, destination, 0, 0).into()))
+				.collect();
+
+			for finding in acl.lint() {
+				let mut without = acl.clone();
+				without.rules.remove(finding.rule);
+
+				for &(source, destination) in &packets {
+					match finding.kind {
+						FindingKind::Shadowed { .. } | FindingKind::Redundant { .. } => {
+							prop_assert_ne!(acl.evaluate(source, destination).rule(), Some(finding.rule));
+						}
+						FindingKind::RedundantWithLater { .. } | FindingKind::RedundantWithDefault => {
+							prop_assert_eq!(
+								without.evaluate(source, destination).action(),
+								acl.evaluate(source, destination).action()
+							);
+						}
+						FindingKind::Conflict { .. } => {}
+					}
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,83 @@
+use crate::PrefixLen;
+use core::net::Ipv4Addr;
+
+/// The class of an IPv4 address, from before classless routing.
+///
+/// Classes are obsolete for routing, but still turn up in older
+/// configuration and registry data, where a network written without a netmask
+/// takes the netmask of its class.  The class of an address is determined by
+/// its leading bits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::AddrClass;
+/// # use std::net::Ipv4Addr;
+/// assert_eq!(AddrClass::of(Ipv4Addr::new(10, 0, 0, 0)), AddrClass::A);
+/// assert_eq!(AddrClass::of(Ipv4Addr::new(172, 16, 0, 0)), AddrClass::B);
+/// assert_eq!(AddrClass::of(Ipv4Addr::new(224, 0, 0, 1)), AddrClass::D);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddrClass {
+	/// `0.0.0.0` to `127.255.255.255`, with a netmask of `/8`.
+	A,
+	/// `128.0.0.0` to `191.255.255.255`, with a netmask of `/16`.
+	B,
+	/// `192.0.0.0` to `223.255.255.255`, with a netmask of `/24`.
+	C,
+	/// `224.0.0.0` to `239.255.255.255`, for multicast, with no netmask.
+	D,
+	/// `240.0.0.0` to `255.255.255.255`, reserved, with no netmask.
+	E,
+}
+
+impl AddrClass {
+	/// Get the class of `addr`.
+	pub const fn of(addr: Ipv4Addr) -> Self {
+		match addr.octets()[0] {
+			0..=127 => Self::A,
+			128..=191 => Self::B,
+			192..=223 => Self::C,
+			224..=239 => Self::D,
+			_ => Self::E,
+		}
+	}
+
+	/// Get the prefix length of the netmask implied by the class, if it has one.
+	pub const fn prefix_len(self) -> Option<PrefixLen> {
+		match self {
+			Self::A => PrefixLen::new(8),
+			Self::B => PrefixLen::new(16),
+			Self::C => PrefixLen::new(24),
+			Self::D | Self::E => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn boundaries() {
+		let class = |a: u8| AddrClass::of(Ipv4Addr::new(a, 255, 255, 255));
+
+		assert_eq!(class(0), AddrClass::A);
+		assert_eq!(class(127), AddrClass::A);
+		assert_eq!(class(128), AddrClass::B);
+		assert_eq!(class(191), AddrClass::B);
+		assert_eq!(class(192), AddrClass::C);
+		assert_eq!(class(223), AddrClass::C);
+		assert_eq!(class(224), AddrClass::D);
+		assert_eq!(class(239), AddrClass::D);
+		assert_eq!(class(240), AddrClass::E);
+		assert_eq!(class(255), AddrClass::E);
+	}
+
+	#[test]
+	fn prefix_lens() {
+		assert_eq!(AddrClass::A.prefix_len(), PrefixLen::new(8));
+		assert_eq!(AddrClass::C.prefix_len(), PrefixLen::new(24));
+		assert_eq!(AddrClass::D.prefix_len(), None);
+	}
+}
@@ -1,9 +1,17 @@
 /// The error type for operations relating to the `NetAddr` type
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Error {
-	ParseError(String),
+	ParseError(&'static str),
+	/// A netmask was not "left contiguous" where one was required, such as
+	/// when converting to a type which can only represent CIDR networks.
+	NonContiguousMask,
+	/// A prefix length was longer than the addresses it applies to.
+	InvalidPrefixLen,
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 mod display;
 mod from;
 mod result;
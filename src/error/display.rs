@@ -5,6 +5,8 @@ impl Display for Error {
 	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
 		match self {
 			Self::ParseError(text) => write!(f, "unable to parse address: {}", text),
+			Self::NonContiguousMask => write!(f, "netmask is not contiguous"),
+			Self::InvalidPrefixLen => write!(f, "prefix length is out of range"),
 		}
 	}
 }
@@ -15,8 +17,20 @@ mod tests {
 
 	#[test]
 	fn right_message() {
-		let error: Error = Error::ParseError("INNER_TEXT".into());
+		let error: Error = Error::ParseError("INNER_TEXT");
 		let result: &str = &format!("{}", error);
 		assert_eq!(result, "unable to parse address: INNER_TEXT");
 	}
+
+	#[test]
+	fn non_contiguous_mask_message() {
+		let result: &str = &format!("{}", Error::NonContiguousMask);
+		assert_eq!(result, "netmask is not contiguous");
+	}
+
+	#[test]
+	fn invalid_prefix_len_message() {
+		let result: &str = &format!("{}", Error::InvalidPrefixLen);
+		assert_eq!(result, "prefix length is out of range");
+	}
 }
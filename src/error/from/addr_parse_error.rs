@@ -1,8 +1,8 @@
 use super::Error;
-use std::net::AddrParseError;
+use core::net::AddrParseError;
 
 impl From<AddrParseError> for Error {
-	fn from(other: AddrParseError) -> Self {
-		Self::ParseError(other.to_string())
+	fn from(_: AddrParseError) -> Self {
+		Self::ParseError("invalid IP address syntax")
 	}
 }
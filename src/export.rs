@@ -0,0 +1,324 @@
+//! Rendering sets of networks as configuration for firewalls and routers.
+
+use crate::{Action, Error, NetAddr, Result};
+
+/// The configuration syntaxes an [`Exporter`] can write.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportSyntax {
+	/// nftables set definitions with `flags interval`, one for each family,
+	/// named with a `_v4` or `_v6` suffix, to be placed inside a table.
+	Nftables,
+	/// An `ipset restore` script creating a `hash:net` set for each family,
+	/// named with a `_v4` or `_v6` suffix.
+	Ipset,
+	/// `iptables` and `ip6tables` commands appending a rule to a chain for each
+	/// source network.
+	Iptables,
+	/// Cisco IOS `ip prefix-list` and `ipv6 prefix-list` entries.
+	CiscoPrefixList,
+	/// Cisco IOS named access lists, with wildcard masks for IPv4.
+	CiscoAcl,
+	/// A Juniper Junos `prefix-list`.
+	Juniper,
+	/// A BIRD filter matching route prefixes.
+	Bird,
+	/// A pf table.
+	Pf,
+}
+
+/// A builder for writing a set of networks as configuration.
+///
+/// Each network of the set becomes an element of a set or list, an entry of a
+/// prefix list, or a rule, depending on the syntax.  Networks are written
+/// IPv4 first, each family in the order given, and the name is written as
+/// given.
+///
+/// So that it can't break out of its place in the configuration, the name may
+/// only contain ASCII letters, digits, `_`, `.`, and `-`, and must not be
+/// empty; nftables set names must also start with a letter or `_`, and BIRD
+/// filter names must be letters, digits, and `_` only, not starting with a
+/// digit.  Any other name gives [`Error::ParseError`].
+///
+/// The [`Action`] decides whether rules and entries permit or deny the
+/// networks, and what happens to everything else where the syntax needs that;
+/// syntaxes which only define sets ignore it.
+///
+/// Only `iptables` and IPv4 Cisco access lists can express non-contiguous
+/// netmasks, so exporting a network with one to any other syntax gives
+/// [`Error::NonContiguousMask`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{ExportSyntax, Exporter, NetAddr};
+/// let nets: Vec<NetAddr> = vec!["10.0.0.0/8".parse().unwrap(), "192.0.2.1/32".parse().unwrap()];
+///
+/// assert_eq!(
+///     Exporter::new(ExportSyntax::CiscoAcl, "ALLOWED").export(&nets),
+///     Ok("ip access-list standard ALLOWED\n permit 10.0.0.0 0.255.255.255\n permit host 192.0.2.1\n".to_string()),
+/// );
+/// assert_eq!(
+///     Exporter::new(ExportSyntax::Pf, "allowed").export(&nets),
+///     Ok("table <allowed> persist { 10.0.0.0/8, 192.0.2.1/32 }\n".to_string()),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exporter<'a> {
+	syntax: ExportSyntax,
+	name: &'a str,
+	action: Action,
+	or_longer: bool,
+}
+
+impl<'a> Exporter<'a> {
+	/// Start writing a set named `name` in the given syntax, allowing the
+	/// networks in it.
+	pub const fn new(syntax: ExportSyntax, name: &'a str) -> Self {
+		Self {
+			syntax,
+			name,
+			action: Action::Allow,
+			or_longer: false,
+		}
+	}
+
+	/// Permit or deny the networks.
+	pub const fn action(self, action: Action) -> Self {
+		Self { action, ..self }
+	}
+
+	/// Match the subnets of each network as well as the network itself.
+	///
+	/// This applies to the syntaxes which match route prefixes exactly,
+	/// Cisco prefix lists and BIRD filters; the others match every address in
+	/// each network regardless.
+	pub const fn or_longer(self, or_longer: bool) -> Self {
+		Self { or_longer, ..self }
+	}
+
+	/// Check that the name is safe to write in the syntax.
+	fn check_name(&self) -> Result<()> {
+		let first: u8 = match self.name.bytes().next() {
+			Some(first) => first,
+			None => return Err(Error::ParseError("name is empty")),
+		};
+
+		if !self
+			.name
+			.bytes()
+			.all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'-'))
+		{
+			return Err(Error::ParseError("name contains an invalid character"));
+		}
+
+		match self.syntax {
+			ExportSyntax::Nftables | ExportSyntax::Bird
+				if !(first.is_ascii_alphabetic() || first == b'_') =>
+			{
+				Err(Error::ParseError("name must start with a letter or `_`"))
+			}
+			ExportSyntax::Bird if self.name.contains(['.', '-']) => {
+				Err(Error::ParseError("name contains an invalid character"))
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Write `nets` as configuration.
+	///
+	/// Gives [`Error::ParseError`] if the name can't be written in the syntax,
+	/// and [`Error::NonContiguousMask`] if a network can't be.
+	pub fn export<'n, I: IntoIterator<Item = &'n NetAddr>>(&self, nets: I) -> Result<String> {
+		self.check_name()?;
+
+		let (mut v4, mut v6): (Vec<NetAddr>, Vec<NetAddr>) = (Vec::new(), Vec::new());
+
+		for net in nets {
+			let non_contiguous_allowed: bool = match (self.syntax, net) {
+				(ExportSyntax::Iptables, _) => true,
+				(ExportSyntax::CiscoAcl, NetAddr::V4(_)) => true,
+				(_, _) => false,
+			};
+
+			if !net.is_cidr() && !non_contiguous_allowed {
+				return Err(Error::NonContiguousMask);
+			}
+
+			match net {
+				NetAddr::V4(_) => v4.push(*net),
+				NetAddr::V6(_) => v6.push(*net),
+			}
+		}
+
+		let mut out: String = String::new();
+		let written = match self.syntax {
+			ExportSyntax::Nftables => nftables::write(self, &mut out, &v4, &v6),
+			ExportSyntax::Ipset => ipset::write(self, &mut out, &v4, &v6),
+			ExportSyntax::Iptables => iptables::write(self, &mut out, &v4, &v6),
+			ExportSyntax::CiscoPrefixList => cisco::write_prefix_list(self, &mut out, &v4, &v6),
+			ExportSyntax::CiscoAcl => cisco::write_acl(self, &mut out, &v4, &v6),
+			ExportSyntax::Juniper => juniper::write(self, &mut out, &v4, &v6),
+			ExportSyntax::Bird => bird::write(self, &mut out, &v4, &v6),
+			ExportSyntax::Pf => pf::write(self, &mut out, &v4, &v6),
+		};
+		written.expect("writing to a String cannot fail");
+
+		Ok(out)
+	}
+}
+
+/// Get the prefix length of a CIDR network.
+fn prefix_len(net: &NetAddr) -> u32 {
+	match net {
+		NetAddr::V4(net) => u32::from(net.mask()).leading_ones(),
+		NetAddr::V6(net) => u128::from(net.mask()).leading_ones(),
+	}
+}
+
+/// Write `items` separated by `, `.
+fn write_list<I>(out: &mut String, items: I) -> core::fmt::Result
+where
+	I: IntoIterator,
+	I::Item: core::fmt::Display,
+{
+	use core::fmt::Write;
+
+	for (index, item) in items.into_iter().enumerate() {
+		if index > 0 {
+			out.push_str(", ");
+		}
+		write!(out, "{}", item)?;
+	}
+
+	Ok(())
+}
+
+mod bird;
+mod cisco;
+mod ipset;
+mod iptables;
+mod juniper;
+mod nftables;
+mod pf;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The networks written to the golden files.
+	pub(super) fn nets() -> Vec<NetAddr> {
+		[
+			"10.0.0.0/8",
+			"2001:db8::/32",
+			"192.0.2.1/32",
+			"198.51.100.0/24",
+			"::1/128",
+			"0.0.0.0/0",
+		]
+		.iter()
+		.map(|net| net.parse().unwrap())
+		.collect()
+	}
+
+	#[test]
+	fn non_contiguous_masks() {
+		let nets: Vec<NetAddr> = vec![pu!("10.0.0.0/255.0.255.0" # NetAddr)];
+		let low: Vec<NetAddr> = vec![pu!("10.0.0.5/0.0.0.255" # NetAddr)];
+		let v6: Vec<NetAddr> = vec![pu!("2001:db8::/ffff::ffff" # NetAddr)];
+
+		for syntax in &[
+			ExportSyntax::Nftables,
+			ExportSyntax::Ipset,
+			ExportSyntax::CiscoPrefixList,
+			ExportSyntax::Juniper,
+			ExportSyntax::Bird,
+			ExportSyntax::Pf,
+		] {
+			assert_eq!(
+				Exporter::new(*syntax, "x").export(&nets),
+				Err(Error::NonContiguousMask)
+			);
+		}
+
+		assert_eq!(
+			Exporter::new(ExportSyntax::Iptables, "x").export(&nets),
+			Ok("iptables -A x -s 10.0.0.0/255.0.255.0 -j ACCEPT\n".to_string())
+		);
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "x").export(&nets),
+			Ok("ip access-list standard x\n permit 10.0.0.0 0.255.0.255\n".to_string())
+		);
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "x").export(&low),
+			Ok("ip access-list standard x\n permit 0.0.0.5 255.255.255.0\n".to_string())
+		);
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "x").export(&v6),
+			Err(Error::NonContiguousMask)
+		);
+	}
+
+	#[test]
+	fn rejects_unsafe_names() {
+		let nets: Vec<NetAddr> = vec![pu!("10.0.0.0/8" # NetAddr)];
+
+		for syntax in &[
+			ExportSyntax::Nftables,
+			ExportSyntax::Ipset,
+			ExportSyntax::Iptables,
+			ExportSyntax::CiscoPrefixList,
+			ExportSyntax::CiscoAcl,
+			ExportSyntax::Juniper,
+			ExportSyntax::Bird,
+			ExportSyntax::Pf,
+		] {
+			for name in &[
+				"",
+				"a b",
+				"x\nflush ruleset",
+				"x {",
+				"x;",
+				"x>",
+				"<x>",
+				"ällowed",
+			] {
+				assert!(
+					matches!(
+						Exporter::new(*syntax, name).export(&nets),
+						Err(Error::ParseError(_))
+					),
+					"{:?} {:?}",
+					syntax,
+					name
+				);
+			}
+
+			assert!(Exporter::new(*syntax, "allowed_v2").export(&nets).is_ok());
+		}
+
+		assert!(Exporter::new(ExportSyntax::Pf, "allowed-2.0")
+			.export(&nets)
+			.is_ok());
+		assert!(Exporter::new(ExportSyntax::Ipset, "2allowed")
+			.export(&nets)
+			.is_ok());
+		assert_eq!(
+			Exporter::new(ExportSyntax::Nftables, "2allowed").export(&nets),
+			Err(Error::ParseError("name must start with a letter or `_`"))
+		);
+		assert_eq!(
+			Exporter::new(ExportSyntax::Bird, "allowed-2").export(&nets),
+			Err(Error::ParseError("name contains an invalid character"))
+		);
+	}
+
+	#[test]
+	fn sets_of_networks() {
+		let nets: std::collections::BTreeSet<NetAddr> = nets().into_iter().collect();
+
+		assert_eq!(
+			Exporter::new(ExportSyntax::Pf, "allowed").export(&nets),
+			Ok("table <allowed> persist { 0.0.0.0/0, 10.0.0.0/8, 192.0.2.1/32, 198.51.100.0/24, ::1/128, 2001:db8::/32 }\n".to_string())
+		);
+	}
+}
@@ -0,0 +1,62 @@
+use super::{write_list, Exporter};
+use crate::{Action, NetAddr};
+use core::fmt::{self, Write};
+
+/// Write a filter taking the action on routes to the networks, and the other
+/// action on every other route.
+///
+/// A prefix set holds a single family, so each family with networks has its
+/// own condition.  Matching longer prefixes adds a `+` to each prefix.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	let (action, otherwise): (&str, &str) = match exporter.action {
+		Action::Allow => ("accept", "reject"),
+		Action::Deny => ("reject", "accept"),
+	};
+	let suffix: &str = if exporter.or_longer { "+" } else { "" };
+
+	writeln!(out, "filter {} {{", exporter.name)?;
+
+	for nets in &[v4, v6] {
+		if nets.is_empty() {
+			continue;
+		}
+
+		out.push_str("\tif net ~ [ ");
+		write_list(out, nets.iter().map(|net| format!("{}{}", net, suffix)))?;
+		writeln!(out, " ] then {};", action)?;
+	}
+
+	writeln!(out, "\t{};", otherwise)?;
+	writeln!(out, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Bird, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/bird.conf").to_string())
+		);
+	}
+
+	#[test]
+	fn or_longer_golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Bird, "bogons")
+				.action(Action::Deny)
+				.or_longer(true)
+				.export(&nets()),
+			Ok(include_str!("../../tests/golden/bird-or-longer.conf").to_string())
+		);
+	}
+}
@@ -0,0 +1,170 @@
+use super::{prefix_len, Exporter};
+use crate::{Action, MaskNotation, NetAddr};
+use core::fmt::{self, Write};
+
+fn keyword(action: Action) -> &'static str {
+	match action {
+		Action::Allow => "permit",
+		Action::Deny => "deny",
+	}
+}
+
+/// Write a prefix list entry for each network, numbered in steps of five as
+/// IOS does.
+///
+/// Matching longer prefixes adds `le` with the length of an address, except
+/// to a host prefix, which has none longer.  Since a prefix list ends with an
+/// implicit deny, denying the networks finishes each family's list with an
+/// entry permitting everything else.
+pub(super) fn write_prefix_list(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	for (command, bits, nets) in &[("ip", 32, v4), ("ipv6", 128, v6)] {
+		for (index, net) in nets.iter().enumerate() {
+			write!(
+				out,
+				"{} prefix-list {} seq {} {} {}",
+				command,
+				exporter.name,
+				(index + 1) * 5,
+				keyword(exporter.action),
+				net
+			)?;
+
+			if exporter.or_longer && prefix_len(net) < *bits {
+				write!(out, " le {}", bits)?;
+			}
+
+			out.push('\n');
+		}
+
+		if exporter.action == Action::Deny && !nets.is_empty() {
+			writeln!(
+				out,
+				"{} prefix-list {} seq {} permit {} le {}",
+				command,
+				exporter.name,
+				(nets.len() + 1) * 5,
+				if *bits == 32 { "0.0.0.0/0" } else { "::/0" },
+				bits
+			)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Write a named access list for each family with networks, matching them by
+/// source.
+///
+/// IPv4 entries use wildcard masks, or `host` and `any` where they can.  Since
+/// an access list ends with an implicit deny, denying the networks finishes
+/// each list with an entry permitting everything else.
+pub(super) fn write_acl(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	let action: &str = keyword(exporter.action);
+
+	if !v4.is_empty() {
+		writeln!(out, "ip access-list standard {}", exporter.name)?;
+
+		for net in v4 {
+			// `any` and `host` only stand for the all-zeroes and all-ones masks.
+			match prefix_len(net) {
+				0 if net.is_cidr() => writeln!(out, " {} any", action)?,
+				32 if net.is_cidr() => writeln!(out, " {} host {}", action, net.addr())?,
+				_ => writeln!(
+					out,
+					" {} {}",
+					action,
+					net.format().mask(MaskNotation::Wildcard)
+				)?,
+			}
+		}
+
+		if exporter.action == Action::Deny {
+			writeln!(out, " permit any")?;
+		}
+	}
+
+	if !v6.is_empty() {
+		writeln!(out, "ipv6 access-list {}", exporter.name)?;
+
+		for net in v6 {
+			match prefix_len(net) {
+				0 => writeln!(out, " {} ipv6 any any", action)?,
+				128 => writeln!(out, " {} ipv6 host {} any", action, net.addr())?,
+				_ => writeln!(out, " {} ipv6 {} any", action, net)?,
+			}
+		}
+
+		if exporter.action == Action::Deny {
+			writeln!(out, " permit ipv6 any any")?;
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn prefix_list_golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoPrefixList, "ALLOWED").export(&nets()),
+			Ok(include_str!("../../tests/golden/cisco-prefix-list.txt").to_string())
+		);
+	}
+
+	#[test]
+	fn prefix_list_or_longer_golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoPrefixList, "BOGONS")
+				.action(Action::Deny)
+				.or_longer(true)
+				.export(&nets()),
+			Ok(include_str!("../../tests/golden/cisco-prefix-list-or-longer.txt").to_string())
+		);
+	}
+
+	#[test]
+	fn acl_golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "ALLOWED").export(&nets()),
+			Ok(include_str!("../../tests/golden/cisco-acl.txt").to_string())
+		);
+	}
+
+	#[test]
+	fn acl_single_family() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "BLOCKED")
+				.action(Action::Deny)
+				.export(&[pu!("2001:db8::/32" # NetAddr)]),
+			Ok(
+				"ipv6 access-list BLOCKED\n deny ipv6 2001:db8::/32 any\n permit ipv6 any any\n"
+					.to_string()
+			)
+		);
+	}
+
+	#[test]
+	fn acl_deny_permits_the_rest() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::CiscoAcl, "BLOCKED")
+				.action(Action::Deny)
+				.export(&[pu!("0.0.0.0/0" # NetAddr), pu!("::1/128" # NetAddr)]),
+			Ok("ip access-list standard BLOCKED\n deny any\n permit any\nipv6 access-list BLOCKED\n deny ipv6 host ::1 any\n permit ipv6 any any\n".to_string())
+		);
+	}
+}
@@ -0,0 +1,64 @@
+use super::{prefix_len, Exporter};
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+use core::fmt::{self, Write};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// Write commands to create, or empty, a set for each family and add the
+/// networks to it.
+///
+/// A `hash:net` set cannot hold a network with a prefix length of zero, so one
+/// is added as its two halves.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	let halves: [[NetAddr; 2]; 2] = [
+		[
+			Netv4Addr::from_prefix(Ipv4Addr::new(0, 0, 0, 0), 1).into(),
+			Netv4Addr::from_prefix(Ipv4Addr::new(128, 0, 0, 0), 1).into(),
+		],
+		[
+			Netv6Addr::from_prefix(Ipv6Addr::UNSPECIFIED, 1).into(),
+			Netv6Addr::from_prefix(Ipv6Addr::new(0x8000, 0, 0, 0, 0, 0, 0, 0), 1).into(),
+		],
+	];
+
+	for ((suffix, family, nets), halves) in [("v4", "inet", v4), ("v6", "inet6", v6)]
+		.iter()
+		.zip(&halves)
+	{
+		let name: String = [exporter.name, "_", suffix].concat();
+
+		writeln!(out, "create {} hash:net family {} -exist", name, family)?;
+		writeln!(out, "flush {}", name)?;
+
+		for net in nets.iter() {
+			if prefix_len(net) == 0 {
+				for half in halves {
+					writeln!(out, "add {} {}", name, half)?;
+				}
+			} else {
+				writeln!(out, "add {} {}", name, net)?;
+			}
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Ipset, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/ipset.txt").to_string())
+		);
+	}
+}
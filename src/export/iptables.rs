@@ -0,0 +1,53 @@
+use super::Exporter;
+use crate::{Action, NetAddr};
+use core::fmt::{self, Write};
+
+/// Write a command appending a rule to the chain for each network.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	let target: &str = match exporter.action {
+		Action::Allow => "ACCEPT",
+		Action::Deny => "DROP",
+	};
+
+	for (command, nets) in &[("iptables", v4), ("ip6tables", v6)] {
+		for net in nets.iter() {
+			writeln!(
+				out,
+				"{} -A {} -s {} -j {}",
+				command, exporter.name, net, target
+			)?;
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Iptables, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/iptables.sh").to_string())
+		);
+	}
+
+	#[test]
+	fn deny() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Iptables, "blocked")
+				.action(Action::Deny)
+				.export(&[pu!("192.0.2.0/24" # NetAddr)]),
+			Ok("iptables -A blocked -s 192.0.2.0/24 -j DROP\n".to_string())
+		);
+	}
+}
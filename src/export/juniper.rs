@@ -0,0 +1,36 @@
+use super::Exporter;
+use crate::NetAddr;
+use core::fmt::{self, Write};
+
+/// Write a prefix list of both families, within `policy-options`.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	writeln!(out, "policy-options {{")?;
+	writeln!(out, "    prefix-list {} {{", exporter.name)?;
+
+	for net in v4.iter().chain(v6) {
+		writeln!(out, "        {};", net)?;
+	}
+
+	writeln!(out, "    }}")?;
+	writeln!(out, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Juniper, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/juniper.conf").to_string())
+		);
+	}
+}
@@ -0,0 +1,53 @@
+use super::{write_list, Exporter};
+use crate::NetAddr;
+use core::fmt::{self, Write};
+
+/// Write a set for each family, leaving out the elements of an empty one.
+///
+/// `auto-merge` lets nftables accept networks which overlap one another.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	for (suffix, kind, nets) in &[("v4", "ipv4_addr", v4), ("v6", "ipv6_addr", v6)] {
+		writeln!(out, "set {}_{} {{", exporter.name, suffix)?;
+		writeln!(out, "\ttype {}", kind)?;
+		writeln!(out, "\tflags interval")?;
+		writeln!(out, "\tauto-merge")?;
+
+		if !nets.is_empty() {
+			out.push_str("\telements = { ");
+			write_list(out, *nets)?;
+			out.push_str(" }\n");
+		}
+
+		writeln!(out, "}}")?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Nftables, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/nftables.nft").to_string())
+		);
+	}
+
+	#[test]
+	fn empty_family() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Nftables, "allowed").export(&[pu!("::/0" # NetAddr)]),
+			Ok("set allowed_v4 {\n\ttype ipv4_addr\n\tflags interval\n\tauto-merge\n}\nset allowed_v6 {\n\ttype ipv6_addr\n\tflags interval\n\tauto-merge\n\telements = { ::/0 }\n}\n".to_string())
+		);
+	}
+}
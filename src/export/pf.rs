@@ -0,0 +1,47 @@
+use super::{write_list, Exporter};
+use crate::NetAddr;
+use core::fmt;
+
+/// Write a persistent table of both families.
+pub(super) fn write(
+	exporter: &Exporter<'_>,
+	out: &mut String,
+	v4: &[NetAddr],
+	v6: &[NetAddr],
+) -> fmt::Result {
+	out.push_str("table <");
+	out.push_str(exporter.name);
+	out.push_str("> persist");
+
+	if !v4.is_empty() || !v6.is_empty() {
+		out.push_str(" { ");
+		write_list(out, v4.iter().chain(v6))?;
+		out.push_str(" }");
+	}
+
+	out.push('\n');
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::tests::nets;
+	use super::super::ExportSyntax;
+	use super::*;
+
+	#[test]
+	fn golden() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Pf, "allowed").export(&nets()),
+			Ok(include_str!("../../tests/golden/pf.conf").to_string())
+		);
+	}
+
+	#[test]
+	fn empty() {
+		assert_eq!(
+			Exporter::new(ExportSyntax::Pf, "allowed").export(&[]),
+			Ok("table <allowed> persist\n".to_string())
+		);
+	}
+}
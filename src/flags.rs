@@ -0,0 +1,41 @@
+//! A macro for defining sets of flags as the system gives them.
+
+/// Define a `Copy` set of flags wrapping the bits the system gives, with a
+/// `const` for each named flag.
+macro_rules! flags {
+	(
+		$(#[$attr:meta])*
+		pub struct $name:ident($bits:ty);
+
+		$(
+			$(#[$flag_attr:meta])*
+			const $flag:ident = $value:expr;
+		)*
+	) => {
+		$(#[$attr])*
+		#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+		pub struct $name($bits);
+
+		impl $name {
+			$(
+				$(#[$flag_attr])*
+				pub const $flag: Self = Self($value);
+			)*
+
+			#[doc = concat!("Create `", stringify!($name), "` from the bits the system gives.")]
+			pub const fn from_bits(bits: $bits) -> Self {
+				Self(bits)
+			}
+
+			/// Get the bits of the flags.
+			pub const fn bits(self) -> $bits {
+				self.0
+			}
+
+			/// Determine whether every flag of `other` is set.
+			pub const fn contains(self, other: Self) -> bool {
+				self.0 & other.0 == other.0
+			}
+		}
+	};
+}
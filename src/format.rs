@@ -0,0 +1,326 @@
+//! Alternate renderings of networks, for generating configuration.
+
+use crate::NetAddr;
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// How to write the addresses of a network; see [`NetFormat::notation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Notation {
+	/// The usual notation: dotted-quad for IPv4, and compressed for IPv6, like
+	/// `2001:db8::`.
+	Standard,
+	/// Dotted-quad for IPv4, and every group written out in full for IPv6,
+	/// like `2001:0db8:0000:0000:0000:0000:0000:0000`.
+	Expanded,
+	/// Lowercase hexadecimal, zero-padded to the width of the address, like
+	/// `c0000200`.
+	LowerHex,
+	/// Uppercase hexadecimal, zero-padded to the width of the address, like
+	/// `C0000200`.
+	UpperHex,
+	/// Binary, zero-padded to the width of the address.  A prefix length is
+	/// written by marking the boundary within the bits of the address, like
+	/// `110000000000000000000010/00000000`.
+	Binary,
+	/// A decimal integer, like `3221225984`.
+	Integer,
+}
+
+/// How to write the mask of a network; see [`NetFormat::mask`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MaskNotation {
+	/// A prefix length, like `192.0.2.0/24`, or the netmask if it is not
+	/// contiguous.
+	PrefixLen,
+	/// The netmask, like `192.0.2.0 255.255.255.0`.
+	Netmask,
+	/// The wildcard (hostmask) as written in access lists, like
+	/// `192.0.2.0 0.0.0.255`.
+	Wildcard,
+}
+
+/// A builder for writing a network in a particular notation.
+///
+/// The `Display` implementations of `NetAddr`, `Netv4Addr`, and `Netv6Addr`
+/// give the usual notation, and their format flags pick a few others:
+///
+/// - `{:#}` writes the address and netmask, like `192.0.2.0 255.255.255.0`;
+/// - `{:x}` and `{:X}` write in hexadecimal, like `c0000200/24`;
+/// - `{:b}` writes in binary, marking the prefix boundary; and
+/// - `#` with `x`, `X`, or `b` adds a `0x` or `0b` prefix to each number.
+///
+/// `NetFormat` gives full control over the notation of the addresses, the
+/// notation of the mask, and the separator between them.  It is created with
+/// [`NetFormat::new`] or the `format` methods of the network types, and
+/// written with `Display`.  No allocation is needed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{MaskNotation, NetAddr, NetFormat, Netv4Addr, Notation};
+/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+///
+/// assert_eq!(format!("{:#}", net), "192.0.2.0 255.255.255.0");
+/// assert_eq!(format!("{:#x}", net), "0xc0000200/24");
+/// assert_eq!(format!("{:b}", net), "110000000000000000000010/00000000");
+/// assert_eq!(net.format().mask(MaskNotation::Wildcard).to_string(), "192.0.2.0 0.0.0.255");
+/// assert_eq!(net.format().notation(Notation::Integer).to_string(), "3221225984/24");
+///
+/// let net: NetAddr = "2001:db8::/32".parse().unwrap();
+///
+/// assert_eq!(
+///     net.format().notation(Notation::Expanded).to_string(),
+///     "2001:0db8:0000:0000:0000:0000:0000:0000/32",
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NetFormat {
+	net: NetAddr,
+	notation: Notation,
+	mask: MaskNotation,
+	separator: Option<char>,
+	radix_prefix: bool,
+}
+
+impl NetFormat {
+	/// Start formatting `net` in the usual notation.
+	pub fn new<N: Into<NetAddr>>(net: N) -> Self {
+		Self {
+			net: net.into(),
+			notation: Notation::Standard,
+			mask: MaskNotation::PrefixLen,
+			separator: None,
+			radix_prefix: false,
+		}
+	}
+
+	/// Write the address, and the mask if it is not a prefix length, in the
+	/// given notation.
+	pub const fn notation(self, notation: Notation) -> Self {
+		Self { notation, ..self }
+	}
+
+	/// Write the mask in the given notation.
+	pub const fn mask(self, mask: MaskNotation) -> Self {
+		Self { mask, ..self }
+	}
+
+	/// Separate the address and the mask with `separator`.
+	///
+	/// By default, this is a `/` before a prefix length and a space before a
+	/// netmask or wildcard, except for a non-contiguous netmask written in
+	/// place of a prefix length, which keeps the `/`.
+	pub const fn separator(self, separator: char) -> Self {
+		Self {
+			separator: Some(separator),
+			..self
+		}
+	}
+
+	/// Precede each hexadecimal or binary number with `0x` or `0b`.
+	pub const fn radix_prefix(self, radix_prefix: bool) -> Self {
+		Self {
+			radix_prefix,
+			..self
+		}
+	}
+
+	fn write_radix_prefix(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.notation {
+			Notation::LowerHex | Notation::UpperHex if self.radix_prefix => f.write_str("0x"),
+			Notation::Binary if self.radix_prefix => f.write_str("0b"),
+			_ => Ok(()),
+		}
+	}
+
+	/// Write the `bits`-wide number `value` in the chosen notation.
+	fn write_number(&self, f: &mut fmt::Formatter<'_>, value: u128, bits: u32) -> fmt::Result {
+		self.write_radix_prefix(f)?;
+
+		let (v4, v6) = (Ipv4Addr::from(value as u32), Ipv6Addr::from(value));
+		match (self.notation, bits) {
+			(Notation::Standard, 32) | (Notation::Expanded, 32) => write!(f, "{}", v4),
+			(Notation::Standard, _) => write!(f, "{}", v6),
+			(Notation::Expanded, _) => {
+				for (index, segment) in v6.segments().iter().enumerate() {
+					if index > 0 {
+						f.write_str(":")?;
+					}
+					write!(f, "{:04x}", segment)?;
+				}
+				Ok(())
+			}
+			(Notation::LowerHex, _) => write!(f, "{:01$x}", value, (bits / 4) as usize),
+			(Notation::UpperHex, _) => write!(f, "{:01$X}", value, (bits / 4) as usize),
+			(Notation::Binary, _) => write!(f, "{:01$b}", value, bits as usize),
+			(Notation::Integer, _) => write!(f, "{}", value),
+		}
+	}
+}
+
+impl fmt::Display for NetFormat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (addr, mask, bits) = self.net.parts();
+		let hostmask: u128 = !mask & (u128::MAX >> (128 - bits));
+
+		// Aligned to the top, a contiguous mask is ones followed by zeroes.
+		let aligned: u128 = mask << (128 - bits);
+		let prefix_len: Option<u32> = if aligned.leading_ones() + aligned.trailing_zeros() == 128 {
+			Some(aligned.leading_ones())
+		} else {
+			None
+		};
+
+		match (self.mask, prefix_len) {
+			(MaskNotation::PrefixLen, Some(len)) if self.notation == Notation::Binary => {
+				// Mark the boundary within the bits of the address.
+				self.write_radix_prefix(f)?;
+				if len > 0 {
+					write!(f, "{:01$b}", addr >> (bits - len), len as usize)?;
+				}
+				write!(f, "{}", self.separator.unwrap_or('/'))?;
+				if len < bits {
+					write!(f, "{:01$b}", addr & hostmask, (bits - len) as usize)?;
+				}
+				Ok(())
+			}
+			(MaskNotation::PrefixLen, Some(len)) => {
+				self.write_number(f, addr, bits)?;
+				write!(f, "{}{}", self.separator.unwrap_or('/'), len)
+			}
+			(MaskNotation::PrefixLen, None) => {
+				self.write_number(f, addr, bits)?;
+				write!(f, "{}", self.separator.unwrap_or('/'))?;
+				self.write_number(f, mask, bits)
+			}
+			(MaskNotation::Netmask, _) => {
+				self.write_number(f, addr, bits)?;
+				write!(f, "{}", self.separator.unwrap_or(' '))?;
+				self.write_number(f, mask, bits)
+			}
+			(MaskNotation::Wildcard, _) => {
+				self.write_number(f, addr, bits)?;
+				write!(f, "{}", self.separator.unwrap_or(' '))?;
+				self.write_number(f, hostmask, bits)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn format(net: &str) -> NetFormat {
+		NetFormat::new(net.parse::<NetAddr>().unwrap())
+	}
+
+	#[test]
+	fn standard() {
+		assert_eq!(format("192.0.2.0/24").to_string(), "192.0.2.0/24");
+		assert_eq!(
+			format("10.0.0.0/255.0.255.0").to_string(),
+			"10.0.0.0/255.0.255.0"
+		);
+		assert_eq!(format("2001:db8::/32").to_string(), "2001:db8::/32");
+	}
+
+	#[test]
+	fn expanded() {
+		let net = format("2001:db8::/ffff:0:ffff::").notation(Notation::Expanded);
+
+		assert_eq!(
+			net.to_string(),
+			"2001:0000:0000:0000:0000:0000:0000:0000/ffff:0000:ffff:0000:0000:0000:0000:0000"
+		);
+		assert_eq!(
+			format("192.0.2.0/24")
+				.notation(Notation::Expanded)
+				.to_string(),
+			"192.0.2.0/24"
+		);
+	}
+
+	#[test]
+	fn masks() {
+		let net = format("192.0.2.0/24");
+
+		assert_eq!(
+			net.mask(MaskNotation::Netmask).to_string(),
+			"192.0.2.0 255.255.255.0"
+		);
+		assert_eq!(
+			net.mask(MaskNotation::Wildcard).to_string(),
+			"192.0.2.0 0.0.0.255"
+		);
+		assert_eq!(
+			net.mask(MaskNotation::Wildcard).separator('/').to_string(),
+			"192.0.2.0/0.0.0.255"
+		);
+		assert_eq!(
+			format("2001:db8::/32")
+				.mask(MaskNotation::Wildcard)
+				.to_string(),
+			"2001:db8:: ::ffff:ffff:ffff:ffff:ffff:ffff"
+		);
+	}
+
+	#[test]
+	fn hex() {
+		let net = format("192.0.2.0/24");
+
+		assert_eq!(net.notation(Notation::LowerHex).to_string(), "c0000200/24");
+		assert_eq!(
+			net
+				.notation(Notation::UpperHex)
+				.radix_prefix(true)
+				.mask(MaskNotation::Netmask)
+				.to_string(),
+			"0xC0000200 0xFFFFFF00"
+		);
+		assert_eq!(
+			format("::1/128").notation(Notation::LowerHex).to_string(),
+			"00000000000000000000000000000001/128"
+		);
+	}
+
+	#[test]
+	fn binary_marks_prefix() {
+		let bits = |net: &str| format(net).notation(Notation::Binary).to_string();
+
+		assert_eq!(bits("192.0.2.0/24"), "110000000000000000000010/00000000");
+		assert_eq!(bits("0.0.0.0/0"), "/00000000000000000000000000000000");
+		assert_eq!(
+			bits("255.255.255.255/32"),
+			"11111111111111111111111111111111/"
+		);
+		assert_eq!(
+			bits("10.0.0.0/255.0.255.0"),
+			"00001010000000000000000000000000/11111111000000001111111100000000"
+		);
+		assert_eq!(
+			format("128.0.0.0/1")
+				.notation(Notation::Binary)
+				.radix_prefix(true)
+				.to_string(),
+			"0b1/0000000000000000000000000000000"
+		);
+	}
+
+	#[test]
+	fn integer() {
+		assert_eq!(
+			format("192.0.2.0/24")
+				.notation(Notation::Integer)
+				.to_string(),
+			"3221225984/24"
+		);
+		assert_eq!(
+			format("::ffff:0:0/96")
+				.notation(Notation::Integer)
+				.to_string(),
+			"281470681743360/96"
+		);
+	}
+}
@@ -0,0 +1,225 @@
+//! The PostgreSQL `inet` and `cidr` binary wire format.
+//!
+//! Both types are sent as the address family, the prefix length, whether the
+//! value is a `cidr`, the number of address bytes, and finally the address
+//! bytes themselves.  See `inet_send` and `inet_recv` in PostgreSQL's
+//! `src/backend/utils/adt/network.c`.
+
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// `PGSQL_AF_INET`, which PostgreSQL defines as `AF_INET + 0`.
+const PGSQL_AF_INET: u8 = 2;
+/// `PGSQL_AF_INET6`, which PostgreSQL defines as `AF_INET + 1`.
+const PGSQL_AF_INET6: u8 = PGSQL_AF_INET + 1;
+
+/// The OID of the `inet` type.
+#[cfg(feature = "sqlx")]
+pub(crate) const INET_OID: u32 = 869;
+/// The OID of the `cidr` type.
+#[cfg(feature = "sqlx")]
+pub(crate) const CIDR_OID: u32 = 650;
+/// The OID of the `inet[]` type.
+#[cfg(feature = "sqlx")]
+pub(crate) const INET_ARRAY_OID: u32 = 1041;
+/// The OID of the `cidr[]` type.
+#[cfg(feature = "sqlx")]
+pub(crate) const CIDR_ARRAY_OID: u32 = 651;
+
+/// The error given when encoding a network which has no prefix length.
+pub(crate) const NON_CIDR: &str = "network does not have a contiguous mask";
+
+/// Encode a network, marking it as a `cidr` value if `is_cidr` is set.
+///
+/// Networks without a contiguous mask cannot be represented.
+pub(crate) fn encode(net: &NetAddr, is_cidr: bool) -> Result<Vec<u8>, &'static str> {
+	if !net.is_cidr() {
+		return Err(NON_CIDR);
+	}
+
+	let mut buf = Vec::with_capacity(20);
+
+	match (net.addr(), net.mask()) {
+		(IpAddr::V4(addr), IpAddr::V4(mask)) => {
+			buf.push(PGSQL_AF_INET);
+			buf.push(u32::from(mask).count_ones() as u8);
+			buf.push(is_cidr.into());
+			buf.push(4);
+			buf.extend_from_slice(&addr.octets());
+		}
+		(IpAddr::V6(addr), IpAddr::V6(mask)) => {
+			buf.push(PGSQL_AF_INET6);
+			buf.push(u128::from(mask).count_ones() as u8);
+			buf.push(is_cidr.into());
+			buf.push(16);
+			buf.extend_from_slice(&addr.octets());
+		}
+		_ => unreachable!("a network's address and mask are always of the same family"),
+	}
+
+	Ok(buf)
+}
+
+/// Decode a network.
+///
+/// Any bits of an `inet` value's address outside of its prefix are discarded.
+pub(crate) fn decode(bytes: &[u8]) -> Result<NetAddr, &'static str> {
+	let (family, bits, _is_cidr, len, addr) = match bytes {
+		[family, bits, is_cidr, len, addr @ ..] => (*family, *bits, *is_cidr, *len, addr),
+		_ => return Err("invalid inet value: too short"),
+	};
+
+	if usize::from(len) != addr.len() {
+		return Err("invalid inet value: length does not match");
+	}
+
+	match (family, addr.len()) {
+		(PGSQL_AF_INET, 4) if bits <= 32 => {
+			let mut octets = [0_u8; 4];
+			octets.copy_from_slice(addr);
+			let mask: u32 = u32::MAX.checked_shl(32 - u32::from(bits)).unwrap_or(0);
+
+			Ok(NetAddr::V4(Netv4Addr::new(
+				Ipv4Addr::from(octets),
+				Ipv4Addr::from(mask),
+			)))
+		}
+		(PGSQL_AF_INET6, 16) if bits <= 128 => {
+			let mut octets = [0_u8; 16];
+			octets.copy_from_slice(addr);
+			let mask: u128 = u128::MAX.checked_shl(128 - u32::from(bits)).unwrap_or(0);
+
+			Ok(NetAddr::V6(Netv6Addr::new(
+				Ipv6Addr::from(octets),
+				Ipv6Addr::from(mask),
+			)))
+		}
+		(PGSQL_AF_INET, _) | (PGSQL_AF_INET6, _) => Err("invalid inet value: invalid length or bits"),
+		_ => Err("invalid inet value: unknown address family"),
+	}
+}
+
+/// Decode a network from PostgreSQL's text format, in which an `inet` value
+/// with a full-length prefix is written as a bare address.
+#[cfg(feature = "sqlx")]
+pub(crate) fn decode_text(text: &str) -> Result<NetAddr, &'static str> {
+	NetAddr::parse_ascii(text.as_bytes())
+		.ok()
+		.or_else(|| text.parse::<IpAddr>().ok().map(NetAddr::from))
+		.ok_or("invalid inet value: could not parse text")
+}
+
+/// Extract a `Netv4Addr` from a decoded network.
+pub(crate) fn v4(net: NetAddr) -> Result<Netv4Addr, &'static str> {
+	match net {
+		NetAddr::V4(net) => Ok(net),
+		NetAddr::V6(_) => Err("expected an IPv4 network, got an IPv6 network"),
+	}
+}
+
+/// Extract a `Netv6Addr` from a decoded network.
+pub(crate) fn v6(net: NetAddr) -> Result<Netv6Addr, &'static str> {
+	match net {
+		NetAddr::V6(net) => Ok(net),
+		NetAddr::V4(_) => Err("expected an IPv6 network, got an IPv4 network"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Captured from PostgreSQL 15 with `SELECT inet_send(...)` and
+	// `SELECT cidr_send(...)`.
+
+	/// `SELECT inet_send('192.168.1.0/24'::inet)`
+	const INET_V4: &[u8] = &[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x00];
+	/// `SELECT cidr_send('10.0.0.0/8'::cidr)`
+	const CIDR_V4: &[u8] = &[0x02, 0x08, 0x01, 0x04, 0x0a, 0x00, 0x00, 0x00];
+	/// `SELECT inet_send('192.168.1.5/24'::inet)`
+	const INET_V4_HOST_BITS: &[u8] = &[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x05];
+	/// `SELECT inet_send('2001:db8::/32'::inet)`
+	const INET_V6: &[u8] = &[
+		0x03, 0x20, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00,
+	];
+	/// `SELECT cidr_send('::1/128'::cidr)`
+	const CIDR_V6: &[u8] = &[
+		0x03, 0x80, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x01,
+	];
+
+	#[test]
+	fn encodes_captured_bytes() {
+		assert_eq!(
+			encode(&pu!("192.168.1.0/24" # NetAddr), false).unwrap(),
+			INET_V4
+		);
+		assert_eq!(encode(&pu!("10.0.0.0/8" # NetAddr), true).unwrap(), CIDR_V4);
+		assert_eq!(
+			encode(&pu!("2001:db8::/32" # NetAddr), false).unwrap(),
+			INET_V6
+		);
+		assert_eq!(encode(&pu!("::1/128" # NetAddr), true).unwrap(), CIDR_V6);
+	}
+
+	#[test]
+	fn decodes_captured_bytes() {
+		assert_eq!(decode(INET_V4), Ok(pu!("192.168.1.0/24" # NetAddr)));
+		assert_eq!(decode(CIDR_V4), Ok(pu!("10.0.0.0/8" # NetAddr)));
+		assert_eq!(decode(INET_V6), Ok(pu!("2001:db8::/32" # NetAddr)));
+		assert_eq!(decode(CIDR_V6), Ok(pu!("::1/128" # NetAddr)));
+	}
+
+	#[test]
+	fn decoding_discards_host_bits() {
+		assert_eq!(
+			decode(INET_V4_HOST_BITS),
+			Ok(pu!("192.168.1.0/24" # NetAddr))
+		);
+	}
+
+	#[test]
+	fn encoding_non_cidr_fails() {
+		assert_eq!(
+			encode(&pu!("10.0.0.0/255.0.255.0" # NetAddr), false),
+			Err(NON_CIDR)
+		);
+	}
+
+	#[test]
+	fn decoding_malformed_fails() {
+		assert!(decode(&[]).is_err());
+		assert!(decode(&INET_V4[..7]).is_err());
+		assert!(decode(&[0x02, 0x21, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x00]).is_err());
+		assert!(decode(&[0x02, 0x08, 0x00, 0x10, 0x0a, 0x00, 0x00, 0x00]).is_err());
+		assert!(decode(&[0x03, 0x08, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x00]).is_err());
+		assert!(decode(&[0x07, 0x08, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x00]).is_err());
+	}
+
+	#[test]
+	#[cfg(feature = "sqlx")]
+	fn decodes_text() {
+		assert_eq!(
+			decode_text("192.168.1.0/24"),
+			Ok(pu!("192.168.1.0/24" # NetAddr))
+		);
+		assert_eq!(
+			decode_text("192.168.1.5"),
+			Ok(pu!("192.168.1.5/32" # NetAddr))
+		);
+		assert_eq!(
+			decode_text("2001:db8::/32"),
+			Ok(pu!("2001:db8::/32" # NetAddr))
+		);
+		assert!(decode_text("not an address").is_err());
+	}
+
+	#[test]
+	fn extracts_families() {
+		assert!(v4(pu!("10.0.0.0/8" # NetAddr)).is_ok());
+		assert!(v4(pu!("::/0" # NetAddr)).is_err());
+		assert!(v6(pu!("::/0" # NetAddr)).is_ok());
+		assert!(v6(pu!("10.0.0.0/8" # NetAddr)).is_err());
+	}
+}
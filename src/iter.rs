@@ -1,14 +1,16 @@
 //! Traits for iteration over `NetAddr` types.
 
 mod address;
-mod offset;
+pub(crate) mod host;
+mod permutation;
+mod shuffle;
 #[cfg(feature = "unstable")]
 mod sibling;
 #[cfg(feature = "unstable")]
 mod subnet;
 
 pub use address::*;
-#[cfg(feature = "unstable")]
-pub use sibling::*;
+pub use host::Hosts;
+pub use shuffle::*;
 #[cfg(feature = "unstable")]
 pub use subnet::*;
@@ -1,6 +1,6 @@
 use crate::Contains;
 
-use super::offset::Offset;
+use crate::traits::Offset;
 
 /// An iterator over a network's _contained addresses_.
 pub struct AddressIterator<Network, Address>
@@ -62,7 +62,7 @@ mod tests {
 		use super::*;
 
 		use crate::Netv4Addr;
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		impl crate::Netv4Addr {
 			pub fn iter(&self) -> AddressIterator<Netv4Addr, Ipv4Addr> {
@@ -113,7 +113,7 @@ mod tests {
 		use super::*;
 
 		use crate::NetAddr;
-		use std::net::IpAddr;
+		use core::net::IpAddr;
 
 		mod v4 {
 			use super::*;
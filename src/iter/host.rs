@@ -0,0 +1,168 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl Sealed for crate::NetAddr {}
+	impl Sealed for crate::Netv4Addr {}
+	impl Sealed for crate::Netv6Addr {}
+}
+
+/// Index-based access to the addresses of a network.
+///
+/// The addresses of a network are numbered by filling its host bits (the
+/// zeroes in its netmask) from least to most significant, which works the same
+/// way for non-CIDR netmasks as it does for CIDR ones.
+///
+/// This trait is sealed, and is implemented for `NetAddr`, `Netv4Addr`, and
+/// `Netv6Addr`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Hosts, Netv4Addr};
+/// # use std::net::Ipv4Addr;
+/// let net: Netv4Addr = "10.0.0.0/255.255.0.255".parse().unwrap();
+///
+/// assert_eq!(net.host_bits(), 8);
+/// assert_eq!(net.host(1), Ipv4Addr::new(10, 0, 1, 0));
+/// ```
+pub trait Hosts: sealed::Sealed {
+	/// The type of the addresses in the network
+	type Address;
+
+	/// The number of host bits (zeroes) in the netmask.
+	fn host_bits(&self) -> u32;
+
+	/// Produce the `index`th address in the network.
+	///
+	/// Bits of `index` beyond [`Hosts::host_bits`] are ignored.
+	fn host(&self, index: u128) -> Self::Address;
+}
+
+/// Scatter the low bits of `n` into the set bits of `mask`, lowest first, also
+/// returning whether any bits of `n` were left over.
+pub(crate) fn deposit(mut n: u128, mask: u128) -> (u128, bool) {
+	let mut remaining: u128 = mask;
+	let mut result: u128 = 0;
+
+	while remaining != 0 && n != 0 {
+		let bit: u128 = remaining & remaining.wrapping_neg();
+		if n & 1 == 1 {
+			result |= bit;
+		}
+		n >>= 1;
+		remaining &= remaining - 1;
+	}
+
+	(result, n != 0)
+}
+
+impl Hosts for Netv4Addr {
+	type Address = Ipv4Addr;
+
+	fn host_bits(&self) -> u32 {
+		u32::from(self.mask()).count_zeros()
+	}
+
+	fn host(&self, index: u128) -> Self::Address {
+		let host_mask: u128 = u128::from(!u32::from(self.mask()));
+		// `deposit` only sets bits within `host_mask`, so this fits in a `u32`.
+		#[allow(clippy::cast_possible_truncation)]
+		let host: u32 = deposit(index, host_mask).0 as u32;
+		Ipv4Addr::from(u32::from(self.addr()) | host)
+	}
+}
+
+impl Hosts for Netv6Addr {
+	type Address = Ipv6Addr;
+
+	fn host_bits(&self) -> u32 {
+		u128::from(self.mask()).count_zeros()
+	}
+
+	fn host(&self, index: u128) -> Self::Address {
+		let host: u128 = deposit(index, !u128::from(self.mask())).0;
+		Ipv6Addr::from(u128::from(self.addr()) | host)
+	}
+}
+
+impl Hosts for NetAddr {
+	type Address = IpAddr;
+
+	fn host_bits(&self) -> u32 {
+		match self {
+			Self::V4(v4) => v4.host_bits(),
+			Self::V6(v6) => v6.host_bits(),
+		}
+	}
+
+	fn host(&self, index: u128) -> Self::Address {
+		match self {
+			Self::V4(v4) => IpAddr::V4(v4.host(index)),
+			Self::V6(v6) => IpAddr::V6(v6.host(index)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn deposit_cidr_is_identity() {
+		assert_eq!(deposit(0b1011, 0xff), (0b1011, false));
+	}
+
+	#[test]
+	fn deposit_non_cidr_scatters_bits() {
+		assert_eq!(deposit(0b11, 0b1010), (0b1010, false));
+		assert_eq!(deposit(0b01, 0b1010), (0b0010, false));
+		assert_eq!(deposit(0b10, 0b1010), (0b1000, false));
+	}
+
+	#[test]
+	fn deposit_reports_leftover_bits() {
+		assert_eq!(deposit(0b101, 0b1101_0000), (0b1001_0000, false));
+		assert_eq!(deposit(0b1000, 0b1101_0000), (0, true));
+		assert_eq!(deposit(1, 0), (0, true));
+		assert_eq!(deposit(0, 0), (0, false));
+		assert_eq!(deposit(0b101, 0b1101 << 124), (0b1001 << 124, false));
+		assert_eq!(deposit(u128::MAX, u128::MAX), (u128::MAX, false));
+	}
+
+	#[test]
+	fn netv4addr_cidr() {
+		let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+		assert_eq!(net.host_bits(), 8);
+		assert_eq!(net.host(0), Ipv4Addr::new(192, 0, 2, 0));
+		assert_eq!(net.host(42), Ipv4Addr::new(192, 0, 2, 42));
+	}
+
+	#[test]
+	fn netv4addr_non_cidr() {
+		let net: Netv4Addr = "10.0.0.0/255.255.0.255".parse().unwrap();
+		assert_eq!(net.host_bits(), 8);
+		assert_eq!(net.host(1), Ipv4Addr::new(10, 0, 1, 0));
+		assert_eq!(net.host(255), Ipv4Addr::new(10, 0, 255, 0));
+	}
+
+	#[test]
+	fn netv6addr_cidr() {
+		let net: Netv6Addr = "2001:db8::/64".parse().unwrap();
+		assert_eq!(net.host_bits(), 64);
+		assert_eq!(
+			net.host(0x1234),
+			"2001:db8::1234".parse::<Ipv6Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn netaddr_dispatches() {
+		let net: NetAddr = "192.0.2.0/30".parse().unwrap();
+		assert_eq!(net.host_bits(), 2);
+		assert_eq!(net.host(3), "192.0.2.3".parse::<IpAddr>().unwrap());
+	}
+}
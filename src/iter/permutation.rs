@@ -0,0 +1,106 @@
+/// The number of mixing rounds applied by a [`Permutation`].
+const ROUNDS: usize = 4;
+
+/// A keyed bijection over the integers `0..2^bits`.
+///
+/// Each round is made up of steps which are individually invertible modulo
+/// `2^bits` (an `xor` with a round key, a multiplication by an odd constant,
+/// and a right xorshift), so the composition is a permutation of the domain.
+/// Only the round keys are kept, so the state is constant-sized no matter how
+/// large the domain is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Permutation {
+	bits: u32,
+	keys: [(u128, u128); ROUNDS],
+}
+
+impl Permutation {
+	/// Create a new permutation of `0..2^bits`, keyed by `seed`.
+	pub(crate) fn new(bits: u32, seed: u64) -> Self {
+		debug_assert!(bits <= 128);
+
+		let mut state: u64 = seed;
+		let mut keys: [(u128, u128); ROUNDS] = [(0, 0); ROUNDS];
+
+		for key in &mut keys {
+			let xor: u128 = u128::from(splitmix64(&mut state)) << 64 | u128::from(splitmix64(&mut state));
+			let mul: u128 = u128::from(splitmix64(&mut state)) << 64 | u128::from(splitmix64(&mut state));
+			*key = (xor, mul | 1);
+		}
+
+		Self { bits, keys }
+	}
+
+	/// The largest value in the domain of this permutation.
+	pub(crate) fn max(&self) -> u128 {
+		u128::MAX.checked_shr(128 - self.bits).unwrap_or(0)
+	}
+
+	/// Compute the image of `value`, which must lie within the domain.
+	pub(crate) fn apply(&self, value: u128) -> u128 {
+		let max: u128 = self.max();
+		let shift: u32 = self.bits.div_ceil(2).max(1);
+
+		self.keys.iter().fold(value & max, |x, &(xor, mul)| {
+			let x: u128 = (x ^ xor) & max;
+			let x: u128 = x.wrapping_mul(mul) & max;
+			x ^ (x >> shift)
+		})
+	}
+}
+
+/// One step of the `splitmix64` generator, used to expand a seed into keys.
+fn splitmix64(state: &mut u64) -> u64 {
+	*state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+	let mut z: u64 = *state;
+	z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+	z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Permutation;
+	use std::collections::HashSet;
+
+	#[test]
+	fn empty_domain_maps_zero_to_zero() {
+		let permutation = Permutation::new(0, 42);
+		assert_eq!(permutation.max(), 0);
+		assert_eq!(permutation.apply(0), 0);
+	}
+
+	#[test]
+	fn is_a_bijection_for_small_domains() {
+		for bits in 0..=12 {
+			let permutation = Permutation::new(bits, 0xdead_beef);
+			let image: HashSet<u128> = (0..=permutation.max())
+				.map(|value| permutation.apply(value))
+				.collect();
+
+			assert_eq!(image.len() as u128, permutation.max() + 1);
+			assert!(image.iter().all(|&value| value <= permutation.max()));
+		}
+	}
+
+	#[test]
+	fn full_domain_does_not_overflow() {
+		let permutation = Permutation::new(128, 7);
+		assert_eq!(permutation.max(), u128::MAX);
+		assert_ne!(permutation.apply(u128::MAX), permutation.apply(0));
+	}
+
+	#[test]
+	fn same_seed_is_reproducible() {
+		let a = Permutation::new(16, 1234);
+		let b = Permutation::new(16, 1234);
+		assert!((0..=a.max()).all(|value| a.apply(value) == b.apply(value)));
+	}
+
+	#[test]
+	fn different_seeds_differ() {
+		let a = Permutation::new(16, 1);
+		let b = Permutation::new(16, 2);
+		assert!((0..=a.max()).any(|value| a.apply(value) != b.apply(value)));
+	}
+}
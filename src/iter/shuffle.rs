@@ -0,0 +1,398 @@
+use core::marker::PhantomData;
+
+use super::host::Hosts;
+use super::permutation::Permutation;
+
+/// An iterator over a network's _contained addresses_ in a pseudo-random
+/// order.
+///
+/// Every address of the network (or of each network in a set of networks) is
+/// visited exactly once.  The order is determined by a keyed permutation of the
+/// address indices, so iterators created with the same seed produce the same
+/// sequence, and the state kept is constant-sized regardless of how many
+/// addresses there are.
+///
+/// The iteration can be split across several workers with
+/// [`ShuffledAddressIterator::shard`]; shards created from the same networks
+/// and seed are disjoint and together cover every address.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv4Addr;
+/// # use std::collections::HashSet;
+/// # use std::net::Ipv4Addr;
+/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+///
+/// let addrs: Vec<Ipv4Addr> = net.shuffled(42).collect();
+/// assert_eq!(addrs.len(), 256);
+/// assert_eq!(addrs.iter().collect::<HashSet<_>>().len(), 256);
+///
+/// // The same seed always gives the same order.
+/// assert!(net.shuffled(42).eq(addrs.iter().copied()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShuffledAddressIterator<Networks, Network> {
+	nets: Networks,
+	permutation: Permutation,
+	last: u128,
+	next: Option<u128>,
+	step: u128,
+	network: PhantomData<Network>,
+}
+
+/// The largest index of a network with `bits` host bits.
+fn last_index(bits: u32) -> u128 {
+	u128::MAX.checked_shr(128 - bits).unwrap_or(0)
+}
+
+impl<Networks, Network> ShuffledAddressIterator<Networks, Network>
+where
+	Networks: AsRef<[Network]>,
+	Network: Hosts,
+{
+	/// Create an iterator over the addresses of every network in `nets`.
+	///
+	/// The networks are expected to be disjoint; addresses covered by more than
+	/// one of them are produced once per network.
+	///
+	/// Returns `None` if `nets` is empty or if the networks contain more than
+	/// `2^128` addresses in total.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Netv4Addr, ShuffledAddressIterator};
+	/// let nets: [Netv4Addr; 2] = ["192.0.2.0/28".parse().unwrap(), "198.51.100.0/29".parse().unwrap()];
+	///
+	/// let it = ShuffledAddressIterator::new(nets, 7).unwrap();
+	/// assert_eq!(it.count(), 16 + 8);
+	/// ```
+	pub fn new(nets: Networks, seed: u64) -> Option<Self> {
+		let mut total: Option<u128> = None;
+
+		for net in nets.as_ref() {
+			let net_last: u128 = last_index(net.host_bits());
+			total = Some(match total {
+				None => net_last,
+				Some(total) => total.checked_add(net_last)?.checked_add(1)?,
+			});
+		}
+
+		total.map(|last: u128| Self::with_last(nets, last, seed))
+	}
+
+	fn with_last(nets: Networks, last: u128, seed: u64) -> Self {
+		let bits: u32 = 128 - last.leading_zeros();
+
+		Self {
+			nets,
+			permutation: Permutation::new(bits, seed),
+			last,
+			next: Some(0),
+			step: 1,
+			network: PhantomData,
+		}
+	}
+
+	/// Restrict this iterator to shard `index` of `count`.
+	///
+	/// Sharding interleaves the underlying permutation, so every shard visits
+	/// addresses spread across all of the networks.  This should be called
+	/// before iteration starts.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is not less than `count`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// # use std::collections::HashSet;
+	/// # use std::net::Ipv4Addr;
+	/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+	///
+	/// let mut seen: HashSet<Ipv4Addr> = HashSet::new();
+	/// for shard in 0..3 {
+	///     for addr in net.shuffled(1).shard(shard, 3) {
+	///         assert!(seen.insert(addr));
+	///     }
+	/// }
+	/// assert_eq!(seen.len(), 256);
+	/// ```
+	#[must_use]
+	pub fn shard(mut self, index: u128, count: u128) -> Self {
+		assert!(
+			index < count,
+			"shard index must be less than the shard count"
+		);
+
+		self.next = Some(index).filter(|&index| index <= self.permutation.max());
+		self.step = count;
+		self
+	}
+
+	/// Find the address for a given index across all of the networks.
+	fn locate(&self, mut index: u128) -> Option<Network::Address> {
+		for net in self.nets.as_ref() {
+			let net_last: u128 = last_index(net.host_bits());
+
+			if index <= net_last {
+				return Some(net.host(index));
+			}
+
+			index -= net_last + 1;
+		}
+
+		None
+	}
+}
+
+impl<Network> ShuffledAddressIterator<[Network; 1], Network>
+where
+	Network: Hosts,
+{
+	pub(crate) fn single(net: Network, seed: u64) -> Self {
+		let last: u128 = last_index(net.host_bits());
+		Self::with_last([net], last, seed)
+	}
+}
+
+/// Implementation of the [`Iterator`] trait for [`ShuffledAddressIterator`].
+impl<Networks, Network> Iterator for ShuffledAddressIterator<Networks, Network>
+where
+	Networks: AsRef<[Network]>,
+	Network: Hosts,
+{
+	type Item = Network::Address;
+
+	/// Produce the next item.
+	///
+	/// The permutation runs over a power-of-two domain, so indices past the last
+	/// address are skipped ("cycle walking"); at most half of the domain is ever
+	/// skipped.
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(counter) = self.next {
+			let max: u128 = self.permutation.max();
+			self.next = counter.checked_add(self.step).filter(|&next| next <= max);
+
+			let index: u128 = self.permutation.apply(counter);
+
+			if index <= self.last {
+				return self.locate(index);
+			}
+		}
+
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ShuffledAddressIterator;
+	use std::collections::HashSet;
+
+	mod netv4addr {
+		use super::*;
+
+		use crate::Netv4Addr;
+		use core::net::Ipv4Addr;
+
+		#[test]
+		fn visits_every_address_once() {
+			let net: Netv4Addr = "10.0.0.0/20".parse().unwrap();
+
+			let shuffled: Vec<Ipv4Addr> = net.shuffled(0).collect();
+			let unique: HashSet<Ipv4Addr> = shuffled.iter().copied().collect();
+			let expected: HashSet<Ipv4Addr> = (0..4096_u32)
+				.map(|offset| Ipv4Addr::from(u32::from(net.addr()) + offset))
+				.collect();
+
+			assert_eq!(shuffled.len(), 4096);
+			assert_eq!(unique, expected);
+		}
+
+		#[test]
+		fn is_not_sequential() {
+			let net: Netv4Addr = "10.0.0.0/20".parse().unwrap();
+
+			let shuffled: Vec<Ipv4Addr> = net.shuffled(0).collect();
+			let sorted: Vec<Ipv4Addr> = {
+				let mut sorted = shuffled.clone();
+				sorted.sort();
+				sorted
+			};
+
+			assert_ne!(shuffled, sorted);
+		}
+
+		#[test]
+		fn slash_32_produces_one_off() {
+			let net: Netv4Addr = "127.0.16.1/32".parse().unwrap();
+
+			let mut it = net.shuffled(99);
+			assert_eq!(it.next(), "127.0.16.1".parse::<Ipv4Addr>().ok());
+			assert_eq!(it.next(), None);
+		}
+
+		#[test]
+		fn non_cidr_visits_only_contained_addresses() {
+			use crate::Contains;
+
+			let net: Netv4Addr = "10.0.0.0/255.255.0.252".parse().unwrap();
+
+			let shuffled: HashSet<Ipv4Addr> = net.shuffled(3).collect();
+			assert_eq!(shuffled.len(), 1024);
+			assert!(shuffled.iter().all(|addr| net.contains(addr)));
+		}
+
+		#[test]
+		fn same_seed_is_reproducible() {
+			let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+			assert!(net.shuffled(5).eq(net.shuffled(5)));
+		}
+
+		#[test]
+		fn different_seeds_differ() {
+			let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+			assert!(!net.shuffled(5).eq(net.shuffled(6)));
+		}
+
+		#[test]
+		fn shards_are_disjoint_and_complete() {
+			let net: Netv4Addr = "192.0.2.0/23".parse().unwrap();
+
+			let mut seen: HashSet<Ipv4Addr> = HashSet::new();
+			for index in 0..5 {
+				for addr in net.shuffled(11).shard(index, 5) {
+					assert!(seen.insert(addr));
+				}
+			}
+
+			assert_eq!(seen.len(), 512);
+		}
+
+		#[test]
+		fn more_shards_than_addresses() {
+			let net: Netv4Addr = "192.0.2.0/31".parse().unwrap();
+
+			let total: usize = (0..8)
+				.map(|index| net.shuffled(0).shard(index, 8).count())
+				.sum();
+			assert_eq!(total, 2);
+		}
+
+		#[test]
+		#[should_panic]
+		fn shard_out_of_range_panics() {
+			let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+			let _ = net.shuffled(0).shard(3, 3);
+		}
+
+		#[test]
+		fn set_of_networks_visits_every_address_once() {
+			let nets: Vec<Netv4Addr> = vec![
+				"192.0.2.0/28".parse().unwrap(),
+				"198.51.100.0/30".parse().unwrap(),
+				"203.0.113.7/32".parse().unwrap(),
+			];
+
+			let shuffled: Vec<Ipv4Addr> = ShuffledAddressIterator::new(nets.clone(), 1)
+				.unwrap()
+				.collect();
+			let unique: HashSet<Ipv4Addr> = shuffled.iter().copied().collect();
+
+			assert_eq!(shuffled.len(), 16 + 4 + 1);
+			assert_eq!(unique.len(), 16 + 4 + 1);
+			assert!(unique.contains(&"203.0.113.7".parse().unwrap()));
+		}
+
+		#[test]
+		fn empty_set_returns_none() {
+			let nets: [Netv4Addr; 0] = [];
+			assert!(ShuffledAddressIterator::new(nets, 0).is_none());
+		}
+
+		#[test]
+		fn whole_address_space_does_not_overflow() {
+			let net: Netv4Addr = "0.0.0.0/0".parse().unwrap();
+			assert_eq!(net.shuffled(0).take(16).count(), 16);
+		}
+	}
+
+	mod netv6addr {
+		use super::*;
+
+		use crate::Netv6Addr;
+		use core::net::Ipv6Addr;
+
+		#[test]
+		fn visits_every_address_once() {
+			let net: Netv6Addr = "2001:db8::/116".parse().unwrap();
+
+			let shuffled: Vec<Ipv6Addr> = net.shuffled(0).collect();
+			let unique: HashSet<Ipv6Addr> = shuffled.iter().copied().collect();
+
+			assert_eq!(shuffled.len(), 4096);
+			assert_eq!(unique.len(), 4096);
+		}
+
+		#[test]
+		fn shards_are_disjoint_and_complete() {
+			let net: Netv6Addr = "2001:db8::/120".parse().unwrap();
+
+			let mut seen: HashSet<Ipv6Addr> = HashSet::new();
+			for index in 0..4 {
+				for addr in net.shuffled(11).shard(index, 4) {
+					assert!(seen.insert(addr));
+				}
+			}
+
+			assert_eq!(seen.len(), 256);
+		}
+
+		#[test]
+		fn whole_address_space_does_not_overflow() {
+			let net: Netv6Addr = "::/0".parse().unwrap();
+			let first: HashSet<Ipv6Addr> = net.shuffled(0).take(64).collect();
+			assert_eq!(first.len(), 64);
+		}
+
+		#[test]
+		fn overflowing_set_returns_none() {
+			let nets: [Netv6Addr; 2] = ["::/0".parse().unwrap(), "2001:db8::/128".parse().unwrap()];
+			assert!(ShuffledAddressIterator::new(nets, 0).is_none());
+		}
+	}
+
+	mod netaddr {
+		use super::*;
+
+		use crate::NetAddr;
+		use core::net::IpAddr;
+
+		#[test]
+		fn mixed_families_visit_every_address_once() {
+			let nets: [NetAddr; 2] = [
+				"192.0.2.0/26".parse().unwrap(),
+				"2001:db8::/122".parse().unwrap(),
+			];
+
+			let shuffled: HashSet<IpAddr> = ShuffledAddressIterator::new(nets, 8).unwrap().collect();
+
+			assert_eq!(shuffled.len(), 128);
+			assert_eq!(shuffled.iter().filter(|addr| addr.is_ipv4()).count(), 64);
+		}
+
+		#[test]
+		fn matches_address_iterator() {
+			let net: NetAddr = "2001:db8::/120".parse().unwrap();
+
+			let shuffled: HashSet<IpAddr> = net.shuffled(0).collect();
+			let sequential: HashSet<IpAddr> = net.iter().collect();
+
+			assert_eq!(shuffled, sequential);
+		}
+	}
+}
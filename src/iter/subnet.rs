@@ -2,7 +2,7 @@ use crate::Contains;
 
 use crate::{Netv4Addr, Netv6Addr};
 
-use super::offset::Offset;
+use crate::traits::Offset;
 
 pub struct SubnetIterator<Network, Subnet> {
 	net: Network,
@@ -23,16 +23,11 @@ impl Iterator for SubnetIterator<Netv4Addr, Netv4Addr> {
 					device_count.and_then(|device_count: u32| cur.offset(device_count));
 
 				match next {
-					Some(next) => {
-						if self.net.contains(&next) {
-							self.cur = Some(next);
-							Some(cur)
-						} else {
-							self.cur = None;
-							Some(cur)
-						}
+					Some(next) if self.net.contains(&next) => {
+						self.cur = Some(next);
+						Some(cur)
 					}
-					None => {
+					_ => {
 						self.cur = None;
 						Some(cur)
 					}
@@ -59,16 +54,11 @@ impl Iterator for SubnetIterator<Netv6Addr, Netv6Addr> {
 					device_count.and_then(|device_count: u128| cur.offset(device_count));
 
 				match next {
-					Some(next) => {
-						if self.net.contains(&next) {
-							self.cur = Some(next);
-							Some(cur)
-						} else {
-							self.cur = None;
-							Some(cur)
-						}
+					Some(next) if self.net.contains(&next) => {
+						self.cur = Some(next);
+						Some(cur)
 					}
-					None => {
+					_ => {
 						self.cur = None;
 						Some(cur)
 					}
@@ -89,7 +79,7 @@ mod tests {
 		use super::*;
 
 		use crate::Netv4Addr;
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		impl crate::Netv4Addr {
 			pub fn subnets(&self, mask: Ipv4Addr) -> SubnetIterator<Netv4Addr, Netv4Addr> {
@@ -170,7 +160,7 @@ mod tests {
 		use super::*;
 
 		use crate::Netv6Addr;
-		use std::net::Ipv6Addr;
+		use core::net::Ipv6Addr;
 
 		impl crate::Netv6Addr {
 			pub fn subnets(&self, mask: Ipv6Addr) -> SubnetIterator<Netv6Addr, Netv6Addr> {
@@ -12,6 +12,12 @@
 //! The API is strikingly similar to that of the `std::net::Ip.*Addr` structs,
 //! and users who have used that set of structs will hopefully find this API
 //! quite naturally similar.
+//!
+//! This crate is `no_std`-compatible; it is built on the `core::net` types, and
+//! none of its operations allocate.  The `std` feature is enabled by default,
+//! and can be turned off with `default-features = false`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 #[cfg(test)]
 #[macro_use]
@@ -23,16 +29,77 @@ mod util {
 	}
 }
 
+#[cfg(feature = "std")]
+mod acl;
+mod class;
 mod error;
+#[cfg(feature = "std")]
+mod export;
+#[cfg(any(feature = "linux", all(feature = "local-networks", unix)))]
+#[macro_use]
+mod flags;
+mod format;
+#[cfg(any(feature = "diesel", feature = "postgres-types", feature = "sqlx"))]
+mod inet;
 mod iter;
+#[cfg(all(feature = "local-networks", unix))]
+mod local_network;
+#[macro_use]
+mod macros;
 mod netaddr;
+#[cfg(feature = "std")]
+mod netlist;
+mod netmask;
+mod netmaskv6;
 mod netv4addr;
 mod netv6addr;
+mod parser;
+#[cfg(feature = "schemars")]
+mod pattern;
+mod prefix_len;
+mod relation;
+mod scoped;
+mod socket_net;
 mod traits;
+#[cfg(feature = "clap")]
+mod value_parser;
+mod zone_id;
+
+#[cfg(feature = "linux")]
+pub mod linux;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategy;
+
+/// Items used by the macros of this crate, which are not part of its API.
+#[doc(hidden)]
+pub mod __private {
+	pub use crate::parser::literal::{netaddr, netv4addr, netv6addr};
+}
 
+#[cfg(feature = "std")]
+pub use acl::*;
+pub use class::*;
 pub use error::*;
+#[cfg(feature = "std")]
+pub use export::*;
+pub use format::*;
 pub use iter::*;
+#[cfg(all(feature = "local-networks", unix))]
+pub use local_network::*;
 pub use netaddr::*;
+#[cfg(feature = "std")]
+pub use netlist::*;
+pub use netmask::*;
+pub use netmaskv6::*;
 pub use netv4addr::*;
 pub use netv6addr::*;
+pub use prefix_len::*;
+pub use relation::*;
+pub use scoped::*;
+pub use socket_net::*;
 pub use traits::*;
+#[cfg(feature = "clap")]
+pub use value_parser::*;
+pub use zone_id::*;
@@ -0,0 +1,63 @@
+//! Reading the routes and addresses of a Linux host.
+//!
+//! The parsers here turn the text of the kernel's `/proc/net` files and the
+//! JSON output of iproute2's `ip -j` into typed records, with networks as
+//! [`Netv4Addr`](crate::Netv4Addr), [`Netv6Addr`](crate::Netv6Addr), or
+//! [`NetAddr`](crate::NetAddr).  They take the text as a string, so they can be
+//! used on any platform, such as with output collected from another host; on
+//! Linux, the `read_*` functions read the live files.
+//!
+//! - [`parse_route`] and [`read_route`] handle `/proc/net/route`, the IPv4
+//!   routing table, whose addresses are hexadecimal in little-endian order;
+//! - [`parse_ipv6_route`] and [`read_ipv6_route`] handle `/proc/net/ipv6_route`,
+//!   the IPv6 routing table;
+//! - [`parse_if_inet6`] and [`read_if_inet6`] handle `/proc/net/if_inet6`, the
+//!   IPv6 addresses of each interface;
+//! - [`parse_ip_route_json`] and [`parse_ip6_route_json`] handle the output of
+//!   `ip -j route` and `ip -j -6 route`; and
+//! - [`parse_ip_addr_json`] handles the output of `ip -j addr`.
+//!
+//! Interface addresses keep their host bits in a separate field from the
+//! network they are on, since networks only hold their network address.
+//!
+//! This module requires the `linux` feature.
+//!
+//! ```
+//! use netaddr2::linux::{parse_route, RouteFlags};
+//!
+//! let routes = parse_route(
+//!     "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n\
+//!      eth0\t00000000\t010200C0\t0003\t0\t0\t100\t00000000\t0\t0\t0\n\
+//!      eth0\t000200C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0\n",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(routes[0].destination, "0.0.0.0/0".parse().unwrap());
+//! assert_eq!(routes[0].gateway, Some("192.0.2.1".parse().unwrap()));
+//! assert!(routes[0].flags.contains(RouteFlags::GATEWAY));
+//! assert_eq!(routes[1].destination, "192.0.2.0/24".parse().unwrap());
+//! ```
+
+flags! {
+	/// The `RTF_` flags of a kernel route.
+	pub struct RouteFlags(u32);
+
+	/// The route is usable.
+	const UP = 0x0001;
+	/// The route goes through a gateway.
+	const GATEWAY = 0x0002;
+	/// The route is to a single host.
+	const HOST = 0x0004;
+	/// The route was created by a redirect.
+	const DYNAMIC = 0x0010;
+	/// The route was modified by a redirect.
+	const MODIFIED = 0x0020;
+	/// The route rejects packets.
+	const REJECT = 0x0200;
+}
+
+mod iproute2;
+mod proc;
+
+pub use iproute2::*;
+pub use proc::*;
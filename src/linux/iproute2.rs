@@ -0,0 +1,408 @@
+use crate::{Error, NetAddr, Netv4Addr, Netv6Addr, PrefixLen, Result};
+use core::convert::TryFrom;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use serde_json::{Map, Value};
+
+/// A route from the output of `ip -j route`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpRoute {
+	/// The destination network
+	pub destination: NetAddr,
+	/// The type of the route, such as `unreachable` or `local`, if it is not
+	/// an ordinary unicast route
+	pub kind: Option<String>,
+	/// The gateway, if the route goes through one
+	pub gateway: Option<IpAddr>,
+	/// The interface the route goes out of
+	pub interface: Option<String>,
+	/// The preferred source address for packets taking the route
+	pub source: Option<IpAddr>,
+	/// The routing protocol which added the route, such as `kernel` or `dhcp`
+	pub protocol: Option<String>,
+	/// The scope of the route, such as `link` or `host`
+	pub scope: Option<String>,
+	/// The metric, or priority, of the route
+	pub metric: Option<u32>,
+	/// The flags of the route, such as `onlink` or `linkdown`
+	pub flags: Vec<String>,
+}
+
+/// An interface from the output of `ip -j addr`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpInterface {
+	/// The index of the interface
+	pub index: u32,
+	/// The name of the interface
+	pub name: String,
+	/// The flags of the interface, such as `UP` or `LOOPBACK`
+	pub flags: Vec<String>,
+	/// The MTU of the interface
+	pub mtu: Option<u32>,
+	/// The addresses of the interface
+	pub addrs: Vec<IpInterfaceAddr>,
+}
+
+/// An address of an [`IpInterface`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpInterfaceAddr {
+	/// The address, including its host bits
+	pub addr: IpAddr,
+	/// The network the address is on
+	pub net: NetAddr,
+	/// The scope of the address, such as `global` or `link`
+	pub scope: Option<String>,
+	/// The label of the address, which for IPv4 is usually the interface name
+	pub label: Option<String>,
+}
+
+fn array<'a>(value: &'a Value, what: &'static str) -> Result<&'a Vec<Value>> {
+	value.as_array().ok_or(Error::ParseError(what))
+}
+
+fn object(value: &Value) -> Result<&Map<String, Value>> {
+	value
+		.as_object()
+		.ok_or(Error::ParseError("expected a JSON object"))
+}
+
+fn string(object: &Map<String, Value>, key: &str) -> Option<String> {
+	object.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn number(object: &Map<String, Value>, key: &str) -> Result<Option<u32>> {
+	match object.get(key) {
+		None => Ok(None),
+		Some(value) => value
+			.as_u64()
+			.and_then(|number| u32::try_from(number).ok())
+			.map(Some)
+			.ok_or(Error::ParseError("expected an unsigned integer")),
+	}
+}
+
+fn addr(object: &Map<String, Value>, key: &str) -> Result<Option<IpAddr>> {
+	match object.get(key).and_then(Value::as_str) {
+		None => Ok(None),
+		Some(addr) => addr
+			.parse()
+			.map(Some)
+			.map_err(|_| Error::ParseError("invalid address")),
+	}
+}
+
+fn strings(object: &Map<String, Value>, key: &str) -> Vec<String> {
+	object
+		.get(key)
+		.and_then(Value::as_array)
+		.map(|values| {
+			values
+				.iter()
+				.filter_map(Value::as_str)
+				.map(str::to_string)
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// The address family of a route.
+#[derive(Clone, Copy)]
+enum Family {
+	V4,
+	V6,
+}
+
+impl From<IpAddr> for Family {
+	fn from(addr: IpAddr) -> Self {
+		match addr {
+			IpAddr::V4(_) => Self::V4,
+			IpAddr::V6(_) => Self::V6,
+		}
+	}
+}
+
+/// Find the family of a route, if the route says what it is: the family it is
+/// listed with, its gateway or preferred source, or the gateway of one of its
+/// `nexthops`, in that order.
+fn family(
+	route: &Map<String, Value>,
+	gateway: Option<IpAddr>,
+	source: Option<IpAddr>,
+) -> Result<Option<Family>> {
+	match route.get("family").and_then(Value::as_str) {
+		Some("inet") => return Ok(Some(Family::V4)),
+		Some("inet6") => return Ok(Some(Family::V6)),
+		_ => {}
+	}
+
+	if let Some(addr) = gateway.or(source) {
+		return Ok(Some(addr.into()));
+	}
+
+	match route.get("nexthops") {
+		None => Ok(None),
+		Some(nexthops) => array(nexthops, "expected a JSON array of next hops")?
+			.iter()
+			.map(|nexthop| addr(object(nexthop)?, "gateway"))
+			.find_map(Result::transpose)
+			.map(|addr| addr.map(Family::from))
+			.transpose(),
+	}
+}
+
+fn parse_routes(json: &str, default_family: Family) -> Result<Vec<IpRoute>> {
+	let value: Value = serde_json::from_str(json).map_err(|_| Error::ParseError("invalid JSON"))?;
+
+	array(&value, "expected a JSON array of routes")?
+		.iter()
+		.map(|route| {
+			let route = object(route)?;
+			let gateway: Option<IpAddr> = addr(route, "gateway")?;
+			let source: Option<IpAddr> = addr(route, "prefsrc")?;
+
+			let destination: NetAddr = match route.get("dst").and_then(Value::as_str) {
+				None => return Err(Error::ParseError("route has no destination")),
+				Some("default") => match family(route, gateway, source)?.unwrap_or(default_family) {
+					Family::V4 => NetAddr::V4(Netv4Addr::from_prefix(Ipv4Addr::UNSPECIFIED, 0)),
+					Family::V6 => NetAddr::V6(Netv6Addr::from_prefix(Ipv6Addr::UNSPECIFIED, 0)),
+				},
+				Some(dst) if dst.contains('/') => dst.parse()?,
+				Some(dst) => dst
+					.parse::<IpAddr>()
+					.map(NetAddr::from)
+					.map_err(|_| Error::ParseError("invalid destination"))?,
+			};
+
+			Ok(IpRoute {
+				destination,
+				kind: string(route, "type"),
+				gateway,
+				interface: string(route, "dev"),
+				source,
+				protocol: string(route, "protocol"),
+				scope: string(route, "scope"),
+				metric: number(route, "metric")?,
+				flags: strings(route, "flags"),
+			})
+		})
+		.collect()
+}
+
+/// Parse the output of `ip -j route`, or of `ip -j -4 route` and the like,
+/// which list IPv4 routes.
+///
+/// A `default` destination is the `/0` network of the family of the route,
+/// going by the family it is listed with, its gateway or preferred source, or
+/// the gateways of its `nexthops`; a route which gives none of those, like a
+/// device route or an `unreachable` one, is taken to be IPv4.  A destination
+/// without a prefix length is a route to a single host.
+pub fn parse_ip_route_json(json: &str) -> Result<Vec<IpRoute>> {
+	parse_routes(json, Family::V4)
+}
+
+/// Parse the output of `ip -j -6 route`, which lists IPv6 routes.
+///
+/// This is the same as [`parse_ip_route_json`], except that a `default` route
+/// which does not give its family is taken to be IPv6.
+pub fn parse_ip6_route_json(json: &str) -> Result<Vec<IpRoute>> {
+	parse_routes(json, Family::V6)
+}
+
+/// Parse the output of `ip -j addr`.
+///
+/// Address entries without a `local` address, as `ip` gives for some tunnel
+/// and link-layer details, are skipped.
+pub fn parse_ip_addr_json(json: &str) -> Result<Vec<IpInterface>> {
+	let value: Value = serde_json::from_str(json).map_err(|_| Error::ParseError("invalid JSON"))?;
+
+	array(&value, "expected a JSON array of interfaces")?
+		.iter()
+		.map(|interface| {
+			let interface = object(interface)?;
+			let addrs: &[Value] = match interface.get("addr_info") {
+				None => &[],
+				Some(addrs) => array(addrs, "expected a JSON array of addresses")?,
+			};
+
+			let addrs: Vec<IpInterfaceAddr> = addrs
+				.iter()
+				.map(|info| {
+					let info = object(info)?;
+					let addr: IpAddr = match addr(info, "local")? {
+						Some(addr) => addr,
+						None => return Ok(None),
+					};
+					let len: PrefixLen = number(info, "prefixlen")?
+						.and_then(|len| u8::try_from(len).ok())
+						.and_then(PrefixLen::new)
+						.ok_or(Error::InvalidPrefixLen)?;
+
+					Ok(Some(IpInterfaceAddr {
+						addr,
+						net: NetAddr::with_prefix_len(addr, len)?,
+						scope: string(info, "scope"),
+						label: string(info, "label"),
+					}))
+				})
+				.filter_map(Result::transpose)
+				.collect::<Result<_>>()?;
+
+			Ok(IpInterface {
+				index: number(interface, "ifindex")?.ok_or(Error::ParseError("interface has no index"))?,
+				name: string(interface, "ifname").ok_or(Error::ParseError("interface has no name"))?,
+				flags: strings(interface, "flags"),
+				mtu: number(interface, "mtu")?,
+				addrs,
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ROUTE: &str = r#"[
+		{"dst":"default","gateway":"192.0.2.1","dev":"eth0","protocol":"dhcp","metric":100,"flags":[]},
+		{"dst":"192.0.2.0/24","dev":"eth0","protocol":"kernel","scope":"link","prefsrc":"192.0.2.2","flags":["linkdown"]},
+		{"type":"unreachable","dst":"198.51.100.7","flags":[]},
+		{"dst":"default","gateway":"fe80::1","dev":"eth0","metric":1024,"flags":[],"pref":"medium"}
+	]"#;
+
+	const ADDR: &str = r#"[
+		{"ifindex":1,"ifname":"lo","flags":["LOOPBACK","UP","LOWER_UP"],"mtu":65536,"operstate":"UNKNOWN","addr_info":[
+			{"family":"inet","local":"127.0.0.1","prefixlen":8,"scope":"host","label":"lo","valid_life_time":4294967295},
+			{"family":"inet6","local":"::1","prefixlen":128,"scope":"host","valid_life_time":4294967295}
+		]},
+		{"ifindex":4,"ifname":"eth0","flags":["BROADCAST","MULTICAST","UP"],"mtu":1500,"addr_info":[
+			{"family":"inet","local":"192.0.2.2","prefixlen":24,"broadcast":"192.0.2.255","scope":"global","label":"eth0"},
+			{"family":"inet6","local":"fe80::fc:ff:fe00:2","prefixlen":64,"scope":"link"},
+			{}
+		]},
+		{"ifindex":5,"ifname":"dummy0","flags":["BROADCAST","NOARP"],"mtu":1500,"addr_info":[]}
+	]"#;
+
+	#[test]
+	fn routes() {
+		let routes = parse_ip_route_json(ROUTE).unwrap();
+
+		assert_eq!(
+			routes[0],
+			IpRoute {
+				destination: pu!("0.0.0.0/0" # NetAddr),
+				kind: None,
+				gateway: Some("192.0.2.1".parse().unwrap()),
+				interface: Some("eth0".to_string()),
+				source: None,
+				protocol: Some("dhcp".to_string()),
+				scope: None,
+				metric: Some(100),
+				flags: vec![],
+			}
+		);
+		assert_eq!(routes[1].destination, pu!("192.0.2.0/24" # NetAddr));
+		assert_eq!(routes[1].source, Some("192.0.2.2".parse().unwrap()));
+		assert_eq!(routes[1].flags, vec!["linkdown".to_string()]);
+		assert_eq!(routes[2].destination, pu!("198.51.100.7/32" # NetAddr));
+		assert_eq!(routes[2].kind.as_deref(), Some("unreachable"));
+		assert_eq!(routes[2].interface, None);
+		assert_eq!(routes[3].destination, pu!("::/0" # NetAddr));
+	}
+
+	#[test]
+	fn default_routes_without_gateways() {
+		let routes = parse_ip6_route_json(
+			r#"[
+				{"dst":"default","dev":"wg0","metric":1024,"flags":[]},
+				{"type":"unreachable","dst":"default","dev":"lo","metric":4294967295,"flags":[]}
+			]"#,
+		)
+		.unwrap();
+
+		assert_eq!(routes[0].destination, pu!("::/0" # NetAddr));
+		assert_eq!(routes[1].destination, pu!("::/0" # NetAddr));
+
+		let routes = parse_ip_route_json(r#"[{"dst":"default","dev":"wg0","flags":[]}]"#).unwrap();
+		assert_eq!(routes[0].destination, pu!("0.0.0.0/0" # NetAddr));
+	}
+
+	#[test]
+	fn default_routes_give_their_family() {
+		let routes = parse_ip_route_json(
+			r#"[
+				{"family":"inet6","type":"unreachable","dst":"default","flags":[]},
+				{"dst":"default","protocol":"static","metric":1024,"nexthops":[
+					{"gateway":"fe80::1","dev":"eth0","weight":1,"flags":[]},
+					{"gateway":"fe80::2","dev":"eth1","weight":1,"flags":[]}
+				],"flags":[]}
+			]"#,
+		)
+		.unwrap();
+
+		assert_eq!(routes[0].destination, pu!("::/0" # NetAddr));
+		assert_eq!(routes[1].destination, pu!("::/0" # NetAddr));
+		assert_eq!(routes[1].gateway, None);
+
+		let routes = parse_ip6_route_json(
+			r#"[{"dst":"default","nexthops":[{"dev":"eth0"},{"gateway":"192.0.2.1","dev":"eth1"}],"flags":[]}]"#,
+		)
+		.unwrap();
+		assert_eq!(routes[0].destination, pu!("0.0.0.0/0" # NetAddr));
+	}
+
+	#[test]
+	fn interfaces() {
+		let interfaces = parse_ip_addr_json(ADDR).unwrap();
+
+		assert_eq!(interfaces.len(), 3);
+		assert_eq!(
+			interfaces[1],
+			IpInterface {
+				index: 4,
+				name: "eth0".to_string(),
+				flags: vec![
+					"BROADCAST".to_string(),
+					"MULTICAST".to_string(),
+					"UP".to_string()
+				],
+				mtu: Some(1500),
+				addrs: vec![
+					IpInterfaceAddr {
+						addr: "192.0.2.2".parse().unwrap(),
+						net: pu!("192.0.2.0/24" # NetAddr),
+						scope: Some("global".to_string()),
+						label: Some("eth0".to_string()),
+					},
+					IpInterfaceAddr {
+						addr: "fe80::fc:ff:fe00:2".parse().unwrap(),
+						net: pu!("fe80::/64" # NetAddr),
+						scope: Some("link".to_string()),
+						label: None,
+					},
+				],
+			}
+		);
+		assert_eq!(interfaces[0].addrs[0].net, pu!("127.0.0.0/8" # NetAddr));
+		assert!(interfaces[2].addrs.is_empty());
+	}
+
+	#[test]
+	fn errors() {
+		assert!(parse_ip_route_json("{").is_err());
+		assert!(parse_ip_route_json("{}").is_err());
+		assert!(parse_ip_route_json(r#"[{"dev":"eth0"}]"#).is_err());
+		assert!(parse_ip_route_json(r#"[{"dst":"bogus"}]"#).is_err());
+		assert!(parse_ip_route_json(r#"[{"dst":"default","metric":-1}]"#).is_err());
+		assert!(parse_ip6_route_json(r#"[{"dst":"default","nexthops":{}}]"#).is_err());
+		assert!(
+			parse_ip6_route_json(r#"[{"dst":"default","nexthops":[{"gateway":"bogus"}]}]"#).is_err()
+		);
+		assert_eq!(
+			parse_ip_addr_json(
+				r#"[{"ifindex":1,"ifname":"lo","addr_info":[{"local":"127.0.0.1","prefixlen":33}]}]"#
+			),
+			Err(Error::InvalidPrefixLen)
+		);
+		assert!(parse_ip_addr_json(r#"[{"ifname":"lo"}]"#).is_err());
+		assert_eq!(parse_ip_route_json("[]"), Ok(vec![]));
+	}
+}
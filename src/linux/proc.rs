@@ -0,0 +1,335 @@
+use super::RouteFlags;
+use crate::{Error, Netv4Addr, Netv6Addr, PrefixLen, Result};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// A route from `/proc/net/route`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Route {
+	/// The interface the route goes out of
+	pub interface: String,
+	/// The destination network
+	pub destination: Netv4Addr,
+	/// The gateway, if the route goes through one
+	pub gateway: Option<Ipv4Addr>,
+	/// The flags of the route
+	pub flags: RouteFlags,
+	/// The metric, or priority, of the route
+	pub metric: u32,
+	/// The MTU of the route, or zero if it has none of its own
+	pub mtu: u32,
+}
+
+/// A route from `/proc/net/ipv6_route`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ipv6Route {
+	/// The interface the route goes out of
+	pub interface: String,
+	/// The destination network
+	pub destination: Netv6Addr,
+	/// The source network, which is `::/0` unless the route is source-specific
+	pub source: Netv6Addr,
+	/// The next hop, if the route goes through one
+	pub next_hop: Option<Ipv6Addr>,
+	/// The flags of the route
+	pub flags: RouteFlags,
+	/// The metric, or priority, of the route
+	pub metric: u32,
+}
+
+/// An IPv6 interface address from `/proc/net/if_inet6`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Inet6Addr {
+	/// The name of the interface
+	pub interface: String,
+	/// The index of the interface
+	pub index: u32,
+	/// The address, including its host bits
+	pub addr: Ipv6Addr,
+	/// The network the address is on
+	pub net: Netv6Addr,
+	/// The scope of the address, such as `0x00` for global or `0x20` for
+	/// link-local
+	pub scope: u8,
+	/// The `IFA_F_` flags of the address
+	pub flags: u8,
+}
+
+fn hex_u32(field: &str) -> Result<u32> {
+	u32::from_str_radix(field, 16).map_err(|_| Error::ParseError("invalid hexadecimal field"))
+}
+
+fn hex_u8(field: &str) -> Result<u8> {
+	u8::from_str_radix(field, 16).map_err(|_| Error::ParseError("invalid hexadecimal field"))
+}
+
+/// Parse an IPv4 address written as a hexadecimal number in little-endian
+/// order, so that `0102A8C0` is `192.168.2.1`.
+fn hex_ipv4addr(field: &str) -> Result<Ipv4Addr> {
+	if field.len() != 8 {
+		return Err(Error::ParseError("invalid hexadecimal IPv4 address"));
+	}
+
+	Ok(Ipv4Addr::from(hex_u32(field)?.swap_bytes()))
+}
+
+/// Parse an IPv6 address written as 32 hexadecimal digits.
+fn hex_ipv6addr(field: &str) -> Result<Ipv6Addr> {
+	if field.len() != 32 {
+		return Err(Error::ParseError("invalid hexadecimal IPv6 address"));
+	}
+
+	u128::from_str_radix(field, 16)
+		.map(Ipv6Addr::from)
+		.map_err(|_| Error::ParseError("invalid hexadecimal IPv6 address"))
+}
+
+/// Parse an IPv6 network from an address and a hexadecimal prefix length.
+fn hex_netv6addr(addr: &str, len: &str) -> Result<Netv6Addr> {
+	let len: PrefixLen = PrefixLen::new(hex_u8(len)?).ok_or(Error::InvalidPrefixLen)?;
+
+	Ok(Netv6Addr::with_prefix_len(hex_ipv6addr(addr)?, len))
+}
+
+/// Get the whitespace-separated fields of each line with at least `count`
+/// fields, skipping blank lines and a header starting with `header`.
+fn records<'a>(
+	text: &'a str,
+	header: Option<&'static str>,
+	count: usize,
+) -> impl Iterator<Item = Result<Vec<&'a str>>> + 'a {
+	text
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.filter(move |line| header.is_none_or(|header| !line.starts_with(header)))
+		.map(move |line| {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+
+			if fields.len() < count {
+				Err(Error::ParseError("too few fields"))
+			} else {
+				Ok(fields)
+			}
+		})
+}
+
+/// Parse the IPv4 routing table in the format of `/proc/net/route`.
+///
+/// The header line is skipped if it is present.
+pub fn parse_route(text: &str) -> Result<Vec<Route>> {
+	records(text, Some("Iface"), 11)
+		.map(|fields| {
+			let fields = fields?;
+			let gateway: Ipv4Addr = hex_ipv4addr(fields[2])?;
+
+			Ok(Route {
+				interface: fields[0].to_string(),
+				destination: Netv4Addr::new(hex_ipv4addr(fields[1])?, hex_ipv4addr(fields[7])?),
+				gateway: Some(gateway).filter(|gateway| !gateway.is_unspecified()),
+				flags: RouteFlags::from_bits(hex_u32(fields[3])?),
+				metric: fields[6]
+					.parse()
+					.map_err(|_| Error::ParseError("invalid metric"))?,
+				mtu: fields[8]
+					.parse()
+					.map_err(|_| Error::ParseError("invalid MTU"))?,
+			})
+		})
+		.collect()
+}
+
+/// Parse the IPv6 routing table in the format of `/proc/net/ipv6_route`.
+pub fn parse_ipv6_route(text: &str) -> Result<Vec<Ipv6Route>> {
+	records(text, None, 10)
+		.map(|fields| {
+			let fields = fields?;
+			let next_hop: Ipv6Addr = hex_ipv6addr(fields[4])?;
+
+			Ok(Ipv6Route {
+				interface: fields[9].to_string(),
+				destination: hex_netv6addr(fields[0], fields[1])?,
+				source: hex_netv6addr(fields[2], fields[3])?,
+				next_hop: Some(next_hop).filter(|next_hop| !next_hop.is_unspecified()),
+				flags: RouteFlags::from_bits(hex_u32(fields[8])?),
+				metric: hex_u32(fields[5])?,
+			})
+		})
+		.collect()
+}
+
+/// Parse the IPv6 interface addresses in the format of `/proc/net/if_inet6`.
+pub fn parse_if_inet6(text: &str) -> Result<Vec<Inet6Addr>> {
+	records(text, None, 6)
+		.map(|fields| {
+			let fields = fields?;
+			let addr: Ipv6Addr = hex_ipv6addr(fields[0])?;
+			let len: PrefixLen = PrefixLen::new(hex_u8(fields[2])?).ok_or(Error::InvalidPrefixLen)?;
+
+			Ok(Inet6Addr {
+				interface: fields[5].to_string(),
+				index: hex_u32(fields[1])?,
+				addr,
+				net: Netv6Addr::with_prefix_len(addr, len),
+				scope: hex_u8(fields[3])?,
+				flags: hex_u8(fields[4])?,
+			})
+		})
+		.collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read<T>(path: &str, parse: fn(&str) -> Result<T>) -> std::io::Result<T> {
+	let text: String = std::fs::read_to_string(path)?;
+
+	parse(&text).map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+}
+
+/// Read the IPv4 routing table from `/proc/net/route`.
+#[cfg(target_os = "linux")]
+pub fn read_route() -> std::io::Result<Vec<Route>> {
+	read("/proc/net/route", parse_route)
+}
+
+/// Read the IPv6 routing table from `/proc/net/ipv6_route`.
+#[cfg(target_os = "linux")]
+pub fn read_ipv6_route() -> std::io::Result<Vec<Ipv6Route>> {
+	read("/proc/net/ipv6_route", parse_ipv6_route)
+}
+
+/// Read the IPv6 interface addresses from `/proc/net/if_inet6`.
+#[cfg(target_os = "linux")]
+pub fn read_if_inet6() -> std::io::Result<Vec<Inet6Addr>> {
+	read("/proc/net/if_inet6", parse_if_inet6)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ROUTE: &str = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t00000000\t0102A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t0002A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t1400\t0\t0
+wg0\t0000000A\t00000000\t0205\t0\t0\t0\t00FF00FF\t0\t0\t0
+";
+
+	const IPV6_ROUTE: &str = "\
+20010db8000000000000000000000000 20 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 20010db8000000000000000000000000 30 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+00000000000000000000000000000001 80 00000000000000000000000000000000 00 00000000000000000000000000000000 00000000 00000002 00000000 80200001       lo
+";
+
+	const IF_INET6: &str = "\
+20010db8000000000000000000000002 04 40 00 80     eth0
+00000000000000000000000000000001 01 80 10 80       lo
+fe8000000000000000fc00fffe000001 04 40 20 80     eth0
+";
+
+	#[test]
+	fn routes() {
+		let routes = parse_route(ROUTE).unwrap();
+
+		assert_eq!(
+			routes,
+			vec![
+				Route {
+					interface: "eth0".to_string(),
+					destination: pu!("0.0.0.0/0" # Netv4Addr),
+					gateway: Some(Ipv4Addr::new(192, 168, 2, 1)),
+					flags: RouteFlags::from_bits(0x3),
+					metric: 100,
+					mtu: 0,
+				},
+				Route {
+					interface: "eth0".to_string(),
+					destination: pu!("192.168.2.0/24" # Netv4Addr),
+					gateway: None,
+					flags: RouteFlags::UP,
+					metric: 100,
+					mtu: 1400,
+				},
+				Route {
+					interface: "wg0".to_string(),
+					destination: pu!("10.0.0.0/255.0.255.0" # Netv4Addr),
+					gateway: None,
+					flags: RouteFlags::from_bits(0x205),
+					metric: 0,
+					mtu: 0,
+				},
+			]
+		);
+		assert!(
+			routes[2].flags.contains(RouteFlags::REJECT) && routes[2].flags.contains(RouteFlags::HOST)
+		);
+		assert!(!routes[1].flags.contains(RouteFlags::GATEWAY));
+	}
+
+	#[test]
+	fn ipv6_routes() {
+		let routes = parse_ipv6_route(IPV6_ROUTE).unwrap();
+
+		assert_eq!(routes.len(), 3);
+		assert_eq!(
+			routes[1],
+			Ipv6Route {
+				interface: "eth0".to_string(),
+				destination: pu!("::/0" # Netv6Addr),
+				source: pu!("2001:db8::/48" # Netv6Addr),
+				next_hop: Some("fe80::1".parse().unwrap()),
+				flags: RouteFlags::from_bits(0x3),
+				metric: 1024,
+			}
+		);
+		assert_eq!(routes[0].destination, pu!("2001:db8::/32" # Netv6Addr));
+		assert_eq!(routes[0].next_hop, None);
+		assert_eq!(routes[2].interface, "lo");
+		assert_eq!(routes[2].destination, pu!("::1/128" # Netv6Addr));
+	}
+
+	#[test]
+	fn if_inet6() {
+		let addrs = parse_if_inet6(IF_INET6).unwrap();
+
+		assert_eq!(
+			addrs[2],
+			Inet6Addr {
+				interface: "eth0".to_string(),
+				index: 4,
+				addr: "fe80::fc:ff:fe00:1".parse().unwrap(),
+				net: pu!("fe80::/64" # Netv6Addr),
+				scope: 0x20,
+				flags: 0x80,
+			}
+		);
+		assert_eq!(addrs[0].addr, "2001:db8::2".parse::<Ipv6Addr>().unwrap());
+		assert_eq!(addrs[1].net, pu!("::1/128" # Netv6Addr));
+	}
+
+	#[test]
+	fn errors() {
+		assert!(parse_route("eth0\t00000000\t00000000\t0001\t0\t0\t0\t00000000").is_err());
+		assert!(parse_route("eth0\t0000000\t00000000\t0001\t0\t0\t0\t00000000\t0\t0\t0").is_err());
+		assert!(parse_route("eth0\t0000000Z\t00000000\t0001\t0\t0\t0\t00000000\t0\t0\t0").is_err());
+		assert!(parse_route("eth0\t00000000\t00000000\t0001\t0\t0\t-1\t00000000\t0\t0\t0").is_err());
+		assert_eq!(
+			parse_if_inet6("00000000000000000000000000000001 01 81 10 80 lo"),
+			Err(Error::InvalidPrefixLen)
+		);
+		assert!(parse_ipv6_route("00000000000000000000000000000001 80").is_err());
+		assert_eq!(parse_route(""), Ok(vec![]));
+	}
+
+	#[cfg(target_os = "linux")]
+	#[test]
+	fn reads_live_files() {
+		// These are missing in some sandboxes, but must parse where present.
+		for result in &[
+			read_route().map(drop),
+			read_ipv6_route().map(drop),
+			read_if_inet6().map(drop),
+		] {
+			if let Err(error) = result {
+				assert_ne!(error.kind(), std::io::ErrorKind::InvalidData, "{}", error);
+			}
+		}
+	}
+}
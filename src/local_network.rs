@@ -0,0 +1,315 @@
+//! Listing the networks this host's interfaces are on.
+
+use crate::{Contains, NetAddr, Netv4Addr, Netv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ffi::CStr;
+use std::io;
+
+flags! {
+	/// The `IFF_` flags of a network interface.
+	pub struct InterfaceFlags(u32);
+
+	/// The interface is administratively up.
+	const UP = libc::IFF_UP as u32;
+	/// The interface has a broadcast address.
+	const BROADCAST = libc::IFF_BROADCAST as u32;
+	/// The interface is a loopback interface.
+	const LOOPBACK = libc::IFF_LOOPBACK as u32;
+	/// The interface is a point-to-point link.
+	const POINTOPOINT = libc::IFF_POINTOPOINT as u32;
+	/// The interface has resources allocated and can carry packets.
+	const RUNNING = libc::IFF_RUNNING as u32;
+	/// The interface supports multicast.
+	const MULTICAST = libc::IFF_MULTICAST as u32;
+}
+
+/// An address of one of this host's interfaces, and the network it is on.
+///
+/// An interface with several addresses has a `LocalNetwork` for each of them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LocalNetwork {
+	name: String,
+	flags: InterfaceFlags,
+	addr: IpAddr,
+	net: NetAddr,
+}
+
+impl LocalNetwork {
+	/// Get the name of the interface, like `eth0`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Get the flags of the interface.
+	pub const fn flags(&self) -> InterfaceFlags {
+		self.flags
+	}
+
+	/// Get the address of the interface, including its host bits.
+	pub const fn addr(&self) -> IpAddr {
+		self.addr
+	}
+
+	/// Get the network the address is on.
+	pub const fn net(&self) -> NetAddr {
+		self.net
+	}
+}
+
+/// Frees the list from `getifaddrs` when dropped.
+struct IfAddrs(*mut libc::ifaddrs);
+
+impl Drop for IfAddrs {
+	fn drop(&mut self) {
+		// SAFETY: the list came from a successful call to `getifaddrs`, and is
+		// freed only here.
+		unsafe { libc::freeifaddrs(self.0) }
+	}
+}
+
+/// Copy the `sockaddr` at `sockaddr` into a zeroed `T`, which must be a kind of
+/// `sockaddr`.
+///
+/// On the BSDs and macOS, a netmask can be shorter than its kind of
+/// `sockaddr`, leaving out trailing zeroes, so only as many bytes as its
+/// `sa_len` gives are read.
+unsafe fn read_sockaddr<T>(sockaddr: *const libc::sockaddr) -> T {
+	let len: usize = core::mem::size_of::<T>();
+	#[cfg(any(
+		target_os = "dragonfly",
+		target_os = "freebsd",
+		target_os = "ios",
+		target_os = "macos",
+		target_os = "netbsd",
+		target_os = "openbsd",
+	))]
+	let len: usize = len.min(usize::from((*sockaddr).sa_len));
+
+	let mut out: core::mem::MaybeUninit<T> = core::mem::MaybeUninit::zeroed();
+	core::ptr::copy_nonoverlapping(sockaddr.cast::<u8>(), out.as_mut_ptr().cast::<u8>(), len);
+	out.assume_init()
+}
+
+/// Read the IPv4 address from `sockaddr`, which must point to a `sockaddr_in`.
+unsafe fn ipv4addr(sockaddr: *const libc::sockaddr) -> Ipv4Addr {
+	let sockaddr: libc::sockaddr_in = read_sockaddr(sockaddr);
+
+	Ipv4Addr::from(u32::from_be(sockaddr.sin_addr.s_addr))
+}
+
+/// Read the IPv6 address from `sockaddr`, which must point to a `sockaddr_in6`.
+unsafe fn ipv6addr(sockaddr: *const libc::sockaddr) -> Ipv6Addr {
+	let sockaddr: libc::sockaddr_in6 = read_sockaddr(sockaddr);
+
+	Ipv6Addr::from(sockaddr.sin6_addr.s6_addr)
+}
+
+/// List the addresses of this host's interfaces, with the networks they are
+/// on, using `getifaddrs`.
+///
+/// Interfaces without an IPv4 or IPv6 address are left out, and an address
+/// without a netmask is taken to be on a network of just itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{local_networks, Contains, InterfaceFlags};
+/// for local in local_networks().unwrap() {
+///     assert!(local.net().contains(&local.addr()));
+///
+///     if local.flags().contains(InterfaceFlags::LOOPBACK) {
+///         assert!(!local.name().is_empty());
+///     }
+/// }
+/// ```
+pub fn local_networks() -> io::Result<Vec<LocalNetwork>> {
+	let mut list: *mut libc::ifaddrs = core::ptr::null_mut();
+
+	// SAFETY: `list` is a valid place for `getifaddrs` to write the list to.
+	if unsafe { libc::getifaddrs(&mut list) } != 0 {
+		return Err(io::Error::last_os_error());
+	}
+
+	let list = IfAddrs(list);
+	let mut networks: Vec<LocalNetwork> = Vec::new();
+	let mut entry: *const libc::ifaddrs = list.0;
+
+	while !entry.is_null() {
+		// SAFETY: `entry` is an element of the list, which lives until `list`
+		// is dropped.
+		let ifaddr: &libc::ifaddrs = unsafe { &*entry };
+		entry = ifaddr.ifa_next;
+
+		if ifaddr.ifa_addr.is_null() {
+			continue;
+		}
+
+		// SAFETY: `ifa_addr` is a non-null `sockaddr`, whose family says which
+		// kind of `sockaddr` it is, and the netmask is of the same family.
+		let (addr, net): (IpAddr, NetAddr) = unsafe {
+			let has_mask: bool = !ifaddr.ifa_netmask.is_null();
+
+			match i32::from((*ifaddr.ifa_addr).sa_family) {
+				libc::AF_INET => {
+					let addr: Ipv4Addr = ipv4addr(ifaddr.ifa_addr);
+					let mask: Ipv4Addr = if has_mask {
+						ipv4addr(ifaddr.ifa_netmask)
+					} else {
+						Ipv4Addr::from(u32::MAX)
+					};
+
+					(addr.into(), Netv4Addr::new(addr, mask).into())
+				}
+				libc::AF_INET6 => {
+					let addr: Ipv6Addr = ipv6addr(ifaddr.ifa_addr);
+					let mask: Ipv6Addr = if has_mask {
+						ipv6addr(ifaddr.ifa_netmask)
+					} else {
+						Ipv6Addr::from(u128::MAX)
+					};
+
+					(addr.into(), Netv6Addr::new(addr, mask).into())
+				}
+				_ => continue,
+			}
+		};
+
+		// SAFETY: `ifa_name` is a NUL-terminated string which lives as long as
+		// the list.
+		let name: String = unsafe { CStr::from_ptr(ifaddr.ifa_name) }
+			.to_string_lossy()
+			.into_owned();
+
+		networks.push(LocalNetwork {
+			name,
+			flags: InterfaceFlags::from_bits(ifaddr.ifa_flags as u32),
+			addr,
+			net,
+		});
+	}
+
+	Ok(networks)
+}
+
+/// Determine whether `addr` is on the same link as this host, that is, whether
+/// the network of any interface which is up contains it.
+///
+/// Addresses of a loopback network, like `127.0.0.1`, are on the link of the
+/// loopback interface.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{is_on_link, local_networks};
+/// for local in local_networks().unwrap() {
+///     if local.flags().contains(netaddr2::InterfaceFlags::UP) {
+///         assert!(is_on_link(local.addr()).unwrap());
+///     }
+/// }
+/// ```
+pub fn is_on_link(addr: IpAddr) -> io::Result<bool> {
+	Ok(on_link(&local_networks()?, addr))
+}
+
+fn on_link(networks: &[LocalNetwork], addr: IpAddr) -> bool {
+	networks
+		.iter()
+		.filter(|local| local.flags.contains(InterfaceFlags::UP))
+		.any(|local| local.net.contains(&addr))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn local(name: &str, flags: InterfaceFlags, addr: &str, net: &str) -> LocalNetwork {
+		LocalNetwork {
+			name: name.to_string(),
+			flags,
+			addr: addr.parse().unwrap(),
+			net: net.parse().unwrap(),
+		}
+	}
+
+	#[test]
+	fn on_link_networks() {
+		let up = InterfaceFlags::from_bits(InterfaceFlags::UP.bits() | InterfaceFlags::RUNNING.bits());
+		let networks = vec![
+			local("eth0", up, "192.0.2.2", "192.0.2.0/24"),
+			local("eth0", up, "2001:db8::2", "2001:db8::/64"),
+			local(
+				"eth1",
+				InterfaceFlags::BROADCAST,
+				"198.51.100.2",
+				"198.51.100.0/24",
+			),
+		];
+
+		assert!(on_link(&networks, "192.0.2.200".parse().unwrap()));
+		assert!(on_link(&networks, "2001:db8::ffff".parse().unwrap()));
+		assert!(!on_link(&networks, "192.0.3.1".parse().unwrap()));
+		assert!(!on_link(&networks, "198.51.100.1".parse().unwrap()));
+		assert!(!on_link(&[], "192.0.2.2".parse().unwrap()));
+	}
+
+	#[test]
+	fn flags() {
+		let flags =
+			InterfaceFlags::from_bits(InterfaceFlags::UP.bits() | InterfaceFlags::LOOPBACK.bits());
+
+		assert!(flags.contains(InterfaceFlags::UP));
+		assert!(flags.contains(InterfaceFlags::LOOPBACK));
+		assert!(!flags.contains(InterfaceFlags::BROADCAST));
+		assert!(flags.contains(InterfaceFlags::default()));
+	}
+
+	#[test]
+	fn reads_netmasks() {
+		// SAFETY: a `sockaddr_in` of zeroes is valid.
+		let mut mask: libc::sockaddr_in = unsafe { core::mem::zeroed() };
+		mask.sin_addr.s_addr = u32::from(Ipv4Addr::new(255, 255, 255, 0)).to_be();
+
+		// SAFETY: the pointer is to a `sockaddr_in`.
+		let read =
+			|mask: &libc::sockaddr_in| unsafe { ipv4addr((mask as *const libc::sockaddr_in).cast()) };
+
+		#[cfg(not(any(
+			target_os = "dragonfly",
+			target_os = "freebsd",
+			target_os = "ios",
+			target_os = "macos",
+			target_os = "netbsd",
+			target_os = "openbsd",
+		)))]
+		assert_eq!(read(&mask), Ipv4Addr::new(255, 255, 255, 0));
+
+		#[cfg(any(
+			target_os = "dragonfly",
+			target_os = "freebsd",
+			target_os = "ios",
+			target_os = "macos",
+			target_os = "netbsd",
+			target_os = "openbsd",
+		))]
+		{
+			mask.sin_len = 16;
+			assert_eq!(read(&mask), Ipv4Addr::new(255, 255, 255, 0));
+
+			// Only the length, family, port, and first two bytes of the address.
+			mask.sin_len = 6;
+			assert_eq!(read(&mask), Ipv4Addr::new(255, 255, 0, 0));
+		}
+	}
+
+	#[test]
+	fn lists_live_interfaces() {
+		for local in local_networks().unwrap() {
+			assert!(!local.name().is_empty());
+			assert!(local.net().contains(&local.addr()));
+
+			if local.flags().contains(InterfaceFlags::UP) {
+				assert!(is_on_link(local.addr()).unwrap());
+			}
+		}
+	}
+}
@@ -0,0 +1,123 @@
+//! Macros for writing networks as literals which are checked at compile time.
+
+/// Create a `NetAddr` from a string literal, checked at compile time.
+///
+/// This accepts the same notation as `NetAddr`'s `FromStr` implementation,
+/// except that it rejects a netmask which is neither a prefix length nor an
+/// address, anything after a second delimiter, and a prefix length longer than
+/// the address, all of which `FromStr` quietly tolerates.  A literal which
+/// does not parse is a compile error rather than a panic at runtime, and the
+/// result can be used in `const` and `static` items.
+///
+/// # Examples
+///
+/// ```rust
+/// use netaddr2::{net, Contains, NetAddr};
+///
+/// static ALLOWED: [NetAddr; 2] = [net!("10.0.0.0/8"), net!("2001:db8::/32")];
+///
+/// let addr: std::net::IpAddr = "10.1.2.3".parse().unwrap();
+/// assert!(ALLOWED.iter().any(|net| net.contains(&addr)));
+/// ```
+///
+/// A literal with a typo in it fails to compile:
+///
+/// ```rust,compile_fail
+/// # use netaddr2::{net, NetAddr};
+/// const TYPO: NetAddr = net!("10.0.0.0/80");
+/// ```
+#[macro_export]
+macro_rules! net {
+	($literal:literal) => {{
+		const NET: $crate::NetAddr = match $crate::__private::netaddr($literal) {
+			::core::result::Result::Ok(net) => net,
+			::core::result::Result::Err(message) => ::core::panic!("{}", message),
+		};
+		NET
+	}};
+}
+
+/// Create a `Netv4Addr` from a string literal, checked at compile time.
+///
+/// See [`net!`] for the notation accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use netaddr2::{net4, Netv4Addr};
+///
+/// const PRIVATE: [Netv4Addr; 3] = [
+///     net4!("10.0.0.0/8"),
+///     net4!("172.16.0.0/12"),
+///     net4!("192.168.0.0 255.255.0.0"),
+/// ];
+///
+/// assert_eq!(PRIVATE[1], "172.16.0.0/12".parse().unwrap());
+/// ```
+///
+/// ```rust,compile_fail
+/// # use netaddr2::{net4, Netv4Addr};
+/// const TYPO: Netv4Addr = net4!("10.0.0.256/8");
+/// ```
+#[macro_export]
+macro_rules! net4 {
+	($literal:literal) => {{
+		const NET: $crate::Netv4Addr = match $crate::__private::netv4addr($literal) {
+			::core::result::Result::Ok(net) => net,
+			::core::result::Result::Err(message) => ::core::panic!("{}", message),
+		};
+		NET
+	}};
+}
+
+/// Create a `Netv6Addr` from a string literal, checked at compile time.
+///
+/// See [`net!`] for the notation accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use netaddr2::{net6, Netv6Addr};
+///
+/// const DOCUMENTATION: Netv6Addr = net6!("2001:db8::/32");
+///
+/// assert_eq!(DOCUMENTATION, "2001:db8::/32".parse().unwrap());
+/// ```
+///
+/// ```rust,compile_fail
+/// # use netaddr2::{net6, Netv6Addr};
+/// const TYPO: Netv6Addr = net6!("2001:db8::/ffff::zoop");
+/// ```
+#[macro_export]
+macro_rules! net6 {
+	($literal:literal) => {{
+		const NET: $crate::Netv6Addr = match $crate::__private::netv6addr($literal) {
+			::core::result::Result::Ok(net) => net,
+			::core::result::Result::Err(message) => ::core::panic!("{}", message),
+		};
+		NET
+	}};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, Netv4Addr, Netv6Addr};
+
+	const NETS: [NetAddr; 2] = [net!("192.0.2.0/24"), net!("2001:db8::/ffff:ffff::")];
+
+	#[test]
+	fn are_const() {
+		assert_eq!(NETS[0], pu!("192.0.2.0/24" # NetAddr));
+		assert_eq!(NETS[1], pu!("2001:db8::/32" # NetAddr));
+	}
+
+	#[test]
+	fn match_parse() {
+		assert_eq!(net4!("10.1.2.3 255.0.0.0"), pu!("10.0.0.0/8" # Netv4Addr));
+		assert_eq!(
+			net6!("::ffff:10.0.0.0/104"),
+			pu!("::ffff:10.0.0.0/104" # Netv6Addr)
+		);
+		assert_eq!(net!("::/0"), pu!("::/0" # NetAddr));
+	}
+}
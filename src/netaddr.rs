@@ -1,14 +1,20 @@
-use crate::AddressIterator;
 use crate::Netv4Addr;
 use crate::Netv6Addr;
-use crate::{Error, Result};
-use std::net::IpAddr;
+use crate::{AddressIterator, ShuffledAddressIterator};
+use crate::{Error, PrefixLen, Result};
+use core::net::IpAddr;
 
 /// A structure representing an IP network.
 ///
 /// Internally using the built-in `std::net::IpAddr` structures, this is a
 /// simple data structure that can be used in a variety of situations.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+	feature = "diesel",
+	derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Inet))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Cidr))]
 pub enum NetAddr {
 	/// An IPv4 network.
 	V4(Netv4Addr),
@@ -45,10 +51,48 @@ impl NetAddr {
 		}
 	}
 
+	/// Create a new CIDR `NetAddr` from the given `addr` and [`PrefixLen`],
+	/// masking the `addr`.
+	///
+	/// # Errors
+	///
+	/// Fails with [`Error::InvalidPrefixLen`] if `addr` is an IPv4 address and
+	/// `len` is longer than 32.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Error, NetAddr, PrefixLen};
+	/// let len: PrefixLen = PrefixLen::new(48).unwrap();
+	///
+	/// assert_eq!(
+	///     NetAddr::with_prefix_len("2001:db8::1".parse().unwrap(), len),
+	///     Ok("2001:db8::/48".parse().unwrap()),
+	/// );
+	/// assert_eq!(
+	///     NetAddr::with_prefix_len("192.0.2.1".parse().unwrap(), len),
+	///     Err(Error::InvalidPrefixLen),
+	/// );
+	/// ```
+	pub fn with_prefix_len(addr: IpAddr, len: PrefixLen) -> Result<Self> {
+		match addr {
+			IpAddr::V4(addr) => Netv4Addr::with_prefix_len(addr, len).map(Self::V4),
+			IpAddr::V6(addr) => Ok(Self::V6(Netv6Addr::with_prefix_len(addr, len))),
+		}
+	}
+
 	/// Produce an iterator over the _contained addresses_ of this `NetAddr`.
 	pub fn iter(&self) -> AddressIterator<NetAddr, IpAddr> {
 		AddressIterator::new(*self, Some(self.addr()))
 	}
+
+	/// Produce an iterator over the _contained addresses_ of this `NetAddr` in a
+	/// pseudo-random order determined by `seed`.
+	///
+	/// See [`ShuffledAddressIterator`] for details.
+	pub fn shuffled(&self, seed: u64) -> ShuffledAddressIterator<[NetAddr; 1], NetAddr> {
+		ShuffledAddressIterator::single(*self, seed)
+	}
 }
 
 mod broadcast;
@@ -59,11 +103,28 @@ mod fromstr;
 mod hash;
 mod merge;
 mod partialord;
+mod relation;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "serde")]
 mod de;
+#[cfg(feature = "diesel")]
+mod diesel_pg;
+#[cfg(feature = "postgres-types")]
+mod postgres;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
 mod ser;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,41 @@
+use super::NetAddr;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Build a `NetAddr` of either family from unstructured fuzzer input.
+impl<'a> Arbitrary<'a> for NetAddr {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		if bool::arbitrary(u)? {
+			Ok(Self::V4(Arbitrary::arbitrary(u)?))
+		} else {
+			Ok(Self::V6(Arbitrary::arbitrary(u)?))
+		}
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		arbitrary::size_hint::and(
+			bool::size_hint(depth),
+			arbitrary::size_hint::or(
+				crate::Netv4Addr::size_hint(depth),
+				crate::Netv6Addr::size_hint(depth),
+			),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn both_families_are_produced() {
+		let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+		let mut u = Unstructured::new(&bytes);
+
+		let nets: Vec<NetAddr> = (0..64)
+			.map(|_| NetAddr::arbitrary(&mut u).unwrap())
+			.collect();
+
+		assert!(nets.iter().any(|net| matches!(net, NetAddr::V4(_))));
+		assert!(nets.iter().any(|net| matches!(net, NetAddr::V6(_))));
+	}
+}
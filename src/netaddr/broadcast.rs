@@ -1,6 +1,6 @@
 use super::NetAddr;
 use crate::traits::Broadcast;
-use std::net::IpAddr;
+use core::net::IpAddr;
 
 impl Broadcast for NetAddr {
 	type Output = Option<IpAddr>;
@@ -17,11 +17,11 @@ impl Broadcast for NetAddr {
 mod tests {
 	use super::*;
 	use crate::NetAddr;
-	use std::net::IpAddr;
+	use core::net::IpAddr;
 
 	mod v4 {
 		use super::*;
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		#[test]
 		fn returns_correct_address() {
@@ -1,8 +1,8 @@
 use super::NetAddr;
 use crate::traits::Contains;
 
-impl Contains<std::net::IpAddr> for NetAddr {
-	fn contains(&self, other: &std::net::IpAddr) -> bool {
+impl Contains<core::net::IpAddr> for NetAddr {
+	fn contains(&self, other: &core::net::IpAddr) -> bool {
 		match self {
 			Self::V4(netaddr) => netaddr.contains(other),
 			Self::V6(netaddr) => netaddr.contains(other),
@@ -10,8 +10,8 @@ impl Contains<std::net::IpAddr> for NetAddr {
 	}
 }
 
-impl Contains<std::net::Ipv4Addr> for NetAddr {
-	fn contains(&self, other: &std::net::Ipv4Addr) -> bool {
+impl Contains<core::net::Ipv4Addr> for NetAddr {
+	fn contains(&self, other: &core::net::Ipv4Addr) -> bool {
 		match self {
 			Self::V4(netaddr) => netaddr.contains(other),
 			_ => false,
@@ -19,8 +19,8 @@ impl Contains<std::net::Ipv4Addr> for NetAddr {
 	}
 }
 
-impl Contains<std::net::Ipv6Addr> for NetAddr {
-	fn contains(&self, other: &std::net::Ipv6Addr) -> bool {
+impl Contains<core::net::Ipv6Addr> for NetAddr {
+	fn contains(&self, other: &core::net::Ipv6Addr) -> bool {
 		match self {
 			Self::V6(netaddr) => netaddr.contains(other),
 			_ => false,
@@ -59,7 +59,7 @@ impl Contains<crate::Netv6Addr> for NetAddr {
 mod tests {
 	use super::*;
 	use crate::NetAddr;
-	use std::net::IpAddr;
+	use core::net::IpAddr;
 
 	macro_rules! assert_contains {
 		($a:expr, $b:expr) => {
@@ -94,7 +94,7 @@ mod tests {
 	mod v4 {
 		use super::*;
 		use crate::{Netv4Addr, Netv6Addr};
-		use std::net::{Ipv4Addr, Ipv6Addr};
+		use core::net::{Ipv4Addr, Ipv6Addr};
 
 		#[test]
 		fn ipaddr_v4() {
@@ -158,7 +158,7 @@ mod tests {
 	mod v6 {
 		use super::*;
 		use crate::{Netv4Addr, Netv6Addr};
-		use std::net::{Ipv4Addr, Ipv6Addr};
+		use core::net::{Ipv4Addr, Ipv6Addr};
 
 		#[test]
 		fn ipaddr_v4() {
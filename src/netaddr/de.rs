@@ -1,6 +1,42 @@
 use serde::{de, Deserialize, Deserializer};
 
 use super::NetAddr;
+use crate::serde::Rhs;
+use crate::{Netv4Addr, Netv6Addr};
+use core::net::IpAddr;
+
+/// Build a network from its address and, if given, its prefix length or mask.
+#[cfg(feature = "serde")]
+fn from_parts<E: de::Error>(addr: IpAddr, rhs: Option<Rhs<IpAddr>>) -> Result<NetAddr, E> {
+	match (addr, rhs) {
+		(IpAddr::V4(_), Some(Rhs::Prefix(prefix))) if prefix > 32 => Err(de::Error::invalid_value(
+			de::Unexpected::Unsigned(prefix.into()),
+			&"a prefix length of at most 32",
+		)),
+		(IpAddr::V6(_), Some(Rhs::Prefix(prefix))) if prefix > 128 => Err(de::Error::invalid_value(
+			de::Unexpected::Unsigned(prefix.into()),
+			&"a prefix length of at most 128",
+		)),
+		(IpAddr::V4(addr), Some(Rhs::Prefix(prefix))) => {
+			let mask: u32 = u32::MAX ^ u32::MAX.checked_shr(prefix).unwrap_or(0_u32);
+			Ok(NetAddr::V4(Netv4Addr::new(addr, mask.into())))
+		}
+		(IpAddr::V6(addr), Some(Rhs::Prefix(prefix))) => {
+			let mask: u128 = u128::MAX ^ u128::MAX.checked_shr(prefix).unwrap_or(0_u128);
+			Ok(NetAddr::V6(Netv6Addr::new(addr, mask.into())))
+		}
+		(IpAddr::V4(addr), Some(Rhs::Mask(IpAddr::V4(mask)))) => {
+			Ok(NetAddr::V4(Netv4Addr::new(addr, mask)))
+		}
+		(IpAddr::V6(addr), Some(Rhs::Mask(IpAddr::V6(mask)))) => {
+			Ok(NetAddr::V6(Netv6Addr::new(addr, mask)))
+		}
+		(_, Some(Rhs::Mask(_))) => Err(de::Error::custom(
+			"address and mask are of different families",
+		)),
+		(addr, None) => Ok(NetAddr::from(addr)),
+	}
+}
 
 #[cfg(feature = "serde")]
 struct NetAddrVisitor;
@@ -16,25 +52,119 @@ impl<'de> de::Visitor<'de> for NetAddrVisitor {
 	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
 		use core::str::FromStr;
 		Self::Value::from_str(value)
+			.or_else(|_| IpAddr::from_str(value).map(Self::Value::from))
 			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
 	}
+
+	fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_map(map)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_seq(seq, &self)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		Self::Value::parse_ascii(value)
+			.ok()
+			.or_else(|| match crate::parser::ipv4addr(value) {
+				Some(addr) => Some(Self::Value::from(addr)),
+				None => crate::parser::ipv6addr(value).map(Self::Value::from),
+			})
+			.ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(value), &self))
+	}
+
+	fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+		use de::VariantAccess;
+
+		match data.variant()? {
+			(Family::V4, variant) => variant.newtype_variant().map(NetAddr::V4),
+			(Family::V6, variant) => variant.newtype_variant().map(NetAddr::V6),
+			(Family::Network(net), variant) => variant.unit_variant().map(|()| net),
+		}
+	}
+}
+
+/// The variants of the compact representation, tagging the address family.
+#[cfg(feature = "serde")]
+enum Family {
+	V4,
+	V6,
+	/// A network in string form, as written by versions before the compact
+	/// representation, which is accepted in place of a variant.
+	Network(NetAddr),
+}
+
+#[cfg(feature = "serde")]
+const FAMILIES: &[&str] = &["V4", "V6"];
+
+#[cfg(feature = "serde")]
+struct FamilyVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for FamilyVisitor {
+	type Value = Family;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("`V4`, `V6`, or a network")
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		match value {
+			0 => Ok(Family::V4),
+			1 => Ok(Family::V6),
+			_ => Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(value),
+				&"variant index 0 <= i < 2",
+			)),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match value {
+			"V4" => Ok(Family::V4),
+			"V6" => Ok(Family::V6),
+			_ => de::Visitor::visit_str(NetAddrVisitor, value).map(Family::Network),
+		}
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		match value {
+			b"V4" => Ok(Family::V4),
+			b"V6" => Ok(Family::V6),
+			_ => de::Visitor::visit_bytes(NetAddrVisitor, value).map(Family::Network),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Family {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(FamilyVisitor)
+	}
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for NetAddr {
 	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		deserializer.deserialize_str(NetAddrVisitor)
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(NetAddrVisitor)
+		} else {
+			deserializer.deserialize_enum("NetAddr", FAMILIES, NetAddrVisitor)
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::NetAddr;
-	use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+	use serde_test::{assert_de_tokens, assert_de_tokens_error, Configure, Readable, Token};
 
 	#[test]
 	fn malformed_produces_correct_error() {
-		assert_de_tokens_error::<NetAddr>(
+		assert_de_tokens_error::<Readable<NetAddr>>(
 			&[Token::Str("asdf")],
 			"invalid value: string \"asdf\", expected a valid cidr/extended network address",
 		)
@@ -47,14 +177,17 @@ mod tests {
 		fn test_de_cidr_localhost() {
 			let netaddr: NetAddr = "127.0.0.1/8".parse().unwrap();
 
-			assert_de_tokens(&netaddr, &[Token::Str("127.0.0.0/8")]);
+			assert_de_tokens(&netaddr.readable(), &[Token::Str("127.0.0.0/8")]);
 		}
 
 		#[test]
 		fn test_de_non_cidr_localhost() {
 			let netaddr: NetAddr = "127.0.0.1/251.255.255.7".parse().unwrap();
 
-			assert_de_tokens(&netaddr, &[Token::Str("123.0.0.1/251.255.255.7")]);
+			assert_de_tokens(
+				&netaddr.readable(),
+				&[Token::Str("123.0.0.1/251.255.255.7")],
+			);
 		}
 	}
 
@@ -65,7 +198,7 @@ mod tests {
 		fn test_de_cidr_localhost() {
 			let netaddr: NetAddr = "ff02::1/60".parse().unwrap();
 
-			assert_de_tokens(&netaddr, &[Token::Str("ff02::/60")]);
+			assert_de_tokens(&netaddr.readable(), &[Token::Str("ff02::/60")]);
 		}
 
 		#[test]
@@ -73,9 +206,146 @@ mod tests {
 			let netaddr: NetAddr = "ff02:dead:beef::1/ff02:eeee:eeee::1".parse().unwrap();
 
 			assert_de_tokens(
-				&netaddr,
+				&netaddr.readable(),
 				&[Token::Str("ff02:ceac:aeee::1/ff02:eeee:eeee::1")],
 			);
 		}
 	}
+
+	#[test]
+	fn test_de_bare_address() {
+		let v4: NetAddr = "192.0.2.1/32".parse().unwrap();
+		let v6: NetAddr = "2001:db8::1/128".parse().unwrap();
+
+		assert_de_tokens(&v4.readable(), &[Token::Str("192.0.2.1")]);
+		assert_de_tokens(&v6.readable(), &[Token::Str("2001:db8::1")]);
+	}
+
+	#[test]
+	fn test_de_map_family_mismatch() {
+		assert_de_tokens_error::<Readable<NetAddr>>(
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("10.0.0.0"),
+				Token::Str("mask"),
+				Token::Str("ffff::"),
+				Token::MapEnd,
+			],
+			"address and mask are of different families",
+		);
+	}
+
+	#[test]
+	fn test_de_map_unknown_field() {
+		assert_de_tokens_error::<Readable<NetAddr>>(
+			&[Token::Map { len: Some(2) }, Token::Str("network")],
+			"unknown field `network`, expected one of `address`, `addr`, `prefix`, `mask`",
+		);
+	}
+
+	#[test]
+	fn test_de_json_forms() {
+		let v4: NetAddr = "10.0.0.0/8".parse().unwrap();
+		let v6: NetAddr = "2001:db8::/32".parse().unwrap();
+
+		for (json, netaddr) in &[
+			(r#""10.0.0.0/8""#, v4),
+			(r#"{ "address": "10.0.0.0", "prefix": 8 }"#, v4),
+			(r#"{ "addr": "10.0.0.0", "mask": "255.0.0.0" }"#, v4),
+			(r#"["10.0.0.0", 8]"#, v4),
+			(r#""2001:db8::/32""#, v6),
+			(r#"{ "address": "2001:db8::", "prefix": 32 }"#, v6),
+			(r#"{ "addr": "2001:db8::", "mask": "ffff:ffff::" }"#, v6),
+			(r#"["2001:db8::", "32"]"#, v6),
+		] {
+			assert_eq!(serde_json::from_str::<NetAddr>(json).unwrap(), *netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string() {
+		let netaddr: NetAddr = "ff02::1/60".parse().unwrap();
+
+		assert_de_tokens(&netaddr.compact(), &[Token::Str("ff02::/60")]);
+		assert_de_tokens(&netaddr.compact(), &[Token::Bytes(b"ff02::/60")]);
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string_from_msgpack() {
+		for string in &["2001:db8::/32", "10.0.0.0/8"] {
+			let netaddr: NetAddr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(string).unwrap();
+
+			assert_eq!(rmp_serde::from_slice::<NetAddr>(&encoded).unwrap(), netaddr);
+		}
+
+		let encoded: Vec<u8> = rmp_serde::to_vec("not a network").unwrap();
+		assert!(rmp_serde::from_slice::<NetAddr>(&encoded).is_err());
+	}
+
+	#[test]
+	fn test_de_compact_round_trips_through_msgpack() {
+		for string in &["2001:db8::/32", "10.0.0.0/8"] {
+			let netaddr: NetAddr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(&netaddr).unwrap();
+
+			assert_eq!(rmp_serde::from_slice::<NetAddr>(&encoded).unwrap(), netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_v6() {
+		let netaddr: NetAddr = "ff02::1/60".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.compact(),
+			&[
+				Token::NewtypeVariant {
+					name: "NetAddr",
+					variant: "V6",
+				},
+				Token::TupleVariant {
+					name: "Netv6Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 16 },
+				Token::U8(0xff),
+				Token::U8(0x02),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(1),
+				Token::TupleEnd,
+				Token::U8(60),
+				Token::TupleVariantEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_bincode_round_trip() {
+		for net in &[
+			"192.0.2.0/24",
+			"127.0.0.1/251.255.255.7",
+			"2001:db8::/32",
+			"ff02:dead:beef::1/ff02:eeee:eeee::1",
+		] {
+			let netaddr: NetAddr = net.parse().unwrap();
+			let bytes = bincode::serialize(&netaddr).unwrap();
+
+			assert_eq!(bincode::deserialize::<NetAddr>(&bytes).unwrap(), netaddr);
+		}
+	}
 }
@@ -0,0 +1,91 @@
+use super::NetAddr;
+use crate::inet;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{Cidr, Inet};
+use std::io::Write;
+
+impl ToSql<Inet, Pg> for NetAddr {
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+		out.write_all(&inet::encode(self, false)?)?;
+		Ok(IsNull::No)
+	}
+}
+
+impl ToSql<Cidr, Pg> for NetAddr {
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+		out.write_all(&inet::encode(self, true)?)?;
+		Ok(IsNull::No)
+	}
+}
+
+impl FromSql<Inet, Pg> for NetAddr {
+	fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+		Ok(inet::decode(value.as_bytes())?)
+	}
+}
+
+impl FromSql<Cidr, Pg> for NetAddr {
+	fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+		Ok(inet::decode(value.as_bytes())?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use diesel::pg::{PgMetadataLookup, PgTypeMetadata};
+	use diesel::query_builder::bind_collector::RawBytesBindCollector;
+	use diesel::query_builder::BindCollector;
+	use std::num::NonZeroU32;
+
+	/// A metadata lookup for built-in types, which never need to be looked up.
+	struct BuiltinLookup;
+
+	impl PgMetadataLookup for BuiltinLookup {
+		fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+			unreachable!("inet and cidr are built-in types")
+		}
+	}
+
+	fn to_sql<T>(net: &NetAddr) -> Vec<u8>
+	where
+		Pg: diesel::sql_types::HasSqlType<T>,
+		NetAddr: ToSql<T, Pg>,
+	{
+		let mut collector = RawBytesBindCollector::<Pg>::new();
+		collector
+			.push_bound_value::<T, NetAddr>(net, &mut BuiltinLookup)
+			.unwrap();
+		collector.binds.remove(0).unwrap()
+	}
+
+	fn from_sql<T>(bytes: &[u8]) -> deserialize::Result<NetAddr>
+	where
+		NetAddr: FromSql<T, Pg>,
+	{
+		let oid = NonZeroU32::new(869).unwrap();
+		<NetAddr as FromSql<T, Pg>>::from_sql(PgValue::new(bytes, &oid))
+	}
+
+	#[test]
+	fn round_trips() {
+		for net in &["192.168.1.0/24", "10.0.0.0/8", "2001:db8::/32", "::1/128"] {
+			let net: NetAddr = net.parse().unwrap();
+
+			assert_eq!(from_sql::<Inet>(&to_sql::<Inet>(&net)).unwrap(), net);
+			assert_eq!(from_sql::<Cidr>(&to_sql::<Cidr>(&net)).unwrap(), net);
+		}
+	}
+
+	#[test]
+	fn to_sql_non_cidr_fails() {
+		let net: NetAddr = "10.0.0.0/255.0.255.0".parse().unwrap();
+		let mut collector = RawBytesBindCollector::<Pg>::new();
+
+		assert!(collector
+			.push_bound_value::<Inet, NetAddr>(&net, &mut BuiltinLookup)
+			.is_err());
+	}
+}
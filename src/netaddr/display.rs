@@ -1,15 +1,46 @@
 use super::NetAddr;
+use crate::{NetFormat, Notation};
 use core::fmt;
 
+impl NetAddr {
+	/// Start writing this network in a notation other than the usual one.
+	///
+	/// See [`NetFormat`] for the notations available.
+	pub fn format(&self) -> NetFormat {
+		NetFormat::new(*self)
+	}
+}
+
 impl fmt::Display for NetAddr {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
-			Self::V4(addr) => write!(f, "{}", addr),
-			Self::V6(addr) => write!(f, "{}", addr),
+			Self::V4(addr) => fmt::Display::fmt(addr, f),
+			Self::V6(addr) => fmt::Display::fmt(addr, f),
 		}
 	}
 }
 
+impl fmt::LowerHex for NetAddr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::LowerHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::UpperHex for NetAddr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::UpperHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::Binary for NetAddr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::Binary);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::NetAddr;
@@ -66,4 +97,15 @@ mod tests {
 			)
 		}
 	}
+
+	#[test]
+	fn flags_pass_through() {
+		let addr: NetAddr = "192.0.2.0/24".parse().unwrap();
+		assert_eq!(format!("{:#}", addr), "192.0.2.0 255.255.255.0");
+		assert_eq!(format!("{:X}", addr), "C0000200/24");
+
+		let addr: NetAddr = "2001:db8::/32".parse().unwrap();
+		assert_eq!(format!("{:#}", addr), "2001:db8:: ffff:ffff::");
+		assert_eq!(format!("{:x}", addr), "20010db8000000000000000000000000/32");
+	}
 }
@@ -3,3 +3,10 @@ mod ipv4addr;
 mod ipv6addr;
 mod netv4addr;
 mod netv6addr;
+
+#[cfg(feature = "cidr")]
+mod cidr;
+#[cfg(feature = "ipnet")]
+mod ipnet;
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork;
@@ -0,0 +1,48 @@
+use crate::{Error, NetAddr, Netv4Addr, Netv6Addr};
+use cidr::{IpCidr, Ipv4Cidr, Ipv6Cidr};
+use core::convert::TryFrom;
+
+impl From<IpCidr> for NetAddr {
+	fn from(net: IpCidr) -> Self {
+		match net {
+			IpCidr::V4(net) => Self::V4(Netv4Addr::from(net)),
+			IpCidr::V6(net) => Self::V6(Netv6Addr::from(net)),
+		}
+	}
+}
+
+/// Converts the network, failing with [`Error::NonContiguousMask`] if its
+/// mask is not contiguous.
+impl TryFrom<NetAddr> for IpCidr {
+	type Error = Error;
+
+	fn try_from(net: NetAddr) -> Result<Self, Self::Error> {
+		match net {
+			NetAddr::V4(net) => Ipv4Cidr::try_from(net).map(Self::V4),
+			NetAddr::V6(net) => Ipv6Cidr::try_from(net).map(Self::V6),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips_cidr() {
+		for net in &["192.0.2.0/24", "0.0.0.0/0", "2001:db8::/32", "::1/128"] {
+			let net: NetAddr = net.parse().unwrap();
+
+			assert_eq!(IpCidr::try_from(net).map(NetAddr::from), Ok(net));
+		}
+	}
+
+	#[test]
+	fn try_from_non_cidr_fails() {
+		for net in &["192.0.2.0/255.255.0.255", "2001:db8::/ffff::ffff"] {
+			let net: NetAddr = net.parse().unwrap();
+
+			assert_eq!(IpCidr::try_from(net), Err(Error::NonContiguousMask));
+		}
+	}
+}
@@ -1,5 +1,5 @@
 use crate::NetAddr;
-use std::net::IpAddr;
+use core::net::IpAddr;
 
 impl From<IpAddr> for NetAddr {
 	fn from(addr: IpAddr) -> Self {
@@ -17,7 +17,7 @@ mod tests {
 	mod v4 {
 		use super::*;
 		use crate::Netv4Addr;
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		#[test]
 		fn uses_max_netmask() {
@@ -28,7 +28,7 @@ mod tests {
 				netaddr,
 				NetAddr::V4(Netv4Addr::new(
 					Ipv4Addr::new(192, 0, 2, 42),
-					Ipv4Addr::from(u32::max_value())
+					Ipv4Addr::from(u32::MAX)
 				))
 			);
 		}
@@ -37,7 +37,7 @@ mod tests {
 	mod v6 {
 		use super::*;
 		use crate::Netv6Addr;
-		use std::net::Ipv6Addr;
+		use core::net::Ipv6Addr;
 
 		#[test]
 		fn uses_max_netmask() {
@@ -48,7 +48,7 @@ mod tests {
 				netaddr,
 				NetAddr::V6(Netv6Addr::new(
 					Ipv6Addr::new(0x2001, 0xdb8, 0xdead, 0xbeef, 0, 0, 0, 0x0042),
-					Ipv6Addr::from(u128::max_value())
+					Ipv6Addr::from(u128::MAX)
 				))
 			);
 		}
@@ -1,5 +1,5 @@
 use crate::{NetAddr, Netv4Addr};
-use std::net::Ipv4Addr;
+use core::net::Ipv4Addr;
 
 impl From<Ipv4Addr> for NetAddr {
 	fn from(addr: Ipv4Addr) -> Self {
@@ -17,7 +17,7 @@ mod tests {
 		let netaddr: NetAddr = NetAddr::from(addr);
 		assert_eq!(
 			netaddr,
-			NetAddr::V4(Netv4Addr::new(addr, Ipv4Addr::from(u32::max_value())))
+			NetAddr::V4(Netv4Addr::new(addr, Ipv4Addr::from(u32::MAX)))
 		);
 	}
 }
@@ -1,5 +1,5 @@
 use crate::{NetAddr, Netv6Addr};
-use std::net::Ipv6Addr;
+use core::net::Ipv6Addr;
 
 impl From<Ipv6Addr> for NetAddr {
 	fn from(addr: Ipv6Addr) -> Self {
@@ -10,7 +10,7 @@ impl From<Ipv6Addr> for NetAddr {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::net::Ipv6Addr;
+	use core::net::Ipv6Addr;
 
 	#[test]
 	fn uses_max_netmask() {
@@ -18,7 +18,7 @@ mod tests {
 		let netaddr: NetAddr = NetAddr::from(addr);
 		assert_eq!(
 			netaddr,
-			NetAddr::V6(Netv6Addr::new(addr, Ipv6Addr::from(u128::max_value())))
+			NetAddr::V6(Netv6Addr::new(addr, Ipv6Addr::from(u128::MAX)))
 		);
 	}
 }
@@ -1,20 +1,30 @@
 use super::{Error, NetAddr, Result};
-use crate::Netv4Addr;
-use crate::Netv6Addr;
 use core::str::FromStr;
 
+impl NetAddr {
+	/// Parse a `NetAddr` from a byte string.
+	///
+	/// This accepts exactly the same formats as the [`FromStr`] implementation,
+	/// but skips UTF-8 validation.  The address family is chosen up front (by
+	/// whether the address contains a `:`), so only one family is ever parsed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::NetAddr;
+	/// let parsed: NetAddr = NetAddr::parse_ascii(b"2001:db8::/32").unwrap();
+	/// assert_eq!(parsed, "2001:db8::/32".parse().unwrap());
+	/// ```
+	pub fn parse_ascii(bytes: &[u8]) -> Result<Self> {
+		crate::parser::netaddr(bytes)
+	}
+}
+
 impl FromStr for NetAddr {
 	type Err = Error;
 
 	fn from_str(string: &str) -> Result<Self> {
-		let as_v4: Result<Netv4Addr> = string.parse::<Netv4Addr>();
-		let as_v6: Result<Netv6Addr> = string.parse::<Netv6Addr>();
-
-		match (as_v4, as_v6) {
-			(Ok(v4), _) => Ok(Self::V4(v4)),
-			(_, Ok(v6)) => Ok(Self::V6(v6)),
-			(Err(_e4), Err(e6)) => Err(e6),
-		}
+		Self::parse_ascii(string.as_bytes())
 	}
 }
 
@@ -40,15 +50,13 @@ mod tests {
 		let result = "zoop".parse::<NetAddr>();
 		assert_eq!(
 			result,
-			Err(Error::ParseError(
-				"could not split provided input".to_string()
-			))
+			Err(Error::ParseError("could not split provided input"))
 		);
 	}
 
 	mod v4 {
 		use super::NetAddr;
-		use std::net::{IpAddr, Ipv4Addr};
+		use core::net::{IpAddr, Ipv4Addr};
 
 		#[test]
 		fn cidr_32_correct_network_and_netmask() {
@@ -88,7 +96,7 @@ mod tests {
 
 	mod v6 {
 		use super::NetAddr;
-		use std::net::{IpAddr, Ipv6Addr};
+		use core::net::{IpAddr, Ipv6Addr};
 
 		#[test]
 		fn cidr_8_correct_network_and_netmask() {
@@ -146,4 +154,24 @@ mod tests {
 			);
 		}
 	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::netaddr;
+		use core::net::IpAddr;
+		use proptest::prelude::*;
+		proptest! {
+			#[test]
+			fn display_round_trips(net in netaddr()) {
+				prop_assert_eq!(net.to_string().parse::<NetAddr>(), Ok(net));
+			}
+
+			#[test]
+			fn hosts_round_trip(addr in any::<IpAddr>()) {
+				let net: NetAddr = NetAddr::from(addr);
+
+				prop_assert_eq!(net.to_string().parse::<NetAddr>(), Ok(net));
+			}
+		}
+	}
 }
@@ -1,20 +1,93 @@
-use crate::traits::Merge;
+use crate::traits::{Merge, MergeResult};
 use crate::NetAddr;
-use crate::Netv4Addr;
-use crate::Netv6Addr;
+#[cfg(feature = "std")]
+use crate::NetRelation;
 
 impl Merge for NetAddr {
-	type Output = Option<Self>;
+	type Output = MergeResult<Self>;
 
+	/// Merge two networks of the same family.
+	///
+	/// Networks of different families give [`MergeResult::FamilyMismatch`]
+	/// rather than panicking.
 	fn merge(&self, other: &Self) -> Self::Output {
-		match (self, other) {
-			(Self::V4(a), Self::V4(b)) => a.merge(b).map(|netvxaddr: Netv4Addr| netvxaddr.into()),
-			(Self::V6(a), Self::V6(b)) => a.merge(b).map(|netvxaddr: Netv6Addr| netvxaddr.into()),
-			(_, _) => unimplemented!(),
+		let merged: Option<Self> = match (self, other) {
+			(Self::V4(a), Self::V4(b)) => a.merge(b).map(Self::V4),
+			(Self::V6(a), Self::V6(b)) => a.merge(b).map(Self::V6),
+			(_, _) => return MergeResult::FamilyMismatch,
+		};
+
+		match merged {
+			Some(merged) => MergeResult::Merged(merged),
+			None => MergeResult::NotMergeable,
 		}
 	}
 }
 
+impl NetAddr {
+	/// Merge any number of networks, of possibly mixed families, into as few
+	/// networks as possible.
+	///
+	/// Networks are merged with their siblings and absorbed into their
+	/// supernets until nothing more can be merged; the rest are left intact.
+	/// The result is sorted, with IPv4 networks first, and has no duplicates.
+	///
+	/// Networks with non-contiguous netmasks are only absorbed into networks
+	/// which contain them, never merged with siblings.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::NetAddr;
+	/// let nets: Vec<NetAddr> = ["10.0.1.0/24", "2001:db8::/32", "10.0.0.0/24", "10.0.0.128/25", "10.0.3.0/24"]
+	///     .iter()
+	///     .map(|net| net.parse().unwrap())
+	///     .collect();
+	///
+	/// let merged: Vec<String> = NetAddr::merge_all(nets).iter().map(ToString::to_string).collect();
+	/// assert_eq!(merged, ["10.0.0.0/23", "10.0.3.0/24", "2001:db8::/32"]);
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn merge_all<I: IntoIterator<Item = Self>>(nets: I) -> Vec<Self> {
+		let mut nets: Vec<Self> = nets.into_iter().collect();
+
+		loop {
+			nets.sort_unstable();
+			nets.dedup();
+
+			let count: usize = nets.len();
+			let mut merged: Vec<Self> = Vec::with_capacity(count);
+
+			for mut net in nets {
+				while let Some(combined) = merged.last().and_then(|last| combine(last, &net)) {
+					merged.pop();
+					net = combined;
+				}
+
+				merged.push(net);
+			}
+
+			nets = merged;
+
+			if nets.len() == count {
+				return nets;
+			}
+		}
+	}
+}
+
+/// Combine two networks into one covering exactly their addresses, if there is
+/// such a network.
+#[cfg(feature = "std")]
+fn combine(a: &NetAddr, b: &NetAddr) -> Option<NetAddr> {
+	match a.relation(b) {
+		NetRelation::Equal | NetRelation::Contains => Some(*a),
+		NetRelation::ContainedBy => Some(*b),
+		_ if a.is_cidr() && b.is_cidr() => a.merge(b).merged(),
+		_ => None,
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -27,7 +100,10 @@ mod tests {
 			let a: NetAddr = "10.0.0.0/24".parse().unwrap();
 			let b: NetAddr = "10.0.1.0/24".parse().unwrap();
 
-			assert_eq!(a.merge(&b), Some("10.0.0.0/23".parse().unwrap()));
+			assert_eq!(
+				a.merge(&b),
+				MergeResult::Merged("10.0.0.0/23".parse().unwrap())
+			);
 		}
 
 		#[test]
@@ -43,7 +119,7 @@ mod tests {
 			let a: NetAddr = "10.0.0.0/24".parse().unwrap();
 			let b: NetAddr = "10.0.0.0/23".parse().unwrap();
 
-			assert_eq!(a.merge(&b), Some(b));
+			assert_eq!(a.merge(&b), MergeResult::Merged(b));
 		}
 
 		#[test]
@@ -59,8 +135,8 @@ mod tests {
 			let a: NetAddr = "10.0.1.0/24".parse().unwrap();
 			let b: NetAddr = "10.0.2.0/24".parse().unwrap();
 
-			assert_eq!(a.merge(&b), None);
-			assert_eq!(b.merge(&a), None);
+			assert_eq!(a.merge(&b), MergeResult::NotMergeable);
+			assert_eq!(b.merge(&a), MergeResult::NotMergeable);
 			assert_eq!(a.merge(&b), b.merge(&a));
 		}
 	}
@@ -75,7 +151,7 @@ mod tests {
 
 			assert_eq!(
 				a.merge(&b),
-				Some("2001:db8:dead:beee::/63".parse().unwrap())
+				MergeResult::Merged("2001:db8:dead:beee::/63".parse().unwrap())
 			);
 		}
 
@@ -92,7 +168,7 @@ mod tests {
 			let a: NetAddr = "2001:db8:dead:beee::/63".parse().unwrap();
 			let b: NetAddr = "2001:db8:dead:beef::/64".parse().unwrap();
 
-			assert_eq!(a.merge(&b), Some(a));
+			assert_eq!(a.merge(&b), MergeResult::Merged(a));
 		}
 
 		#[test]
@@ -108,9 +184,147 @@ mod tests {
 			let a: NetAddr = "2001:db8:dead:beee::/64".parse().unwrap();
 			let b: NetAddr = "2001:db8:dead:beed::/64".parse().unwrap();
 
-			assert_eq!(a.merge(&b), None);
-			assert_eq!(b.merge(&a), None);
+			assert_eq!(a.merge(&b), MergeResult::NotMergeable);
+			assert_eq!(b.merge(&a), MergeResult::NotMergeable);
 			assert_eq!(a.merge(&b), b.merge(&a));
 		}
 	}
+
+	mod mixed {
+		use super::*;
+
+		#[test]
+		fn family_mismatch() {
+			let a: NetAddr = "10.0.0.0/24".parse().unwrap();
+			let b: NetAddr = "2001:db8::/64".parse().unwrap();
+
+			assert_eq!(a.merge(&b), MergeResult::FamilyMismatch);
+			assert_eq!(b.merge(&a), MergeResult::FamilyMismatch);
+			assert_eq!(a.merge(&b).merged(), None);
+		}
+
+		#[test]
+		fn result_conversions() {
+			let a: NetAddr = "10.0.0.0/24".parse().unwrap();
+			let b: NetAddr = "10.0.1.0/24".parse().unwrap();
+
+			assert!(a.merge(&b).is_merged());
+			assert_eq!(
+				Option::from(a.merge(&b)),
+				Some("10.0.0.0/23".parse::<NetAddr>().unwrap())
+			);
+			assert!(!a.merge(&"10.0.2.0/24".parse().unwrap()).is_merged());
+		}
+	}
+
+	#[cfg(feature = "std")]
+	mod merge_all {
+		use super::*;
+
+		fn nets(nets: &[&str]) -> Vec<NetAddr> {
+			nets.iter().map(|net| net.parse().unwrap()).collect()
+		}
+
+		#[test]
+		fn empty() {
+			assert_eq!(NetAddr::merge_all(Vec::new()), Vec::new());
+		}
+
+		#[test]
+		fn cascades() {
+			assert_eq!(
+				NetAddr::merge_all(nets(&[
+					"10.0.3.0/24",
+					"10.0.0.0/24",
+					"10.0.2.0/24",
+					"10.0.1.128/25",
+					"10.0.1.0/25",
+				])),
+				nets(&["10.0.0.0/22"])
+			);
+		}
+
+		#[test]
+		fn mixed_families() {
+			assert_eq!(
+				NetAddr::merge_all(nets(&[
+					"2001:db8:1::/48",
+					"10.0.0.0/24",
+					"2001:db8::/48",
+					"10.0.1.0/24",
+					"::/0",
+				])),
+				nets(&["10.0.0.0/23", "::/0"])
+			);
+		}
+
+		#[test]
+		fn leaves_unmergeable_intact() {
+			assert_eq!(
+				NetAddr::merge_all(nets(&["10.0.2.0/24", "10.0.1.0/24", "10.0.1.0/24"])),
+				nets(&["10.0.1.0/24", "10.0.2.0/24"])
+			);
+		}
+
+		#[test]
+		fn absorbs_into_supernets() {
+			assert_eq!(
+				NetAddr::merge_all(nets(&[
+					"10.1.2.0/24",
+					"10.0.0.0/255.0.255.0",
+					"10.0.0.0/8",
+					"11.0.0.0/255.0.255.0",
+					"11.0.1.0/255.0.255.0",
+				])),
+				nets(&["10.0.0.0/8", "11.0.0.0/255.0.255.0", "11.0.1.0/255.0.255.0"])
+			);
+		}
+	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::{cidr_netv4addr, cidr_netv6addr};
+		use crate::Contains;
+		use proptest::prelude::*;
+
+		fn same_family_pair() -> impl Strategy<Value = (NetAddr, NetAddr)> {
+			prop_oneof![
+				(cidr_netv4addr(), cidr_netv4addr()).prop_map(|(a, b)| (a.into(), b.into())),
+				(cidr_netv6addr(), cidr_netv6addr()).prop_map(|(a, b)| (a.into(), b.into())),
+			]
+		}
+
+		proptest! {
+			#[test]
+			fn is_commutative((a, b) in same_family_pair()) {
+				prop_assert_eq!(a.merge(&b), b.merge(&a));
+			}
+
+			#[cfg(feature = "std")]
+			#[test]
+			fn merge_all_covers_inputs(nets in proptest::collection::vec(crate::strategy::cidr_netaddr(), 0..16)) {
+				let merged = NetAddr::merge_all(nets.clone());
+
+				for net in &nets {
+					prop_assert!(merged.iter().any(|merged| merged.contains(net)));
+				}
+
+				for (i, a) in merged.iter().enumerate() {
+					for b in &merged[i + 1..] {
+						prop_assert!(a.is_disjoint(b));
+					}
+				}
+
+				prop_assert_eq!(NetAddr::merge_all(merged.clone()), merged);
+			}
+
+			#[test]
+			fn result_contains_both((a, b) in same_family_pair()) {
+				if let MergeResult::Merged(merged) = a.merge(&b) {
+					prop_assert!(merged.contains(&a));
+					prop_assert!(merged.contains(&b));
+				}
+			}
+		}
+	}
 }
@@ -0,0 +1,65 @@
+use super::NetAddr;
+use crate::inet;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+impl ToSql for NetAddr {
+	fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		out.extend_from_slice(&inet::encode(self, *ty == Type::CIDR)?);
+		Ok(IsNull::No)
+	}
+
+	accepts!(INET, CIDR);
+
+	to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for NetAddr {
+	fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		Ok(inet::decode(raw)?)
+	}
+
+	accepts!(INET, CIDR);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips() {
+		for (ty, net) in &[
+			(Type::INET, "192.168.1.0/24"),
+			(Type::CIDR, "10.0.0.0/8"),
+			(Type::INET, "2001:db8::/32"),
+			(Type::CIDR, "::1/128"),
+		] {
+			let mut out = BytesMut::new();
+			let net: NetAddr = net.parse().unwrap();
+
+			assert!(net.to_sql(ty, &mut out).is_ok());
+			assert_eq!(NetAddr::from_sql(ty, &out).unwrap(), net);
+		}
+	}
+
+	#[test]
+	fn to_sql_cidr_sets_flag() {
+		let mut out = BytesMut::new();
+		let net: NetAddr = "::1/128".parse().unwrap();
+
+		assert!(net.to_sql(&Type::CIDR, &mut out).is_ok());
+		assert_eq!(
+			&out[..],
+			&[
+				0x03, 0x80, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0x00, 0x00, 0x01,
+			]
+		);
+	}
+
+	#[test]
+	fn from_sql_malformed_fails() {
+		assert!(NetAddr::from_sql(&Type::INET, &[0x02, 0x18, 0x00]).is_err());
+	}
+}
@@ -0,0 +1,46 @@
+use super::NetAddr;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generate `NetAddr`s of either family for `quickcheck`.
+impl Arbitrary for NetAddr {
+	fn arbitrary(g: &mut Gen) -> Self {
+		if bool::arbitrary(g) {
+			Self::V4(Arbitrary::arbitrary(g))
+		} else {
+			Self::V6(Arbitrary::arbitrary(g))
+		}
+	}
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		match self {
+			Self::V4(v4) => Box::new(v4.shrink().map(Self::V4)),
+			Self::V6(v6) => Box::new(v6.shrink().map(Self::V6)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn produces_both_families() {
+		let mut g = Gen::new(100);
+		let nets: Vec<NetAddr> = (0..64).map(|_| NetAddr::arbitrary(&mut g)).collect();
+
+		assert!(nets.iter().any(|net| matches!(net, NetAddr::V4(_))));
+		assert!(nets.iter().any(|net| matches!(net, NetAddr::V6(_))));
+	}
+
+	#[test]
+	fn shrinks_keep_family() {
+		let mut g = Gen::new(100);
+
+		for _ in 0..16 {
+			let net: NetAddr = NetAddr::arbitrary(&mut g);
+			assert!(net
+				.shrink()
+				.all(|shrunk| shrunk.addr().is_ipv4() == net.addr().is_ipv4()));
+		}
+	}
+}
@@ -0,0 +1,131 @@
+use super::NetAddr;
+use core::net::IpAddr;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+impl NetAddr {
+	/// Pick an address from the inner `Netv4Addr` or `Netv6Addr` uniformly at
+	/// random.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Contains, NetAddr};
+	/// let net: NetAddr = "192.0.2.0/24".parse().unwrap();
+	/// let addr = net.random_addr(&mut rand::thread_rng());
+	/// assert!(net.contains(&addr));
+	/// ```
+	pub fn random_addr<R: Rng + ?Sized>(&self, rng: &mut R) -> IpAddr {
+		match self {
+			Self::V4(v4) => IpAddr::V4(v4.random_addr(rng)),
+			Self::V6(v6) => IpAddr::V6(v6.random_addr(rng)),
+		}
+	}
+
+	/// Pick an address from the inner `Netv4Addr` or `Netv6Addr` uniformly at
+	/// random, excluding the addresses whose host bits are all zeroes or all
+	/// ones.
+	///
+	/// Returns `None` if the network has fewer than four addresses.
+	pub fn random_host<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<IpAddr> {
+		match self {
+			Self::V4(v4) => v4.random_host(rng).map(IpAddr::V4),
+			Self::V6(v6) => v6.random_host(rng).map(IpAddr::V6),
+		}
+	}
+
+	/// Pick a subnet of the inner `Netv4Addr` or `Netv6Addr` with the given
+	/// prefix length uniformly at random.
+	///
+	/// Returns `None` if no subnet of that length exists.
+	pub fn random_subnet<R: Rng + ?Sized>(&self, prefix_len: u32, rng: &mut R) -> Option<Self> {
+		match self {
+			Self::V4(v4) => v4.random_subnet(prefix_len, rng).map(Self::V4),
+			Self::V6(v6) => v6.random_subnet(prefix_len, rng).map(Self::V6),
+		}
+	}
+}
+
+/// A `NetAddr` is a uniform distribution over its contained addresses.
+impl Distribution<IpAddr> for NetAddr {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IpAddr {
+		self.random_addr(rng)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Contains;
+	use rand::rngs::StdRng;
+	use rand::SeedableRng;
+
+	fn rng() -> StdRng {
+		StdRng::seed_from_u64(0x5eed)
+	}
+
+	mod v4 {
+		use super::*;
+
+		#[test]
+		fn random_addr_is_contained() {
+			let net: NetAddr = "192.0.2.0/24".parse().unwrap();
+			let mut rng = rng();
+
+			for _ in 0..1000 {
+				let addr: IpAddr = net.random_addr(&mut rng);
+				assert!(addr.is_ipv4());
+				assert!(net.contains(&addr));
+			}
+		}
+
+		#[test]
+		fn random_host_too_small_returns_none() {
+			let net: NetAddr = "192.0.2.0/31".parse().unwrap();
+			assert_eq!(net.random_host(&mut rng()), None);
+		}
+
+		#[test]
+		fn random_subnet_is_contained() {
+			let net: NetAddr = "10.0.0.0/8".parse().unwrap();
+			let subnet: NetAddr = net.random_subnet(24, &mut rng()).unwrap();
+			assert!(net.contains(&subnet));
+		}
+	}
+
+	mod v6 {
+		use super::*;
+
+		#[test]
+		fn random_addr_is_contained() {
+			let net: NetAddr = "2001:db8::/32".parse().unwrap();
+			let mut rng = rng();
+
+			for _ in 0..1000 {
+				let addr: IpAddr = net.random_addr(&mut rng);
+				assert!(addr.is_ipv6());
+				assert!(net.contains(&addr));
+			}
+		}
+
+		#[test]
+		fn random_host_excludes_network() {
+			let net: NetAddr = "2001:db8::/126".parse().unwrap();
+			let mut rng = rng();
+
+			for _ in 0..100 {
+				assert_ne!(net.random_host(&mut rng), Some(net.addr()));
+			}
+		}
+
+		#[test]
+		fn distribution_samples_contained_addresses() {
+			let net: NetAddr = "2001:db8::/32".parse().unwrap();
+
+			assert!(net
+				.sample_iter(rng())
+				.take(1000)
+				.all(|addr: IpAddr| net.contains(&addr)));
+		}
+	}
+}
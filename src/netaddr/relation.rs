@@ -0,0 +1,224 @@
+use super::NetAddr;
+use crate::{NetRelation, Netv4Addr, Netv6Addr};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+impl NetAddr {
+	/// Determine how this network relates to `other`, as sets of addresses.
+	///
+	/// Networks of different address families are always
+	/// [`NetRelation::Disjoint`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{NetAddr, NetRelation};
+	/// let net: NetAddr = "10.0.0.0/8".parse().unwrap();
+	///
+	/// assert_eq!(net.relation(&"10.1.0.0/16".parse().unwrap()), NetRelation::Contains);
+	/// assert_eq!(net.relation(&"::/0".parse().unwrap()), NetRelation::Disjoint);
+	/// ```
+	pub fn relation(&self, other: &Self) -> NetRelation {
+		match (self, other) {
+			(Self::V4(a), Self::V4(b)) => a.relation(b),
+			(Self::V6(a), Self::V6(b)) => a.relation(b),
+			(_, _) => NetRelation::Disjoint,
+		}
+	}
+
+	/// Determine whether this network shares any addresses with `other`.
+	pub fn overlaps(&self, other: &Self) -> bool {
+		!matches!(
+			self.relation(other),
+			NetRelation::Adjacent | NetRelation::Disjoint
+		)
+	}
+
+	/// Determine whether every address of this network is in `other`.
+	///
+	/// A network is a subnet of itself.
+	pub fn is_subnet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::ContainedBy
+		)
+	}
+
+	/// Determine whether every address of `other` is in this network.
+	///
+	/// A network is a supernet of itself.
+	pub fn is_supernet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::Contains
+		)
+	}
+
+	/// Determine whether this network shares no addresses with `other`.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		!self.overlaps(other)
+	}
+
+	/// Determine whether this network shares no addresses with `other`, but an
+	/// address of one is immediately followed by an address of the other.
+	pub fn is_adjacent(&self, other: &Self) -> bool {
+		self.relation(other) == NetRelation::Adjacent
+	}
+
+	/// Get the addresses in both this network and `other` as a network, if
+	/// there are any.
+	///
+	/// The addresses of a network are those which agree with its address on the
+	/// bits of its mask, so the shared addresses are those which agree with both
+	/// on either mask.
+	pub(crate) fn intersection(&self, other: &Self) -> Option<Self> {
+		if !self.overlaps(other) {
+			return None;
+		}
+
+		let ((addr, mask, bits), (other_addr, other_mask, _)) = (self.parts(), other.parts());
+		Some(Self::from_parts(addr | other_addr, mask | other_mask, bits))
+	}
+
+	/// Split the addresses of this network which are not in `other` into
+	/// disjoint networks, from the largest to the smallest.
+	///
+	/// Each bit fixed by the intersection but not by this network splits off
+	/// the addresses which differ from the intersection at that bit, but agree
+	/// with it on the bits above.
+	#[cfg(any(feature = "std", test))]
+	pub(crate) fn difference(&self, other: &Self) -> Vec<Self> {
+		let shared: Self = match self.intersection(other) {
+			Some(shared) => shared,
+			None => return vec![*self],
+		};
+
+		let (_, mask, bits) = self.parts();
+		let (shared_addr, shared_mask, _) = shared.parts();
+
+		let mut fixed: u128 = mask;
+		let mut pieces: Vec<Self> = Vec::new();
+
+		for bit in (0..bits).rev().map(|k| 1_u128 << k) {
+			if shared_mask & !mask & bit != 0 {
+				fixed |= bit;
+				pieces.push(Self::from_parts((shared_addr ^ bit) & fixed, fixed, bits));
+			}
+		}
+
+		pieces
+	}
+
+	/// Get the address, mask, and width in bits of the network.
+	pub(crate) fn parts(&self) -> (u128, u128, u32) {
+		match self {
+			Self::V4(net) => (
+				u32::from(net.addr()).into(),
+				u32::from(net.mask()).into(),
+				32,
+			),
+			Self::V6(net) => (net.addr().into(), net.mask().into(), 128),
+		}
+	}
+
+	fn from_parts(addr: u128, mask: u128, bits: u32) -> Self {
+		match bits {
+			32 => Netv4Addr::new(Ipv4Addr::from(addr as u32), Ipv4Addr::from(mask as u32)).into(),
+			_ => Netv6Addr::new(Ipv6Addr::from(addr), Ipv6Addr::from(mask)).into(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{strategy, Contains};
+	use core::net::IpAddr;
+	use proptest::prelude::*;
+
+	#[test]
+	fn same_family() {
+		let a: NetAddr = "10.0.0.0/24".parse().unwrap();
+		let b: NetAddr = "10.0.1.0/24".parse().unwrap();
+
+		assert!(a.is_adjacent(&b));
+		assert!(a.is_subnet_of(&"10.0.0.0/8".parse().unwrap()));
+
+		let a: NetAddr = "2001:db8::/32".parse().unwrap();
+		let b: NetAddr = "2001:db8:1::/48".parse().unwrap();
+
+		assert!(a.is_supernet_of(&b));
+		assert_eq!(b.relation(&a), NetRelation::ContainedBy);
+	}
+
+	#[test]
+	fn mixed_families_are_disjoint() {
+		let v4: NetAddr = "0.0.0.0/0".parse().unwrap();
+		let v6: NetAddr = "::/0".parse().unwrap();
+
+		assert_eq!(v4.relation(&v6), NetRelation::Disjoint);
+		assert_eq!(v6.relation(&v4), NetRelation::Disjoint);
+		assert!(v4.is_disjoint(&v6));
+		assert!(!v4.overlaps(&v6) && !v4.is_adjacent(&v6));
+		assert!(!v4.is_subnet_of(&v6) && !v4.is_supernet_of(&v6));
+	}
+
+	#[test]
+	fn intersections() {
+		assert_eq!(
+			pu!("10.0.0.0/255.0.255.0" # NetAddr).intersection(&pu!("10.1.0.0/16" # NetAddr)),
+			Some(pu!("10.1.0.0/255.255.255.0" # NetAddr))
+		);
+		assert_eq!(
+			pu!("2001:db8::/32" # NetAddr).intersection(&pu!("2001:db8:1::/48" # NetAddr)),
+			Some(pu!("2001:db8:1::/48" # NetAddr))
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8" # NetAddr).intersection(&pu!("11.0.0.0/8" # NetAddr)),
+			None
+		);
+		assert_eq!(
+			pu!("0.0.0.0/0" # NetAddr).intersection(&pu!("::/0" # NetAddr)),
+			None
+		);
+	}
+
+	#[test]
+	fn differences() {
+		let net: NetAddr = pu!("10.0.0.0/8" # NetAddr);
+
+		assert_eq!(
+			net.difference(&pu!("10.128.0.0/10" # NetAddr)),
+			vec![pu!("10.0.0.0/9" # NetAddr), pu!("10.192.0.0/10" # NetAddr)]
+		);
+		assert_eq!(net.difference(&pu!("0.0.0.0/0" # NetAddr)), vec![]);
+		assert_eq!(net.difference(&pu!("::/0" # NetAddr)), vec![net]);
+		assert_eq!(
+			net.difference(&pu!("0.0.0.0/0.255.0.0" # NetAddr)),
+			vec![
+				pu!("10.128.0.0/255.128.0.0" # NetAddr),
+				pu!("10.64.0.0/255.192.0.0" # NetAddr),
+				pu!("10.32.0.0/255.224.0.0" # NetAddr),
+				pu!("10.16.0.0/255.240.0.0" # NetAddr),
+				pu!("10.8.0.0/255.248.0.0" # NetAddr),
+				pu!("10.4.0.0/255.252.0.0" # NetAddr),
+				pu!("10.2.0.0/255.254.0.0" # NetAddr),
+				pu!("10.1.0.0/255.255.0.0" # NetAddr)
+			]
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn difference_partitions(a in strategy::netaddr(), b in strategy::netaddr(), addr in any::<u128>()) {
+			let addr: IpAddr = match a {
+				NetAddr::V4(_) => Ipv4Addr::from(addr as u32).into(),
+				NetAddr::V6(_) => Ipv6Addr::from(addr).into(),
+			};
+			let pieces = a.difference(&b);
+			let holding = pieces.iter().filter(|piece| piece.contains(&addr)).count();
+
+			prop_assert!(pieces.iter().all(|piece| a.contains(piece) && piece.is_disjoint(&b)));
+			prop_assert_eq!(holding, usize::from(a.contains(&addr) && !b.contains(&addr)));
+		}
+	}
+}
@@ -0,0 +1,102 @@
+use super::NetAddr;
+use crate::{Netv4Addr, Netv6Addr};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+/// Stores the network as text, like `"192.0.2.0/24"` or `"2001:db8::/32"`.
+impl ToSql for NetAddr {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		Ok(ToSqlOutput::from(self.to_string()))
+	}
+}
+
+/// Reads the network from text, in any notation accepted by `parse_ascii` or
+/// as a bare address, or from a blob of the address octets followed by the mask
+/// octets, which is eight octets long for IPv4 and thirty-two for IPv6.
+impl FromSql for NetAddr {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		match value {
+			ValueRef::Text(text) => Self::parse_ascii(text)
+				.or_else(|error| {
+					crate::parser::ipv4addr(text)
+						.map(Self::from)
+						.or_else(|| crate::parser::ipv6addr(text).map(Self::from))
+						.ok_or(error)
+				})
+				.map_err(|error| FromSqlError::Other(Box::new(error))),
+			ValueRef::Blob(blob) if blob.len() == 8 => Netv4Addr::column_result(value).map(Self::V4),
+			ValueRef::Blob(_) => Netv6Addr::column_result(value).map(Self::V6),
+			_ => Err(FromSqlError::InvalidType),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rusqlite::Connection;
+
+	fn round_trip(net: &NetAddr) -> NetAddr {
+		let conn = Connection::open_in_memory().unwrap();
+		conn
+			.execute("CREATE TABLE nets (net TEXT NOT NULL)", [])
+			.unwrap();
+		conn
+			.execute("INSERT INTO nets (net) VALUES (?1)", [net])
+			.unwrap();
+		conn
+			.query_row("SELECT net FROM nets", [], |row| row.get(0))
+			.unwrap()
+	}
+
+	fn select(sql: &str) -> rusqlite::Result<NetAddr> {
+		let conn = Connection::open_in_memory().unwrap();
+		conn.query_row(sql, [], |row| row.get(0))
+	}
+
+	#[test]
+	fn round_trips_through_text() {
+		for net in &[
+			"192.0.2.0/24",
+			"10.0.0.0/255.0.255.0",
+			"2001:db8::/32",
+			"2001:db8::/ffff::ffff",
+		] {
+			let net: NetAddr = net.parse().unwrap();
+
+			assert_eq!(round_trip(&net), net);
+		}
+	}
+
+	#[test]
+	fn stores_text() {
+		let conn = Connection::open_in_memory().unwrap();
+		let net: NetAddr = "2001:db8::/32".parse().unwrap();
+		let text: String = conn
+			.query_row("SELECT ?1", [net], |row| row.get(0))
+			.unwrap();
+
+		assert_eq!(text, "2001:db8::/32");
+	}
+
+	#[test]
+	fn reads_bare_address() {
+		let net: NetAddr = "2001:db8::1/128".parse().unwrap();
+
+		assert_eq!(select("SELECT '2001:db8::1'").unwrap(), net);
+	}
+
+	#[test]
+	fn reads_blob() {
+		let net: NetAddr = "10.0.0.0/255.0.255.0".parse().unwrap();
+
+		assert_eq!(select("SELECT x'0a000000ff00ff00'").unwrap(), net);
+	}
+
+	#[test]
+	fn rejects_other_values() {
+		assert!(select("SELECT 'not a network'").is_err());
+		assert!(select("SELECT x'0a000000'").is_err());
+		assert!(select("SELECT 42").is_err());
+		assert!(select("SELECT NULL").is_err());
+	}
+}
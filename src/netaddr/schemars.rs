@@ -0,0 +1,48 @@
+use super::NetAddr;
+use crate::pattern;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+/// Describes the network as a string in CIDR or extended notation, or as a
+/// single address.
+impl JsonSchema for NetAddr {
+	fn schema_name() -> Cow<'static, str> {
+		"NetAddr".into()
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		"netaddr2::NetAddr".into()
+	}
+
+	fn json_schema(_: &mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"type": "string",
+			"description": "An IPv4 or IPv6 network, with either a prefix length or a netmask, or a single address.",
+			"pattern": pattern::NETADDR,
+			"examples": ["192.0.2.0/24", "2001:db8::/32", "192.0.2.1"],
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use schemars::schema_for;
+	use serde_json::json;
+
+	#[test]
+	fn schema_is_a_string_with_a_pattern() {
+		let schema = schema_for!(NetAddr);
+
+		assert_eq!(schema.get("type"), Some(&json!("string")));
+		assert_eq!(schema.get("pattern"), Some(&json!(pattern::NETADDR)));
+	}
+
+	#[test]
+	fn schema_is_referenced_by_name() {
+		let mut generator = SchemaGenerator::default();
+		let schema = generator.subschema_for::<NetAddr>();
+
+		assert_eq!(schema.get("$ref"), Some(&json!("#/$defs/NetAddr")));
+	}
+}
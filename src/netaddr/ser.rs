@@ -2,17 +2,29 @@ use super::NetAddr;
 
 use serde::{Serialize, Serializer};
 
+/// Serializes the network as a string (e.g. `"2001:db8::/32"`) for
+/// human-readable formats.
+///
+/// For compact formats, the network is instead serialized as the `V4` or `V6`
+/// variant holding the compact form of the inner network.
 #[cfg(feature = "serde")]
 impl Serialize for NetAddr {
 	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		serializer.serialize_str(&self.to_string())
+		if serializer.is_human_readable() {
+			return serializer.collect_str(self);
+		}
+
+		match self {
+			Self::V4(netaddr) => serializer.serialize_newtype_variant("NetAddr", 0, "V4", netaddr),
+			Self::V6(netaddr) => serializer.serialize_newtype_variant("NetAddr", 1, "V6", netaddr),
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::NetAddr;
-	use serde_test::{assert_tokens, Token};
+	use serde_test::{assert_tokens, Configure, Token};
 
 	mod v4 {
 		use super::*;
@@ -21,14 +33,45 @@ mod tests {
 		fn test_ser_cidr_localhost() {
 			let netaddr: NetAddr = "127.0.0.1/8".parse().unwrap();
 
-			assert_tokens(&netaddr, &[Token::Str("127.0.0.0/8")]);
+			assert_tokens(&netaddr.readable(), &[Token::Str("127.0.0.0/8")]);
 		}
 
 		#[test]
 		fn test_ser_non_cidr_localhost() {
 			let netaddr: NetAddr = "127.0.0.1/251.255.255.7".parse().unwrap();
 
-			assert_tokens(&netaddr, &[Token::Str("123.0.0.1/251.255.255.7")]);
+			assert_tokens(
+				&netaddr.readable(),
+				&[Token::Str("123.0.0.1/251.255.255.7")],
+			);
+		}
+
+		#[test]
+		fn test_ser_compact_cidr() {
+			let netaddr: NetAddr = "127.0.0.1/8".parse().unwrap();
+
+			assert_tokens(
+				&netaddr.compact(),
+				&[
+					Token::NewtypeVariant {
+						name: "NetAddr",
+						variant: "V4",
+					},
+					Token::TupleVariant {
+						name: "Netv4Addr",
+						variant: "Cidr",
+						len: 2,
+					},
+					Token::Tuple { len: 4 },
+					Token::U8(127),
+					Token::U8(0),
+					Token::U8(0),
+					Token::U8(0),
+					Token::TupleEnd,
+					Token::U8(8),
+					Token::TupleVariantEnd,
+				],
+			);
 		}
 	}
 
@@ -39,7 +82,7 @@ mod tests {
 		fn test_ser_cidr_localhost() {
 			let netaddr: NetAddr = "ff02::1/60".parse().unwrap();
 
-			assert_tokens(&netaddr, &[Token::Str("ff02::/60")]);
+			assert_tokens(&netaddr.readable(), &[Token::Str("ff02::/60")]);
 		}
 
 		#[test]
@@ -47,9 +90,24 @@ mod tests {
 			let netaddr: NetAddr = "ff02:dead:beef::1/ff02:eeee:eeee::1".parse().unwrap();
 
 			assert_tokens(
-				&netaddr,
+				&netaddr.readable(),
 				&[Token::Str("ff02:ceac:aeee::1/ff02:eeee:eeee::1")],
 			);
 		}
 	}
+
+	#[test]
+	fn test_ser_compact_bincode_layout() {
+		let v4: NetAddr = "192.0.2.0/24".parse().unwrap();
+		let v6: NetAddr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			bincode::serialize(&v4).unwrap(),
+			[0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 2, 0, 24]
+		);
+		assert_eq!(
+			bincode::serialize(&v6).unwrap(),
+			[1, 0, 0, 0, 0, 0, 0, 0, 0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32]
+		);
+	}
 }
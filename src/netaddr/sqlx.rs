@@ -0,0 +1,81 @@
+use super::NetAddr;
+use crate::inet;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{
+	PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+impl Type<Postgres> for NetAddr {
+	fn type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_OID))
+	}
+
+	fn compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_OID))
+	}
+}
+
+impl PgHasArrayType for NetAddr {
+	fn array_type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+	}
+
+	fn array_compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_ARRAY_OID))
+	}
+}
+
+impl Encode<'_, Postgres> for NetAddr {
+	fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+		buf.extend_from_slice(&inet::encode(self, false)?);
+		Ok(IsNull::No)
+	}
+}
+
+impl<'r> Decode<'r, Postgres> for NetAddr {
+	fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+		let net = match value.format() {
+			PgValueFormat::Binary => inet::decode(value.as_bytes()?)?,
+			PgValueFormat::Text => inet::decode_text(value.as_str()?)?,
+		};
+
+		Ok(net)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encodes_captured_bytes() {
+		let mut buf = PgArgumentBuffer::default();
+		let net: NetAddr = "10.0.0.0/8".parse().unwrap();
+
+		assert!(matches!(net.encode_by_ref(&mut buf), Ok(IsNull::No)));
+		assert_eq!(&buf[..], &[0x02, 0x08, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x00]);
+	}
+
+	#[test]
+	fn encoding_non_cidr_fails() {
+		let mut buf = PgArgumentBuffer::default();
+		let net: NetAddr = "10.0.0.0/255.0.255.0".parse().unwrap();
+
+		assert!(net.encode_by_ref(&mut buf).is_err());
+	}
+
+	#[test]
+	fn array_compatible_with_inet_and_cidr() {
+		assert!(<NetAddr as PgHasArrayType>::array_compatible(
+			&PgTypeInfo::with_oid(Oid(1041))
+		));
+		assert!(<NetAddr as PgHasArrayType>::array_compatible(
+			&PgTypeInfo::with_oid(Oid(651))
+		));
+	}
+}
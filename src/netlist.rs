@@ -0,0 +1,146 @@
+use crate::NetAddr;
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut};
+
+/// A list of networks, written as a comma-separated string.
+///
+/// This is convenient for configuration which can only be given as a single
+/// string, such as environment variables or command-line arguments.  Parsing
+/// accepts any notation that the network type's `FromStr` implementation does,
+/// ignores whitespace around each network, and skips empty entries, so a
+/// trailing comma or an empty string is fine.  Formatting separates the
+/// networks with a comma and no whitespace.
+///
+/// With the `serde` feature, a `NetList` deserializes from either such a
+/// string or a sequence of networks.  Either way, each network is read as it
+/// is when deserialized on its own, so it may also be a bare address.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{NetAddr, NetList};
+/// let list: NetList = "10.0.0.0/8, 2001:db8::/32,".parse().unwrap();
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list[1], "2001:db8::/32".parse::<NetAddr>().unwrap());
+/// assert_eq!(list.to_string(), "10.0.0.0/8,2001:db8::/32");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NetList<N = NetAddr>(pub Vec<N>);
+
+impl<N> NetList<N> {
+	/// Create an empty `NetList`.
+	pub const fn new() -> Self {
+		Self(Vec::new())
+	}
+
+	/// Unwrap the list into its networks.
+	pub fn into_inner(self) -> Vec<N> {
+		self.0
+	}
+}
+
+impl<N> Deref for NetList<N> {
+	type Target = Vec<N>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<N> DerefMut for NetList<N> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<N> From<Vec<N>> for NetList<N> {
+	fn from(nets: Vec<N>) -> Self {
+		Self(nets)
+	}
+}
+
+impl<N> From<NetList<N>> for Vec<N> {
+	fn from(list: NetList<N>) -> Self {
+		list.0
+	}
+}
+
+impl<N> FromIterator<N> for NetList<N> {
+	fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+impl<N> IntoIterator for NetList<N> {
+	type Item = N;
+	type IntoIter = std::vec::IntoIter<N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, N> IntoIterator for &'a NetList<N> {
+	type Item = &'a N;
+	type IntoIter = core::slice::Iter<'a, N>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+/// Networks which are displayed separated by commas.
+pub(crate) struct Joined<'a, N>(pub(crate) &'a [N]);
+
+impl<N: fmt::Display> fmt::Display for Joined<'_, N> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, net) in self.0.iter().enumerate() {
+			if i > 0 {
+				f.write_str(",")?;
+			}
+
+			write!(f, "{}", net)?;
+		}
+
+		Ok(())
+	}
+}
+
+mod display;
+mod fromstr;
+
+#[cfg(feature = "serde")]
+mod de;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod ser;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Netv4Addr;
+
+	#[test]
+	fn collects_and_iterates() {
+		let list: NetList<Netv4Addr> = vec![
+			pu!("10.0.0.0/8" # Netv4Addr),
+			pu!("192.0.2.0/24" # Netv4Addr),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(list.iter().count(), 2);
+		assert_eq!(
+			(&list).into_iter().next(),
+			Some(&pu!("10.0.0.0/8" # Netv4Addr))
+		);
+		assert_eq!(Vec::from(list.clone()), list.into_inner());
+	}
+
+	#[test]
+	fn new_is_empty() {
+		assert!(NetList::<NetAddr>::new().is_empty());
+	}
+}
@@ -0,0 +1,125 @@
+use super::NetList;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::{de, Deserialize, Deserializer};
+
+struct NetListVisitor<N>(PhantomData<N>);
+
+impl<'de, N: Deserialize<'de>> de::Visitor<'de> for NetListVisitor<N> {
+	type Value = NetList<N>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a comma-separated list of networks or a sequence of networks")
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		value
+			.split(',')
+			.map(str::trim)
+			.filter(|net| !net.is_empty())
+			.map(|net| N::deserialize(de::value::StrDeserializer::<E>::new(net)))
+			.collect::<Result<_, E>>()
+			.map(NetList)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut nets = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+		while let Some(net) = seq.next_element()? {
+			nets.push(net);
+		}
+
+		Ok(NetList(nets))
+	}
+}
+
+/// Deserializes the list from a comma-separated string, split as the `FromStr`
+/// implementation does but with each network read as it is when deserialized,
+/// so that bare addresses are accepted, or from a sequence of networks.
+impl<'de, N: Deserialize<'de>> Deserialize<'de> for NetList<N> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(NetListVisitor(PhantomData))
+		} else {
+			deserializer.deserialize_seq(NetListVisitor(PhantomData))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, NetList, Netv4Addr};
+	use serde_test::{assert_de_tokens, assert_de_tokens_error, Configure, Token};
+
+	#[test]
+	fn from_string() {
+		let list = NetList(vec![
+			pu!("10.0.0.0/8" # NetAddr),
+			pu!("2001:db8::/32" # NetAddr),
+		]);
+
+		assert_de_tokens(
+			&list.clone().readable(),
+			&[Token::Str("10.0.0.0/8, 2001:db8::/32")],
+		);
+		assert_de_tokens(&list.readable(), &[Token::Str("10.0.0.0/8,2001:db8::/32,")]);
+	}
+
+	#[test]
+	fn from_string_of_bare_addresses() {
+		let list = NetList(vec![
+			pu!("192.0.2.1/32" # NetAddr),
+			pu!("2001:db8::1/128" # NetAddr),
+		]);
+
+		assert_de_tokens(&list.readable(), &[Token::Str("192.0.2.1, 2001:db8::1")]);
+	}
+
+	#[test]
+	fn from_sequence() {
+		let list = NetList(vec![
+			pu!("10.0.0.0/8" # Netv4Addr),
+			pu!("192.0.2.1/32" # Netv4Addr),
+		]);
+
+		assert_de_tokens(
+			&list.readable(),
+			&[
+				Token::Seq { len: Some(2) },
+				Token::Str("10.0.0.0/8"),
+				Token::Str("192.0.2.1"),
+				Token::SeqEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn invalid_entry_fails() {
+		assert_de_tokens_error::<serde_test::Readable<NetList>>(
+			&[Token::Str("10.0.0.0/8,zoop")],
+			"invalid value: string \"10.0.0.0/8,zoop\", expected a comma-separated list of networks or a sequence of networks",
+		);
+	}
+
+	#[test]
+	fn from_environment_style_json() {
+		let list: NetList = serde_json::from_str(r#""10.0.0.0/8,fd00::/8""#).unwrap();
+		assert_eq!(list.len(), 2);
+
+		let list: NetList =
+			serde_json::from_str(r#"["10.0.0.0/8", {"address": "fd00::", "prefix": 8}]"#).unwrap();
+		assert_eq!(list[1], pu!("fd00::/8" # NetAddr));
+	}
+
+	#[test]
+	fn compact_round_trip() {
+		let list = NetList(vec![
+			pu!("10.0.0.0/8" # NetAddr),
+			pu!("2001:db8::/32" # NetAddr),
+		]);
+		let bytes = bincode::serialize(&list).unwrap();
+
+		assert_eq!(bincode::deserialize::<NetList>(&bytes).unwrap(), list);
+	}
+}
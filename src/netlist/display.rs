@@ -0,0 +1,32 @@
+use super::{Joined, NetList};
+use core::fmt;
+
+impl<N: fmt::Display> fmt::Display for NetList<N> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		Joined(&self.0).fmt(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, NetList};
+
+	#[test]
+	fn separates_with_commas() {
+		let list = NetList(vec![
+			pu!("10.0.0.0/8" # NetAddr),
+			pu!("2001:db8::/ffff:ffff::" # NetAddr),
+			pu!("10.0.0.0/255.0.255.0" # NetAddr),
+		]);
+
+		assert_eq!(
+			list.to_string(),
+			"10.0.0.0/8,2001:db8::/32,10.0.0.0/255.0.255.0"
+		);
+	}
+
+	#[test]
+	fn empty_is_empty_string() {
+		assert_eq!(NetList::<NetAddr>::new().to_string(), "");
+	}
+}
@@ -0,0 +1,61 @@
+use super::NetList;
+use crate::Error;
+use core::str::FromStr;
+
+impl<N: FromStr<Err = Error>> FromStr for NetList<N> {
+	type Err = Error;
+
+	/// Parse a comma-separated list of networks.
+	///
+	/// Whitespace around each network is ignored, and empty entries are
+	/// skipped.  The first network which fails to parse gives the error.
+	fn from_str(string: &str) -> Result<Self, Error> {
+		string
+			.split(',')
+			.map(str::trim)
+			.filter(|net| !net.is_empty())
+			.map(str::parse)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Error, NetAddr, NetList, Netv4Addr, Netv6Addr};
+
+	#[test]
+	fn mixed_families() {
+		let list: NetList = "10.0.0.0/8,2001:db8::/32".parse().unwrap();
+		assert_eq!(
+			list.0,
+			vec![pu!("10.0.0.0/8" # NetAddr), pu!("2001:db8::/32" # NetAddr)]
+		);
+	}
+
+	#[test]
+	fn ignores_whitespace_and_empty_entries() {
+		let list: NetList<Netv4Addr> = " 10.0.0.0/8 ,, 192.0.2.0 255.255.255.0,\n".parse().unwrap();
+		assert_eq!(
+			list.0,
+			vec![
+				pu!("10.0.0.0/8" # Netv4Addr),
+				pu!("192.0.2.0/24" # Netv4Addr)
+			]
+		);
+	}
+
+	#[test]
+	fn empty_string_is_empty_list() {
+		assert_eq!("".parse::<NetList<Netv6Addr>>(), Ok(NetList::new()));
+		assert_eq!(" , ".parse::<NetList<Netv6Addr>>(), Ok(NetList::new()));
+	}
+
+	#[test]
+	fn invalid_entry_fails() {
+		assert!("10.0.0.0/8,zoop".parse::<NetList>().is_err());
+		assert!(matches!(
+			"2001:db8::/32".parse::<NetList<Netv4Addr>>(),
+			Err(Error::ParseError(_))
+		));
+	}
+}
@@ -0,0 +1,65 @@
+use super::NetList;
+use crate::pattern;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+/// Describes the list as either a comma-separated string or an array of
+/// networks.
+impl<N: JsonSchema> JsonSchema for NetList<N> {
+	fn schema_name() -> Cow<'static, str> {
+		format!("NetList_for_{}", N::schema_name()).into()
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		format!("netaddr2::NetList<{}>", N::schema_id()).into()
+	}
+
+	fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"description": "A list of networks, either as a comma-separated string or as an array.",
+			"anyOf": [
+				{
+					"type": "string",
+					"pattern": pattern::NETLIST,
+				},
+				{
+					"type": "array",
+					"items": generator.subschema_for::<N>(),
+				},
+			],
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{NetAddr, Netv4Addr};
+	use regex::Regex;
+	use schemars::schema_for;
+	use serde_json::json;
+
+	#[test]
+	fn schema_allows_strings_and_arrays() {
+		let schema = schema_for!(NetList<Netv4Addr>);
+		let any_of = schema.get("anyOf").unwrap();
+
+		assert_eq!(any_of[0]["type"], json!("string"));
+		assert_eq!(any_of[1]["type"], json!("array"));
+		assert_eq!(any_of[1]["items"], json!({ "$ref": "#/$defs/Netv4Addr" }));
+	}
+
+	#[test]
+	fn pattern_matches_lists() {
+		let pattern = Regex::new(pattern::NETLIST).unwrap();
+
+		assert!(pattern.is_match(""));
+		assert!(pattern.is_match("10.0.0.0/8"));
+		assert!(pattern.is_match("10.0.0.0/8, 2001:db8::/32,"));
+		assert!(pattern.is_match("192.0.2.1, 2001:db8::1"));
+		assert!(!pattern.is_match("10.0.0.0/8,zoop"));
+
+		let list: NetList<NetAddr> = "10.0.0.0/8, 2001:db8::/32,".parse().unwrap();
+		assert!(pattern.is_match(&list.to_string()));
+	}
+}
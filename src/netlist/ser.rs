@@ -0,0 +1,40 @@
+use super::NetList;
+use core::fmt;
+use serde::{Serialize, Serializer};
+
+/// Serializes the list as a comma-separated string (e.g.
+/// `"10.0.0.0/8,2001:db8::/32"`) for human-readable formats, or as a sequence
+/// of networks for compact formats.
+impl<N: fmt::Display + Serialize> Serialize for NetList<N> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			serializer.collect_str(self)
+		} else {
+			self.0.serialize(serializer)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, NetList};
+	use serde_test::{assert_ser_tokens, Configure, Token};
+
+	#[test]
+	fn readable_is_a_string() {
+		let list = NetList(vec![
+			pu!("10.0.0.0/8" # NetAddr),
+			pu!("2001:db8::/32" # NetAddr),
+		]);
+
+		assert_ser_tokens(&list.readable(), &[Token::Str("10.0.0.0/8,2001:db8::/32")]);
+	}
+
+	#[test]
+	fn compact_is_a_sequence() {
+		let list = NetList(vec![pu!("10.0.0.0/8" # NetAddr)]);
+		let bytes = bincode::serialize(&list).unwrap();
+
+		assert_eq!(bytes, bincode::serialize(&list.0).unwrap());
+	}
+}
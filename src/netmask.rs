@@ -0,0 +1,112 @@
+use crate::PrefixLen;
+use core::net::Ipv4Addr;
+
+/// An IPv4 netmask.
+///
+/// This may be contiguous (a number of ones followed by zeroes, as in a CIDR
+/// network) or not; see [`Netmask::is_contiguous`], and [`PrefixLen`] for a
+/// type which can only be contiguous.  A `Netmask` converts to and from its
+/// other notations: an `Ipv4Addr` like `255.255.252.0`, a `u32` like
+/// `0xfffffc00`, a [`PrefixLen`] like `/22`, and a wildcard (or hostmask) like
+/// `0.0.3.255`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netmask;
+/// let mask: Netmask = "/22".parse().unwrap();
+///
+/// assert_eq!(mask.to_string(), "255.255.252.0");
+/// assert_eq!(format!("{:#}", mask), "/22");
+/// assert_eq!(format!("{:#x}", mask), "0xfffffc00");
+/// assert_eq!(mask.wildcard().to_string(), "0.0.3.255");
+/// assert_eq!(mask, "0xfffffc00".parse().unwrap());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Netmask(Ipv4Addr);
+
+impl Netmask {
+	/// Create a `Netmask` from its dotted-quad form.
+	pub const fn new(mask: Ipv4Addr) -> Self {
+		Self(mask)
+	}
+
+	/// Create a `Netmask` from its wildcard, the inverse of the netmask, as
+	/// written in access lists.
+	pub const fn from_wildcard(wildcard: Ipv4Addr) -> Self {
+		Self(Ipv4Addr::from_bits(!wildcard.to_bits()))
+	}
+
+	/// Get the netmask as an address, like `255.255.252.0`.
+	pub const fn addr(self) -> Ipv4Addr {
+		self.0
+	}
+
+	/// Get the netmask as a number, like `0xfffffc00`.
+	pub const fn to_bits(self) -> u32 {
+		self.0.to_bits()
+	}
+
+	/// Get the hostmask, which has a one for every bit that varies between the
+	/// hosts of a network: the inverse of the netmask.
+	pub const fn hostmask(self) -> Ipv4Addr {
+		Ipv4Addr::from_bits(!self.to_bits())
+	}
+
+	/// Get the wildcard mask, as written in access lists.
+	///
+	/// This is the same as the [hostmask](Netmask::hostmask).
+	pub const fn wildcard(self) -> Ipv4Addr {
+		self.hostmask()
+	}
+
+	/// Determine whether the netmask is contiguous, that is, some number of
+	/// ones followed by zeroes.
+	pub const fn is_contiguous(self) -> bool {
+		let bits: u32 = self.to_bits();
+		bits.leading_ones() + bits.trailing_zeros() == 32
+	}
+
+	/// Get the prefix length, if the netmask is contiguous.
+	pub const fn prefix_len(self) -> Option<PrefixLen> {
+		if self.is_contiguous() {
+			PrefixLen::new(self.to_bits().leading_ones() as u8)
+		} else {
+			None
+		}
+	}
+}
+
+mod display;
+mod from;
+mod fromstr;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn is_contiguous() {
+		assert!(Netmask::from(0).is_contiguous());
+		assert!(Netmask::from(u32::MAX).is_contiguous());
+		assert!(Netmask::from(0xffff_fc00).is_contiguous());
+		assert!(!Netmask::from(0xff00_ff00).is_contiguous());
+		assert!(!Netmask::from(1).is_contiguous());
+	}
+
+	#[test]
+	fn prefix_len() {
+		assert_eq!(Netmask::from(0).prefix_len(), PrefixLen::new(0));
+		assert_eq!(Netmask::from(u32::MAX).prefix_len(), PrefixLen::new(32));
+		assert_eq!(Netmask::from(0xff00_ff00).prefix_len(), None);
+	}
+
+	#[test]
+	fn hostmask_and_wildcard() {
+		let mask: Netmask = Netmask::new(Ipv4Addr::new(255, 255, 252, 0));
+
+		assert_eq!(mask.hostmask(), Ipv4Addr::new(0, 0, 3, 255));
+		assert_eq!(mask.wildcard(), mask.hostmask());
+		assert_eq!(Netmask::from_wildcard(mask.wildcard()), mask);
+	}
+}
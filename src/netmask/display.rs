@@ -0,0 +1,49 @@
+use super::Netmask;
+use core::fmt;
+
+impl fmt::Display for Netmask {
+	/// Format the netmask as an address, like `255.255.252.0`, or with the
+	/// alternate flag (`{:#}`) as a prefix length like `/22` if it is contiguous.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.prefix_len() {
+			Some(len) if f.alternate() => fmt::Display::fmt(&len, f),
+			_ => fmt::Display::fmt(&self.addr(), f),
+		}
+	}
+}
+
+impl fmt::LowerHex for Netmask {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerHex::fmt(&self.to_bits(), f)
+	}
+}
+
+impl fmt::UpperHex for Netmask {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperHex::fmt(&self.to_bits(), f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn contiguous() {
+		let mask: Netmask = Netmask::from(0xffff_fc00);
+
+		assert_eq!(format!("{}", mask), "255.255.252.0");
+		assert_eq!(format!("{:#}", mask), "/22");
+		assert_eq!(format!("{:x}", mask), "fffffc00");
+		assert_eq!(format!("{:#X}", mask), "0xFFFFFC00");
+	}
+
+	#[test]
+	fn non_contiguous() {
+		let mask: Netmask = Netmask::from(0xff00_ff00);
+
+		assert_eq!(format!("{}", mask), "255.0.255.0");
+		assert_eq!(format!("{:#}", mask), "255.0.255.0");
+		assert_eq!(format!("{:#010x}", Netmask::from(0xff)), "0x000000ff");
+	}
+}
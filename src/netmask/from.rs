@@ -0,0 +1,64 @@
+use super::Netmask;
+use crate::{Error, PrefixLen};
+use core::convert::TryFrom;
+use core::net::Ipv4Addr;
+
+impl From<Ipv4Addr> for Netmask {
+	fn from(mask: Ipv4Addr) -> Self {
+		Self::new(mask)
+	}
+}
+
+impl From<Netmask> for Ipv4Addr {
+	fn from(mask: Netmask) -> Self {
+		mask.addr()
+	}
+}
+
+impl From<u32> for Netmask {
+	fn from(bits: u32) -> Self {
+		Self::new(Ipv4Addr::from(bits))
+	}
+}
+
+impl From<Netmask> for u32 {
+	fn from(mask: Netmask) -> Self {
+		mask.to_bits()
+	}
+}
+
+impl TryFrom<PrefixLen> for Netmask {
+	type Error = Error;
+
+	/// Convert a prefix length into a contiguous netmask, failing if it is
+	/// longer than 32.
+	fn try_from(len: PrefixLen) -> Result<Self, Error> {
+		match u32::from(len) {
+			len @ 0..=32 => Ok(Self::from(u32::MAX.checked_shl(32 - len).unwrap_or(0))),
+			_ => Err(Error::InvalidPrefixLen),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_prefix_len() {
+		let len = |len: u8| PrefixLen::new(len).unwrap();
+
+		assert_eq!(Netmask::try_from(len(0)), Ok(Netmask::from(0)));
+		assert_eq!(Netmask::try_from(len(22)), Ok(Netmask::from(0xffff_fc00)));
+		assert_eq!(Netmask::try_from(len(32)), Ok(Netmask::from(u32::MAX)));
+		assert_eq!(Netmask::try_from(len(33)), Err(Error::InvalidPrefixLen));
+	}
+
+	#[test]
+	fn round_trips() {
+		let mask: Ipv4Addr = Ipv4Addr::new(255, 0, 255, 0);
+
+		assert_eq!(Ipv4Addr::from(Netmask::from(mask)), mask);
+		assert_eq!(u32::from(Netmask::from(mask)), 0xff00_ff00);
+	}
+}
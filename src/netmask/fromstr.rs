@@ -0,0 +1,63 @@
+use super::Netmask;
+use crate::parser;
+use crate::{Error, Result};
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+impl FromStr for Netmask {
+	type Err = Error;
+
+	/// Parse a `Netmask` from a string
+	///
+	/// This accepts any of the notations for a netmask:
+	/// - A prefix length, with or without a leading `/`: `22` or `/22`
+	/// - A dotted-quad address: `255.255.252.0`
+	/// - A `0x`-prefixed hexadecimal number: `0xfffffc00`
+	///
+	/// A wildcard like `0.0.3.255` is indistinguishable from a non-contiguous
+	/// netmask, so it is read as one; use [`Netmask::from_wildcard`] instead.
+	fn from_str(string: &str) -> Result<Self> {
+		let bytes: &[u8] = string.as_bytes();
+
+		if let Some(len) = parser::mask_prefix_len(bytes, 32) {
+			return Self::try_from(len?);
+		}
+
+		if let Some(bits) = parser::hex_mask(bytes, 32) {
+			return Ok(Self::from(bits as u32));
+		}
+
+		parser::ipv4addr(bytes)
+			.map(Self::new)
+			.ok_or(Error::ParseError("invalid IPv4 netmask syntax"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn every_notation() {
+		let mask: Netmask = Netmask::from(0xffff_fc00);
+
+		assert_eq!("22".parse(), Ok(mask));
+		assert_eq!("/22".parse(), Ok(mask));
+		assert_eq!("255.255.252.0".parse(), Ok(mask));
+		assert_eq!("0xfffffc00".parse(), Ok(mask));
+		assert_eq!("0XFFFFFC00".parse(), Ok(mask));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!("33".parse::<Netmask>(), Err(Error::InvalidPrefixLen));
+		assert_eq!(
+			"0x1fffffc00".parse::<Netmask>(),
+			Err(Error::ParseError("invalid IPv4 netmask syntax"))
+		);
+		assert_eq!(
+			"255.255.252".parse::<Netmask>(),
+			Err(Error::ParseError("invalid IPv4 netmask syntax"))
+		);
+	}
+}
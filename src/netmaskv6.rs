@@ -0,0 +1,115 @@
+use crate::PrefixLen;
+use core::net::Ipv6Addr;
+
+/// An IPv6 netmask.
+///
+/// This is the IPv6 counterpart of [`Netmask`](crate::Netmask): it may or
+/// may not be contiguous, and converts to and from an `Ipv6Addr` like
+/// `ffff:ffff::`, a `u128` like `0xffffffff000000000000000000000000`, a
+/// [`PrefixLen`] like `/32`, and a wildcard (or hostmask) like
+/// `::ffff:ffff:ffff:ffff:ffff:ffff`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netmaskv6;
+/// let mask: Netmaskv6 = "/32".parse().unwrap();
+///
+/// assert_eq!(mask.to_string(), "ffff:ffff::");
+/// assert_eq!(format!("{:#}", mask), "/32");
+/// assert_eq!(format!("{:#x}", mask), "0xffffffff000000000000000000000000");
+/// assert_eq!(mask.wildcard().to_string(), "::ffff:ffff:ffff:ffff:ffff:ffff");
+/// assert_eq!(mask, "ffff:ffff::".parse().unwrap());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Netmaskv6(Ipv6Addr);
+
+impl Netmaskv6 {
+	/// Create a `Netmaskv6` from its dotted-quad form.
+	pub const fn new(mask: Ipv6Addr) -> Self {
+		Self(mask)
+	}
+
+	/// Create a `Netmaskv6` from its wildcard, the inverse of the netmask, as
+	/// written in access lists.
+	pub const fn from_wildcard(wildcard: Ipv6Addr) -> Self {
+		Self(Ipv6Addr::from_bits(!wildcard.to_bits()))
+	}
+
+	/// Get the netmask as an address, like `ffff:ffff::`.
+	pub const fn addr(self) -> Ipv6Addr {
+		self.0
+	}
+
+	/// Get the netmask as a number.
+	pub const fn to_bits(self) -> u128 {
+		self.0.to_bits()
+	}
+
+	/// Get the hostmask, which has a one for every bit that varies between the
+	/// hosts of a network: the inverse of the netmask.
+	pub const fn hostmask(self) -> Ipv6Addr {
+		Ipv6Addr::from_bits(!self.to_bits())
+	}
+
+	/// Get the wildcard mask, as written in access lists.
+	///
+	/// This is the same as the [hostmask](Netmaskv6::hostmask).
+	pub const fn wildcard(self) -> Ipv6Addr {
+		self.hostmask()
+	}
+
+	/// Determine whether the netmask is contiguous, that is, some number of
+	/// ones followed by zeroes.
+	pub const fn is_contiguous(self) -> bool {
+		let bits: u128 = self.to_bits();
+		bits.leading_ones() + bits.trailing_zeros() == 128
+	}
+
+	/// Get the prefix length, if the netmask is contiguous.
+	pub const fn prefix_len(self) -> Option<PrefixLen> {
+		if self.is_contiguous() {
+			PrefixLen::new(self.to_bits().leading_ones() as u8)
+		} else {
+			None
+		}
+	}
+}
+
+mod display;
+mod from;
+mod fromstr;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn is_contiguous() {
+		assert!(Netmaskv6::from(0).is_contiguous());
+		assert!(Netmaskv6::from(u128::MAX).is_contiguous());
+		assert!(Netmaskv6::from(u128::MAX << 64).is_contiguous());
+		assert!(!Netmaskv6::from(u128::MAX >> 1).is_contiguous());
+		assert!(!Netmaskv6::from(1).is_contiguous());
+	}
+
+	#[test]
+	fn prefix_len() {
+		assert_eq!(Netmaskv6::from(0).prefix_len(), PrefixLen::new(0));
+		assert_eq!(Netmaskv6::from(u128::MAX).prefix_len(), PrefixLen::new(128));
+		assert_eq!(
+			Netmaskv6::from(u128::MAX << 64).prefix_len(),
+			PrefixLen::new(64)
+		);
+		assert_eq!(Netmaskv6::from(u128::MAX >> 1).prefix_len(), None);
+	}
+
+	#[test]
+	fn hostmask_and_wildcard() {
+		let mask: Netmaskv6 = Netmaskv6::from(u128::MAX << 96);
+
+		assert_eq!(mask.hostmask(), Ipv6Addr::from(u128::MAX >> 32));
+		assert_eq!(mask.wildcard(), mask.hostmask());
+		assert_eq!(Netmaskv6::from_wildcard(mask.wildcard()), mask);
+	}
+}
@@ -0,0 +1,48 @@
+use super::Netmaskv6;
+use core::fmt;
+
+impl fmt::Display for Netmaskv6 {
+	/// Format the netmask as an address, like `ffff:ffff::`, or with the
+	/// alternate flag (`{:#}`) as a prefix length like `/32` if it is contiguous.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.prefix_len() {
+			Some(len) if f.alternate() => fmt::Display::fmt(&len, f),
+			_ => fmt::Display::fmt(&self.addr(), f),
+		}
+	}
+}
+
+impl fmt::LowerHex for Netmaskv6 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerHex::fmt(&self.to_bits(), f)
+	}
+}
+
+impl fmt::UpperHex for Netmaskv6 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperHex::fmt(&self.to_bits(), f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn contiguous() {
+		let mask: Netmaskv6 = Netmaskv6::from(u128::MAX << 96);
+
+		assert_eq!(format!("{}", mask), "ffff:ffff::");
+		assert_eq!(format!("{:#}", mask), "/32");
+		assert_eq!(format!("{:x}", mask), "ffffffff000000000000000000000000");
+		assert_eq!(format!("{:#X}", mask), "0xFFFFFFFF000000000000000000000000");
+	}
+
+	#[test]
+	fn non_contiguous() {
+		let mask: Netmaskv6 = Netmaskv6::from(0xffff_0000_ffff << 80);
+
+		assert_eq!(format!("{}", mask), "ffff:0:ffff::");
+		assert_eq!(format!("{:#}", mask), "ffff:0:ffff::");
+	}
+}
@@ -0,0 +1,56 @@
+use super::Netmaskv6;
+use crate::PrefixLen;
+use core::net::Ipv6Addr;
+
+impl From<Ipv6Addr> for Netmaskv6 {
+	fn from(mask: Ipv6Addr) -> Self {
+		Self::new(mask)
+	}
+}
+
+impl From<Netmaskv6> for Ipv6Addr {
+	fn from(mask: Netmaskv6) -> Self {
+		mask.addr()
+	}
+}
+
+impl From<u128> for Netmaskv6 {
+	fn from(bits: u128) -> Self {
+		Self::new(Ipv6Addr::from(bits))
+	}
+}
+
+impl From<Netmaskv6> for u128 {
+	fn from(mask: Netmaskv6) -> Self {
+		mask.to_bits()
+	}
+}
+
+impl From<PrefixLen> for Netmaskv6 {
+	/// Convert a prefix length into a contiguous netmask.
+	fn from(len: PrefixLen) -> Self {
+		Self::from(u128::MAX.checked_shl(128 - u32::from(len)).unwrap_or(0))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_prefix_len() {
+		let len = |len: u8| PrefixLen::new(len).unwrap();
+
+		assert_eq!(Netmaskv6::from(len(0)), Netmaskv6::from(0));
+		assert_eq!(Netmaskv6::from(len(64)), Netmaskv6::from(u128::MAX << 64));
+		assert_eq!(Netmaskv6::from(len(128)), Netmaskv6::from(u128::MAX));
+	}
+
+	#[test]
+	fn round_trips() {
+		let mask: Ipv6Addr = "ffff:0:ffff::".parse().unwrap();
+
+		assert_eq!(Ipv6Addr::from(Netmaskv6::from(mask)), mask);
+		assert_eq!(u128::from(Netmaskv6::from(mask)), 0xffff_0000_ffff << 80);
+	}
+}
@@ -0,0 +1,63 @@
+use super::Netmaskv6;
+use crate::parser;
+use crate::{Error, Result};
+use core::str::FromStr;
+
+impl FromStr for Netmaskv6 {
+	type Err = Error;
+
+	/// Parse a `Netmaskv6` from a string
+	///
+	/// This accepts any of the notations for a netmask:
+	/// - A prefix length, with or without a leading `/`: `32` or `/32`
+	/// - An address: `ffff:ffff::`
+	/// - A `0x`-prefixed hexadecimal number of up to 32 digits:
+	///   `0xffffffff000000000000000000000000`
+	///
+	/// A wildcard is indistinguishable from a non-contiguous netmask, so it is
+	/// read as one; use [`Netmaskv6::from_wildcard`] instead.
+	fn from_str(string: &str) -> Result<Self> {
+		let bytes: &[u8] = string.as_bytes();
+
+		if let Some(len) = parser::mask_prefix_len(bytes, 128) {
+			return len.map(Self::from);
+		}
+
+		if let Some(bits) = parser::hex_mask(bytes, 128) {
+			return Ok(Self::from(bits));
+		}
+
+		parser::ipv6addr(bytes)
+			.map(Self::new)
+			.ok_or(Error::ParseError("invalid IPv6 netmask syntax"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn every_notation() {
+		let mask: Netmaskv6 = Netmaskv6::from(u128::MAX << 96);
+
+		assert_eq!("32".parse(), Ok(mask));
+		assert_eq!("/32".parse(), Ok(mask));
+		assert_eq!("ffff:ffff::".parse(), Ok(mask));
+		assert_eq!("0xffffffff000000000000000000000000".parse(), Ok(mask));
+		assert_eq!("0xff".parse(), Ok(Netmaskv6::from(0xff)));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!("129".parse::<Netmaskv6>(), Err(Error::InvalidPrefixLen));
+		assert_eq!(
+			"0x1ffffffff000000000000000000000000".parse::<Netmaskv6>(),
+			Err(Error::ParseError("invalid IPv6 netmask syntax"))
+		);
+		assert_eq!(
+			"255.255.252.0".parse::<Netmaskv6>(),
+			Err(Error::ParseError("invalid IPv6 netmask syntax"))
+		);
+	}
+}
@@ -1,11 +1,19 @@
 use crate::traits::Mask;
-use std::net::Ipv4Addr;
+use crate::{Netmask, PrefixLen, Result, ShuffledAddressIterator};
+use core::convert::TryFrom;
+use core::net::Ipv4Addr;
 
 /// A structure representing an IPv4 network.
 ///
 /// Internally, this structure includes two values; an `Ipv4Addr` representing
 /// the network address (`addr`), and another representing the netmask (`mask`).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+	feature = "diesel",
+	derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Inet))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Cidr))]
 pub struct Netv4Addr {
 	addr: Ipv4Addr,
 	mask: Ipv4Addr,
@@ -22,10 +30,12 @@ impl Netv4Addr {
 
 	#[allow(clippy::trivially_copy_pass_by_ref)]
 	pub fn is_cidr(&self) -> bool {
-		let mask: u32 = self.mask.into();
-		let ones: u32 = mask.count_ones();
-		let cidr_mask: u32 = u32::max_value().checked_shl(32 - ones).unwrap_or(0);
-		mask == cidr_mask
+		self.netmask().is_contiguous()
+	}
+
+	/// Get the netmask as a [`Netmask`].
+	pub const fn netmask(&self) -> Netmask {
+		Netmask::new(self.mask)
 	}
 
 	/// Create a new `Netv4Addr` from the given `addr` and `mask`.
@@ -52,6 +62,86 @@ impl Netv4Addr {
 		Self { addr, mask }
 	}
 
+	/// Create a new `Netv4Addr` from the given `addr` and `mask`, in a `const`
+	/// context.
+	///
+	/// This is the same as [`Netv4Addr::new`], but can be used to initialize `const`
+	/// and `static` items.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// # use std::net::Ipv4Addr;
+	/// const NET: Netv4Addr = Netv4Addr::new_const(
+	///     Ipv4Addr::new(192, 0, 2, 1),
+	///     Ipv4Addr::new(255, 255, 255, 0),
+	/// );
+	///
+	/// assert_eq!(NET, "192.0.2.0/24".parse().unwrap());
+	/// ```
+	pub const fn new_const(addr: Ipv4Addr, mask: Ipv4Addr) -> Self {
+		let addr: Ipv4Addr = Ipv4Addr::from_bits(addr.to_bits() & mask.to_bits());
+		Self { addr, mask }
+	}
+
+	/// Create a new CIDR `Netv4Addr` from the given `addr` and prefix length, in a
+	/// `const` context.
+	///
+	/// Masks the given `addr` with a netmask of `prefix` leading ones.
+	///
+	/// # Panics
+	///
+	/// Panics if `prefix` is greater than 32, which is a compile error when
+	/// evaluated in a `const` context.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// # use std::net::Ipv4Addr;
+	/// const NET: Netv4Addr = Netv4Addr::from_prefix(Ipv4Addr::new(192, 0, 2, 1), 24);
+	///
+	/// assert_eq!(NET, "192.0.2.0/24".parse().unwrap());
+	/// ```
+	pub const fn from_prefix(addr: Ipv4Addr, prefix: u8) -> Self {
+		assert!(prefix <= 32, "prefix length is too long");
+
+		let mask: u32 = match u32::MAX.checked_shl(32 - prefix as u32) {
+			Some(mask) => mask,
+			None => 0,
+		};
+		Self::new_const(addr, Ipv4Addr::from_bits(mask))
+	}
+
+	/// Create a new `Netv4Addr` from the given `addr` and [`Netmask`], masking the
+	/// `addr`.
+	pub const fn with_netmask(addr: Ipv4Addr, mask: Netmask) -> Self {
+		Self::new_const(addr, mask.addr())
+	}
+
+	/// Create a new CIDR `Netv4Addr` from the given `addr` and [`PrefixLen`],
+	/// masking the `addr`.
+	///
+	/// # Errors
+	///
+	/// Fails with [`Error::InvalidPrefixLen`](crate::Error::InvalidPrefixLen)
+	/// if `len` is longer than 32.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Netv4Addr, PrefixLen};
+	/// # use std::net::Ipv4Addr;
+	/// let len: PrefixLen = PrefixLen::new(24).unwrap();
+	/// let net = Netv4Addr::with_prefix_len(Ipv4Addr::new(192, 0, 2, 1), len);
+	///
+	/// assert_eq!(net, Ok("192.0.2.0/24".parse().unwrap()));
+	/// ```
+	pub fn with_prefix_len(addr: Ipv4Addr, len: PrefixLen) -> Result<Self> {
+		Netmask::try_from(len).map(|mask| Self::with_netmask(addr, mask))
+	}
+
 	/// Compute the number of addresses in this network.
 	///
 	/// This is done by raising `2_u32` to the number of zeroes in the netmask,
@@ -90,6 +180,14 @@ impl Netv4Addr {
 		2_u32.checked_pow(u32::from(self.mask).count_zeros())
 	}
 
+	/// Produce an iterator over the _contained addresses_ of this `Netv4Addr` in
+	/// a pseudo-random order determined by `seed`.
+	///
+	/// See [`ShuffledAddressIterator`] for details.
+	pub fn shuffled(&self, seed: u64) -> ShuffledAddressIterator<[Netv4Addr; 1], Netv4Addr> {
+		ShuffledAddressIterator::single(*self, seed)
+	}
+
 	/// Determine if the network is empty.
 	///
 	/// (Plot twist, it isn't.)  Even a /32 has one device in it.
@@ -99,20 +197,41 @@ impl Netv4Addr {
 	}
 }
 
+mod arith;
+mod bitops;
 mod broadcast;
+mod classful;
 mod contains;
 mod display;
 mod from;
 mod fromstr;
 mod hash;
+mod ip_network;
 mod merge;
 mod ord;
 mod partialord;
+mod relation;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "serde")]
 mod de;
+#[cfg(feature = "diesel")]
+mod diesel_pg;
+#[cfg(feature = "postgres-types")]
+mod postgres;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
 mod ser;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 
 #[cfg(test)]
 mod tests {
@@ -188,6 +307,70 @@ mod tests {
 		}
 	}
 
+	mod new_const {
+		use super::*;
+
+		#[test]
+		fn matches_new() {
+			let addr: Ipv4Addr = "192.168.16.32".parse().unwrap();
+			let mask: Ipv4Addr = "255.64.128.3".parse().unwrap();
+
+			assert_eq!(Netv4Addr::new_const(addr, mask), Netv4Addr::new(addr, mask));
+		}
+	}
+
+	mod from_prefix {
+		use super::*;
+
+		#[test]
+		fn masks_addr() {
+			let addr: Ipv4Addr = "192.168.16.32".parse().unwrap();
+
+			assert_eq!(
+				Netv4Addr::from_prefix(addr, 0),
+				pu!("0.0.0.0/0" # Netv4Addr)
+			);
+			assert_eq!(
+				Netv4Addr::from_prefix(addr, 32),
+				pu!("192.168.16.32/32" # Netv4Addr)
+			);
+		}
+
+		#[test]
+		#[should_panic(expected = "prefix length is too long")]
+		fn rejects_long_prefix() {
+			Netv4Addr::from_prefix(Ipv4Addr::UNSPECIFIED, 33);
+		}
+	}
+
+	mod with_prefix_len {
+		use super::*;
+		use crate::Error;
+
+		#[test]
+		fn masks_addr() {
+			let addr: Ipv4Addr = "192.168.16.32".parse().unwrap();
+			let len = |len: u8| PrefixLen::new(len).unwrap();
+
+			assert_eq!(
+				Netv4Addr::with_prefix_len(addr, len(20)),
+				Ok(pu!("192.168.16.0/20" # Netv4Addr))
+			);
+			assert_eq!(
+				Netv4Addr::with_prefix_len(addr, len(33)),
+				Err(Error::InvalidPrefixLen)
+			);
+		}
+
+		#[test]
+		fn netmask_round_trips() {
+			let net: Netv4Addr = pu!("10.0.0.0/255.0.255.0" # Netv4Addr);
+
+			assert_eq!(Netv4Addr::with_netmask(net.addr(), net.netmask()), net);
+			assert_eq!(net.netmask().prefix_len(), None);
+		}
+	}
+
 	mod len {
 		use super::*;
 
@@ -0,0 +1,59 @@
+use super::Netv4Addr;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::net::Ipv4Addr;
+
+/// Build a `Netv4Addr` from unstructured fuzzer input.
+///
+/// Half of the input space yields CIDR networks, since a uniformly random
+/// netmask is almost never CIDR.
+impl<'a> Arbitrary<'a> for Netv4Addr {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let addr: Ipv4Addr = u32::arbitrary(u)?.into();
+
+		let mask: u32 = if bool::arbitrary(u)? {
+			u32::MAX
+				.checked_shl(32 - u.int_in_range(0..=32_u32)?)
+				.unwrap_or(0)
+		} else {
+			u32::arbitrary(u)?
+		};
+
+		Ok(Self::new(addr, mask.into()))
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		<(u32, bool, u32)>::size_hint(depth)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cidr_from_bytes() {
+		let bytes: [u8; 6] = [192, 0, 2, 42, 1, 24];
+		let net: Netv4Addr = Netv4Addr::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+		assert!(net.is_cidr());
+	}
+
+	#[test]
+	fn non_cidr_from_bytes() {
+		let bytes: [u8; 9] = [192, 0, 2, 42, 0, 0xff, 0x00, 0xff, 0x00];
+		let net: Netv4Addr = Netv4Addr::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+		assert!(!net.is_cidr());
+	}
+
+	#[test]
+	fn addr_is_masked() {
+		let bytes: Vec<u8> = (0..=255).collect();
+		let mut u = Unstructured::new(&bytes);
+
+		for _ in 0..32 {
+			let net: Netv4Addr = Netv4Addr::arbitrary(&mut u).unwrap();
+			assert_eq!(u32::from(net.addr()) & !u32::from(net.mask()), 0);
+		}
+	}
+}
@@ -0,0 +1,235 @@
+use super::Netv4Addr;
+use crate::iter::host::deposit;
+use core::net::Ipv4Addr;
+use core::ops::{Add, Sub};
+
+/// Get how far to move an address to go `count` networks along with `mask`, and
+/// whether `count` was too large for the network bits of `mask`.
+fn step(count: u32, mask: u32) -> (u32, bool) {
+	let (deposited, overflowed) = deposit(count.into(), mask.into());
+
+	// Only the bits of `mask` are set, so this fits in a `u32`.
+	#[allow(clippy::cast_possible_truncation)]
+	(deposited as u32, overflowed)
+}
+
+impl Netv4Addr {
+	/// Move forward by `count` networks of the same size, or give `None` if
+	/// that would go past `255.255.255.255`.
+	///
+	/// The network keeps its netmask, and its network bits are counted up as
+	/// though they were one number.  For a CIDR network, this is the same as
+	/// adding `count` times its size to its address; non-contiguous netmasks
+	/// skip over the addresses in their host bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+	/// assert_eq!(net.checked_add(2), Some("192.0.4.0/24".parse().unwrap()));
+	/// assert_eq!(net.checked_add(u32::from(u16::MAX) << 8), None);
+	///
+	/// let net: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+	/// assert_eq!(net.checked_add(257), Some("11.0.1.0/255.0.255.0".parse().unwrap()));
+	/// ```
+	pub fn checked_add(self, count: u32) -> Option<Self> {
+		let mask: u32 = self.mask().into();
+		let (step, overflowed) = step(count, mask);
+
+		if overflowed {
+			return None;
+		}
+
+		(u32::from(self.addr()) | !mask)
+			.checked_add(step)
+			.map(|addr: u32| Self::new(Ipv4Addr::from(addr), self.mask()))
+	}
+
+	/// Move backward by `count` networks of the same size, or give `None` if
+	/// that would go past `0.0.0.0`.
+	///
+	/// See [`checked_add`](Self::checked_add) for how networks are counted.
+	pub fn checked_sub(self, count: u32) -> Option<Self> {
+		let mask: u32 = self.mask().into();
+		let (step, overflowed) = step(count, mask);
+
+		if overflowed {
+			return None;
+		}
+
+		u32::from(self.addr())
+			.checked_sub(step)
+			.map(|addr: u32| Self::new(Ipv4Addr::from(addr), self.mask()))
+	}
+
+	/// Move forward by `count` networks of the same size, wrapping around from
+	/// the last network to the first.
+	pub fn wrapping_add(self, count: u32) -> Self {
+		let mask: u32 = self.mask().into();
+		let (step, _) = step(count, mask);
+		let addr: u32 = (u32::from(self.addr()) | !mask).wrapping_add(step);
+
+		Self::new(Ipv4Addr::from(addr), self.mask())
+	}
+
+	/// Move backward by `count` networks of the same size, wrapping around from
+	/// the first network to the last.
+	pub fn wrapping_sub(self, count: u32) -> Self {
+		let mask: u32 = self.mask().into();
+		let (step, _) = step(count, mask);
+		let addr: u32 = u32::from(self.addr()).wrapping_sub(step);
+
+		Self::new(Ipv4Addr::from(addr), self.mask())
+	}
+
+	/// Get the next network of the same size, if there is one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+	/// assert_eq!(net.checked_next(), Some("192.0.3.0/24".parse().unwrap()));
+	///
+	/// let net: Netv4Addr = "255.255.255.0/24".parse().unwrap();
+	/// assert_eq!(net.checked_next(), None);
+	/// ```
+	pub fn checked_next(self) -> Option<Self> {
+		self.checked_add(1)
+	}
+
+	/// Get the previous network of the same size, if there is one.
+	pub fn checked_prev(self) -> Option<Self> {
+		self.checked_sub(1)
+	}
+}
+
+/// Moves forward by whole networks, like [`Netv4Addr::checked_add`].
+///
+/// # Panics
+///
+/// Panics if the result would go past `255.255.255.255`.
+impl Add<u32> for Netv4Addr {
+	type Output = Self;
+
+	fn add(self, count: u32) -> Self {
+		self
+			.checked_add(count)
+			.expect("attempt to move network past the end of the address space")
+	}
+}
+
+/// Moves backward by whole networks, like [`Netv4Addr::checked_sub`].
+///
+/// # Panics
+///
+/// Panics if the result would go past `0.0.0.0`.
+impl Sub<u32> for Netv4Addr {
+	type Output = Self;
+
+	fn sub(self, count: u32) -> Self {
+		self
+			.checked_sub(count)
+			.expect("attempt to move network past the start of the address space")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use proptest::prelude::*;
+
+	#[test]
+	fn moves_by_network_sizes() {
+		let net: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+
+		assert_eq!(net + 1, "11.0.0.0/8".parse().unwrap());
+		assert_eq!(net - 10, "0.0.0.0/8".parse().unwrap());
+		assert_eq!(net.checked_sub(11), None);
+		assert_eq!(net.checked_add(245), Some("255.0.0.0/8".parse().unwrap()));
+		assert_eq!(net.checked_add(246), None);
+	}
+
+	#[test]
+	fn whole_space() {
+		let net: Netv4Addr = "0.0.0.0/0".parse().unwrap();
+
+		assert_eq!(net.checked_add(0), Some(net));
+		assert_eq!(net.checked_next(), None);
+		assert_eq!(net.checked_prev(), None);
+		assert_eq!(net.wrapping_add(1), net);
+	}
+
+	#[test]
+	fn wraps_around() {
+		let net: Netv4Addr = "255.255.255.0/24".parse().unwrap();
+
+		assert_eq!(net.wrapping_add(1), "0.0.0.0/24".parse().unwrap());
+		assert_eq!(
+			net.wrapping_add(1).wrapping_sub(1),
+			"255.255.255.0/24".parse().unwrap()
+		);
+
+		let net: Netv4Addr = "10.0.255.0/255.0.255.0".parse().unwrap();
+		assert_eq!(net.wrapping_add(1), "11.0.0.0/255.0.255.0".parse().unwrap());
+	}
+
+	#[test]
+	fn non_contiguous_skips_host_bits() {
+		let net: Netv4Addr = "10.0.255.0/255.0.255.0".parse().unwrap();
+
+		assert_eq!(
+			net.checked_next(),
+			Some("11.0.0.0/255.0.255.0".parse().unwrap())
+		);
+		assert_eq!(
+			"11.0.0.0/255.0.255.0"
+				.parse::<Netv4Addr>()
+				.unwrap()
+				.checked_prev(),
+			Some(net)
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "attempt to move network past the end of the address space")]
+	fn add_panics_on_overflow() {
+		let _ = "255.0.0.0/8".parse::<Netv4Addr>().unwrap() + 1;
+	}
+
+	#[test]
+	#[should_panic(expected = "attempt to move network past the start of the address space")]
+	fn sub_panics_on_overflow() {
+		let _ = "0.0.0.0/8".parse::<Netv4Addr>().unwrap() - 1;
+	}
+
+	proptest! {
+		#[test]
+		fn next_and_prev_are_inverses(net in strategy::netv4addr()) {
+			if let Some(next) = net.checked_next() {
+				prop_assert_eq!(next.checked_prev(), Some(net));
+				prop_assert!(next > net);
+				prop_assert_eq!(next.mask(), net.mask());
+			}
+		}
+
+		#[test]
+		fn steps_to_disjoint_networks(net in strategy::netv4addr(), count in 1_u32..1024) {
+			if let Some(moved) = net.checked_add(count) {
+				prop_assert!(moved.is_disjoint(&net));
+				prop_assert_eq!(moved.checked_sub(count), Some(net));
+			}
+		}
+
+		#[test]
+		fn wrapping_agrees_with_checked(net in strategy::netv4addr(), count: u32) {
+			if let Some(moved) = net.checked_add(count) {
+				prop_assert_eq!(net.wrapping_add(count), moved);
+			}
+
+			prop_assert_eq!(net.wrapping_add(count).wrapping_sub(count), net);
+		}
+	}
+}
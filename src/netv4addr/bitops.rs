@@ -0,0 +1,93 @@
+use super::Netv4Addr;
+use crate::traits::Mask;
+use core::net::Ipv4Addr;
+use core::ops::{BitAnd, BitOr, Not};
+
+/// Masks the address with the network's netmask, giving its network part.
+///
+/// This is the same as [`Mask::mask`]ing the address with the netmask.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv4Addr;
+/// # use std::net::Ipv4Addr;
+/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+/// assert_eq!(net & Ipv4Addr::new(198, 51, 100, 7), Ipv4Addr::new(198, 51, 100, 0));
+/// ```
+impl BitAnd<Ipv4Addr> for Netv4Addr {
+	type Output = Ipv4Addr;
+
+	fn bitand(self, addr: Ipv4Addr) -> Ipv4Addr {
+		addr.mask(&self.mask())
+	}
+}
+
+/// Combines the network's address with the host bits of the address, giving
+/// the address of that host within the network.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv4Addr;
+/// # use std::net::Ipv4Addr;
+/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+/// assert_eq!(net | Ipv4Addr::from(5), Ipv4Addr::new(192, 0, 2, 5));
+/// assert_eq!(net | Ipv4Addr::new(198, 51, 100, 7), Ipv4Addr::new(192, 0, 2, 7));
+/// ```
+impl BitOr<Ipv4Addr> for Netv4Addr {
+	type Output = Ipv4Addr;
+
+	fn bitor(self, addr: Ipv4Addr) -> Ipv4Addr {
+		self.addr() | addr.mask(&!self.mask())
+	}
+}
+
+/// Gives the hostmask of the network, which is the inverse of its netmask.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv4Addr;
+/// # use std::net::Ipv4Addr;
+/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+/// assert_eq!(!net, Ipv4Addr::new(0, 0, 0, 255));
+/// ```
+impl Not for Netv4Addr {
+	type Output = Ipv4Addr;
+
+	fn not(self) -> Ipv4Addr {
+		!self.mask()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{strategy, Contains};
+	use proptest::prelude::*;
+
+	#[test]
+	fn non_contiguous() {
+		let net: Netv4Addr = "10.0.1.0/255.0.255.0".parse().unwrap();
+		let addr: Ipv4Addr = "192.168.0.42".parse().unwrap();
+
+		assert_eq!(net & addr, Ipv4Addr::new(192, 0, 0, 0));
+		assert_eq!(net | addr, Ipv4Addr::new(10, 168, 1, 42));
+		assert_eq!(!net, Ipv4Addr::new(0, 255, 0, 255));
+	}
+
+	proptest! {
+		#[test]
+		fn host_is_contained(net in strategy::netv4addr(), addr: Ipv4Addr) {
+			prop_assert!(net.contains(&(net | addr)));
+			prop_assert_eq!((net | addr) & !net, addr & !net);
+		}
+
+		#[test]
+		fn network_part_is_masked(net in strategy::netv4addr(), addr: Ipv4Addr) {
+			prop_assert_eq!(net & addr, addr & net.mask());
+			prop_assert_eq!(net & addr & !net, Ipv4Addr::UNSPECIFIED);
+		}
+	}
+}
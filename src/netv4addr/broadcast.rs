@@ -1,6 +1,6 @@
 use super::Netv4Addr;
 use crate::traits::Broadcast;
-use std::net::Ipv4Addr;
+use core::net::Ipv4Addr;
 
 impl Broadcast for Netv4Addr {
 	type Output = Ipv4Addr;
@@ -0,0 +1,146 @@
+use super::Netv4Addr;
+use crate::{AddrClass, Error, Netmask, Result};
+use core::net::Ipv4Addr;
+
+impl Netv4Addr {
+	/// Parse a `Netv4Addr` written in the abbreviated or classful notation of
+	/// older configuration and registry data.
+	///
+	/// This is an opt-in alternative to the [`FromStr`](core::str::FromStr)
+	/// implementation which additionally accepts:
+	/// - Truncated dotted quads, whose missing octets are zero: `10/8` or
+	///   `172.16/12`
+	/// - Networks without a netmask, which take the netmask of their
+	///   [class](AddrClass), widened to cover every nonzero octet: `10` is
+	///   `10.0.0.0/8`, `192.168.1` is `192.168.1.0/24`, and `10.1` is
+	///   `10.1.0.0/16`
+	///
+	/// The netmask, if given, may be in any notation accepted by [`Netmask`].
+	/// Unlike `FromStr`, this rejects a netmask it cannot read.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// let net = |s: &str| Netv4Addr::parse_classful(s).unwrap().to_string();
+	///
+	/// assert_eq!(net("10/8"), "10.0.0.0/8");
+	/// assert_eq!(net("172.16/12"), "172.16.0.0/12");
+	/// assert_eq!(net("192.168.1"), "192.168.1.0/24");
+	/// assert_eq!(net("10.1.0.0/255.255.0.0"), "10.1.0.0/16");
+	/// ```
+	pub fn parse_classful(string: &str) -> Result<Self> {
+		let (addr, mask) = match string.find(['/', ' ']) {
+			Some(at) => (&string[..at], Some(&string[at + 1..])),
+			None => (string, None),
+		};
+
+		let addr: Ipv4Addr = truncated_ipv4addr(addr)?;
+
+		let mask: Netmask = match mask {
+			Some(mask) => mask.parse()?,
+			None => {
+				let class: u32 = AddrClass::of(addr).prefix_len().map_or(0, u32::from);
+				// Whole octets, up to and including the last nonzero one.
+				let written: u32 = (32 - u32::from(addr).trailing_zeros()).div_ceil(8) * 8;
+
+				Netmask::from(u32::MAX.checked_shl(32 - class.max(written)).unwrap_or(0))
+			}
+		};
+
+		Ok(Self::with_netmask(addr, mask))
+	}
+
+	/// Get the legacy class of the network's address.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{AddrClass, Netv4Addr};
+	/// let net: Netv4Addr = "172.16.0.0/12".parse().unwrap();
+	/// assert_eq!(net.class(), AddrClass::B);
+	/// ```
+	pub const fn class(&self) -> AddrClass {
+		AddrClass::of(self.addr)
+	}
+}
+
+/// Parse one to four dotted decimal octets, filling in any missing octets on
+/// the right with zeroes.
+fn truncated_ipv4addr(string: &str) -> Result<Ipv4Addr> {
+	let invalid = Error::ParseError("invalid abbreviated IPv4 address syntax");
+	let mut octets: [u8; 4] = [0; 4];
+	for (index, part) in string.split('.').enumerate() {
+		let octet: &mut u8 = octets.get_mut(index).ok_or_else(|| invalid.clone())?;
+		let is_decimal: bool = !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+
+		if !is_decimal || (part.len() > 1 && part.starts_with('0')) {
+			return Err(invalid);
+		}
+
+		*octet = part.parse().map_err(|_| invalid.clone())?;
+	}
+
+	Ok(Ipv4Addr::from(octets))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(string: &str) -> Result<Netv4Addr> {
+		Netv4Addr::parse_classful(string)
+	}
+
+	#[test]
+	fn truncated_with_prefix() {
+		assert_eq!(parse("10/8"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+		assert_eq!(parse("172.16/12"), Ok(pu!("172.16.0.0/12" # Netv4Addr)));
+		assert_eq!(parse("192.168.1/24"), Ok(pu!("192.168.1.0/24" # Netv4Addr)));
+		assert_eq!(parse("0/0"), Ok(pu!("0.0.0.0/0" # Netv4Addr)));
+		assert_eq!(parse("10 255.0.0.0"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+	}
+
+	#[test]
+	fn full_forms() {
+		assert_eq!(
+			parse("10.1.0.0/255.255.0.0"),
+			Ok(pu!("10.1.0.0/16" # Netv4Addr))
+		);
+		assert_eq!(parse("192.0.2.1/32"), Ok(pu!("192.0.2.1/32" # Netv4Addr)));
+	}
+
+	#[test]
+	fn classful_implied() {
+		assert_eq!(parse("10"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+		assert_eq!(parse("10.0.0.0"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+		assert_eq!(parse("10.1"), Ok(pu!("10.1.0.0/16" # Netv4Addr)));
+		assert_eq!(parse("172.16"), Ok(pu!("172.16.0.0/16" # Netv4Addr)));
+		assert_eq!(parse("192.168.1"), Ok(pu!("192.168.1.0/24" # Netv4Addr)));
+		assert_eq!(parse("192.168"), Ok(pu!("192.168.0.0/24" # Netv4Addr)));
+		assert_eq!(parse("192.0.2.1"), Ok(pu!("192.0.2.1/32" # Netv4Addr)));
+		assert_eq!(parse("224"), Ok(pu!("224.0.0.0/8" # Netv4Addr)));
+		assert_eq!(parse("224.0.0.5"), Ok(pu!("224.0.0.5/32" # Netv4Addr)));
+	}
+
+	#[test]
+	fn rejects_invalid() {
+		let invalid = Err(Error::ParseError("invalid abbreviated IPv4 address syntax"));
+
+		assert_eq!(parse(""), invalid);
+		assert_eq!(parse("10./8"), invalid);
+		assert_eq!(parse("1.2.3.4.5/8"), invalid);
+		assert_eq!(parse("256/8"), invalid);
+		assert_eq!(parse("010/8"), invalid);
+		assert_eq!(parse("+10/8"), invalid);
+		assert_eq!(parse("10/33"), Err(Error::InvalidPrefixLen));
+		assert!(parse("10/zoop").is_err());
+	}
+
+	#[test]
+	fn class() {
+		assert_eq!(pu!("10.0.0.0/8" # Netv4Addr).class(), AddrClass::A);
+		assert_eq!(pu!("192.0.2.0/24" # Netv4Addr).class(), AddrClass::C);
+		assert_eq!(pu!("240.0.0.0/4" # Netv4Addr).class(), AddrClass::E);
+	}
+}
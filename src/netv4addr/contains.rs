@@ -2,17 +2,17 @@ use super::Netv4Addr;
 use crate::traits::Contains;
 use crate::traits::Mask;
 
-impl Contains<std::net::IpAddr> for Netv4Addr {
-	fn contains(&self, other: &std::net::IpAddr) -> bool {
+impl Contains<core::net::IpAddr> for Netv4Addr {
+	fn contains(&self, other: &core::net::IpAddr) -> bool {
 		match other {
-			std::net::IpAddr::V4(other) => self.contains(other),
+			core::net::IpAddr::V4(other) => self.contains(other),
 			_ => false,
 		}
 	}
 }
 
-impl Contains<std::net::Ipv4Addr> for Netv4Addr {
-	fn contains(&self, other: &std::net::Ipv4Addr) -> bool {
+impl Contains<core::net::Ipv4Addr> for Netv4Addr {
+	fn contains(&self, other: &core::net::Ipv4Addr) -> bool {
 		other.mask(&self.mask()) == self.addr()
 	}
 }
@@ -28,7 +28,7 @@ impl Contains<crate::NetAddr> for Netv4Addr {
 
 impl Contains<Netv4Addr> for Netv4Addr {
 	fn contains(&self, other: &Netv4Addr) -> bool {
-		other.addr().mask(&self.mask()) == self.addr()
+		other.addr().mask(&self.mask()) == self.addr() && other.mask().mask(&self.mask()) == self.mask()
 	}
 }
 
@@ -36,7 +36,7 @@ impl Contains<Netv4Addr> for Netv4Addr {
 mod tests {
 	mod cidr {
 		use crate::{Contains, Netv4Addr};
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		#[test]
 		fn ip() {
@@ -52,11 +52,18 @@ mod tests {
 			let net_inner: Netv4Addr = "127.128.0.1/24".parse().unwrap();
 			assert!(net.contains(&net_inner));
 		}
+
+		#[test]
+		fn supernet() {
+			let net: Netv4Addr = "128.0.0.0/2".parse().unwrap();
+			let net_outer: Netv4Addr = "128.0.0.0/1".parse().unwrap();
+			assert!(!net.contains(&net_outer));
+		}
 	}
 
 	mod non_cidr {
 		use crate::{Contains, Netv4Addr};
-		use std::net::Ipv4Addr;
+		use core::net::Ipv4Addr;
 
 		#[test]
 		fn ip() {
@@ -67,4 +74,38 @@ mod tests {
 			assert!(!net.contains(&Ipv4Addr::new(255, 127, 127, 0)));
 		}
 	}
+
+	mod properties {
+		use crate::strategy::{cidr_netv4addr, contained_ipv4addr, contained_netv4addr, netv4addr};
+		use crate::Contains;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn contains_itself(net in netv4addr()) {
+				prop_assert!(net.contains(&net));
+				prop_assert!(net.contains(&net.addr()));
+			}
+
+			#[test]
+			fn contains_contained_addresses(
+				(net, addr) in netv4addr().prop_flat_map(|net| (Just(net), contained_ipv4addr(net)))
+			) {
+				prop_assert!(net.contains(&addr));
+			}
+
+			#[test]
+			fn contains_contained_networks(
+				(net, inner) in netv4addr().prop_flat_map(|net| (Just(net), contained_netv4addr(net)))
+			) {
+				prop_assert!(net.contains(&inner));
+				prop_assert!(net.contains(&inner.addr()));
+			}
+
+			#[test]
+			fn cidr_mutual_containment_is_equality(a in cidr_netv4addr(), b in cidr_netv4addr()) {
+				prop_assert_eq!(a.contains(&b) && b.contains(&a), a == b);
+			}
+		}
+	}
 }
@@ -1,6 +1,25 @@
 use serde::{de, Deserialize, Deserializer};
 
 use super::Netv4Addr;
+use crate::serde::Rhs;
+use core::net::Ipv4Addr;
+
+/// Build a network from its address and, if given, its prefix length or mask.
+#[cfg(feature = "serde")]
+fn from_parts<E: de::Error>(addr: Ipv4Addr, rhs: Option<Rhs<Ipv4Addr>>) -> Result<Netv4Addr, E> {
+	match rhs {
+		Some(Rhs::Prefix(prefix)) if prefix > 32 => Err(de::Error::invalid_value(
+			de::Unexpected::Unsigned(prefix.into()),
+			&"a prefix length of at most 32",
+		)),
+		Some(Rhs::Prefix(prefix)) => {
+			let mask: u32 = u32::MAX ^ u32::MAX.checked_shr(prefix).unwrap_or(0_u32);
+			Ok(Netv4Addr::new(addr, mask.into()))
+		}
+		Some(Rhs::Mask(mask)) => Ok(Netv4Addr::new(addr, mask)),
+		None => Ok(Netv4Addr::from(addr)),
+	}
+}
 
 #[cfg(feature = "serde")]
 struct Netv4AddrVisitor;
@@ -16,25 +35,180 @@ impl<'de> de::Visitor<'de> for Netv4AddrVisitor {
 	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
 		use core::str::FromStr;
 		Self::Value::from_str(value)
+			.or_else(|_| Ipv4Addr::from_str(value).map(Self::Value::from))
 			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
 	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		use core::convert::TryFrom;
+		u32::try_from(value)
+			.map(|addr| Self::Value::from(Ipv4Addr::from(addr)))
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(value), &self))
+	}
+
+	fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_map(map)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_seq(seq, &self)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		Self::Value::parse_ascii(value)
+			.ok()
+			.or_else(|| crate::parser::ipv4addr(value).map(Self::Value::from))
+			.ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(value), &self))
+	}
+
+	fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+		use de::VariantAccess;
+
+		match data.variant()? {
+			(Form::Cidr, variant) => variant.tuple_variant(2, CidrVisitor),
+			(Form::Extended, variant) => variant.tuple_variant(2, ExtendedVisitor),
+			(Form::Network(net), variant) => variant.unit_variant().map(|()| net),
+		}
+	}
+}
+
+/// The variants of the compact representation.
+#[cfg(feature = "serde")]
+enum Form {
+	Cidr,
+	Extended,
+	/// A network in string form, as written by versions before the compact
+	/// representation, which is accepted in place of a variant.
+	Network(Netv4Addr),
+}
+
+#[cfg(feature = "serde")]
+const FORMS: &[&str] = &["Cidr", "Extended"];
+
+#[cfg(feature = "serde")]
+struct FormVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for FormVisitor {
+	type Value = Form;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("`Cidr`, `Extended`, or a network")
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		match value {
+			0 => Ok(Form::Cidr),
+			1 => Ok(Form::Extended),
+			_ => Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(value),
+				&"variant index 0 <= i < 2",
+			)),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match value {
+			"Cidr" => Ok(Form::Cidr),
+			"Extended" => Ok(Form::Extended),
+			_ => de::Visitor::visit_str(Netv4AddrVisitor, value).map(Form::Network),
+		}
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		match value {
+			b"Cidr" => Ok(Form::Cidr),
+			b"Extended" => Ok(Form::Extended),
+			_ => de::Visitor::visit_bytes(Netv4AddrVisitor, value).map(Form::Network),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Form {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(FormVisitor)
+	}
+}
+
+/// Visits the address octets and prefix length of the `Cidr` variant.
+#[cfg(feature = "serde")]
+struct CidrVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for CidrVisitor {
+	type Value = Netv4Addr;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("an address and prefix length")
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let addr: [u8; 4] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let prefix: u8 = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+		if prefix > 32 {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(prefix.into()),
+				&"a prefix length of at most 32",
+			));
+		}
+
+		let mask: u32 = u32::MAX ^ u32::MAX.checked_shr(prefix.into()).unwrap_or(0_u32);
+
+		Ok(Netv4Addr::new(addr.into(), mask.into()))
+	}
+}
+
+/// Visits the address and mask octets of the `Extended` variant.
+#[cfg(feature = "serde")]
+struct ExtendedVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for ExtendedVisitor {
+	type Value = Netv4Addr;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("an address and mask")
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let addr: [u8; 4] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let mask: [u8; 4] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+		Ok(Netv4Addr::new(addr.into(), mask.into()))
+	}
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Netv4Addr {
 	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		deserializer.deserialize_str(Netv4AddrVisitor)
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(Netv4AddrVisitor)
+		} else {
+			deserializer.deserialize_enum("Netv4Addr", FORMS, Netv4AddrVisitor)
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::Netv4Addr;
-	use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+	use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Readable, Token};
 
 	#[test]
 	fn malformed_produces_correct_error() {
-		assert_de_tokens_error::<Netv4Addr>(
+		assert_de_tokens_error::<Readable<Netv4Addr>>(
 			&[Token::Str("asdf")],
 			"invalid value: string \"asdf\", expected a valid cidr/extended network address",
 		)
@@ -44,13 +218,274 @@ mod tests {
 	fn test_de_cidr_localhost() {
 		let netaddr: Netv4Addr = "127.0.0.1/8".parse().unwrap();
 
-		assert_de_tokens(&netaddr, &[Token::Str("127.0.0.0/8")]);
+		assert_de_tokens(&netaddr.readable(), &[Token::Str("127.0.0.0/8")]);
 	}
 
 	#[test]
 	fn test_de_non_cidr_localhost() {
 		let netaddr: Netv4Addr = "127.0.0.1/251.255.255.7".parse().unwrap();
 
-		assert_de_tokens(&netaddr, &[Token::Str("123.0.0.1/251.255.255.7")]);
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[Token::Str("123.0.0.1/251.255.255.7")],
+		);
+	}
+
+	#[test]
+	fn test_de_bare_address() {
+		let netaddr: Netv4Addr = "192.0.2.1/32".parse().unwrap();
+
+		assert_de_tokens(&netaddr.readable(), &[Token::Str("192.0.2.1")]);
+		assert_de_tokens(&netaddr.readable(), &[Token::U32(0xc000_0201)]);
+	}
+
+	#[test]
+	fn test_de_map_prefix() {
+		let netaddr: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("10.1.2.3"),
+				Token::Str("prefix"),
+				Token::U8(8),
+				Token::MapEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_map_mask() {
+		let netaddr: Netv4Addr = "10.0.2.0/255.0.255.0".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("addr"),
+				Token::Str("10.1.2.3"),
+				Token::Str("mask"),
+				Token::Str("255.0.255.0"),
+				Token::MapEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_map_missing_address() {
+		assert_de_tokens_error::<Readable<Netv4Addr>>(
+			&[
+				Token::Map { len: Some(1) },
+				Token::Str("prefix"),
+				Token::U8(8),
+				Token::MapEnd,
+			],
+			"missing field `address`",
+		);
+	}
+
+	#[test]
+	fn test_de_map_prefix_and_mask() {
+		assert_de_tokens_error::<Readable<Netv4Addr>>(
+			&[
+				Token::Map { len: Some(3) },
+				Token::Str("address"),
+				Token::Str("10.0.0.0"),
+				Token::Str("prefix"),
+				Token::U8(8),
+				Token::Str("mask"),
+			],
+			"only one of `prefix` and `mask` may be given",
+		);
+	}
+
+	#[test]
+	fn test_de_map_prefix_too_long() {
+		assert_de_tokens_error::<Readable<Netv4Addr>>(
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("10.0.0.0"),
+				Token::Str("prefix"),
+				Token::U8(33),
+				Token::MapEnd,
+			],
+			"invalid value: integer `33`, expected a prefix length of at most 32",
+		);
+	}
+
+	#[test]
+	fn test_de_map_prefix_as_in_seq() {
+		let netaddr: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+
+		for prefix in &[Token::Str("8"), Token::I64(8), Token::U64(8)] {
+			assert_de_tokens(
+				&netaddr.readable(),
+				&[
+					Token::Map { len: Some(2) },
+					Token::Str("address"),
+					Token::Str("10.1.2.3"),
+					Token::Str("prefix"),
+					*prefix,
+					Token::MapEnd,
+				],
+			);
+		}
+
+		assert_de_tokens_error::<Readable<Netv4Addr>>(
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("10.1.2.3"),
+				Token::Str("prefix"),
+				Token::Str("255.0.0.0"),
+				Token::MapEnd,
+			],
+			"invalid value: a mask, expected a prefix length",
+		);
+	}
+
+	#[test]
+	fn test_de_seq() {
+		let netaddr: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Seq { len: Some(2) },
+				Token::Str("10.0.0.0"),
+				Token::U8(8),
+				Token::SeqEnd,
+			],
+		);
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Seq { len: Some(2) },
+				Token::Str("10.0.0.0"),
+				Token::Str("255.0.0.0"),
+				Token::SeqEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_json_forms() {
+		let netaddr: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+
+		for json in &[
+			r#""10.0.0.0/8""#,
+			r#"{ "address": "10.0.0.0", "prefix": 8 }"#,
+			r#"{ "addr": "10.0.0.0", "mask": "255.0.0.0" }"#,
+			r#"["10.0.0.0", 8]"#,
+			r#"["10.0.0.0", "8"]"#,
+		] {
+			assert_eq!(serde_json::from_str::<Netv4Addr>(json).unwrap(), netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string() {
+		let netaddr: Netv4Addr = "127.0.0.1/8".parse().unwrap();
+
+		assert_de_tokens(&netaddr.compact(), &[Token::Str("127.0.0.0/8")]);
+		assert_de_tokens(&netaddr.compact(), &[Token::Bytes(b"127.0.0.0/8")]);
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string_from_msgpack() {
+		for string in &["10.0.0.0/8", "192.0.2.0/255.0.255.0"] {
+			let netaddr: Netv4Addr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(string).unwrap();
+
+			assert_eq!(
+				rmp_serde::from_slice::<Netv4Addr>(&encoded).unwrap(),
+				netaddr
+			);
+		}
+
+		let encoded: Vec<u8> = rmp_serde::to_vec("not a network").unwrap();
+		assert!(rmp_serde::from_slice::<Netv4Addr>(&encoded).is_err());
+	}
+
+	#[test]
+	fn test_de_compact_round_trips_through_msgpack() {
+		for string in &["10.0.0.0/8", "192.0.2.0/255.0.255.0"] {
+			let netaddr: Netv4Addr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(&netaddr).unwrap();
+
+			assert_eq!(
+				rmp_serde::from_slice::<Netv4Addr>(&encoded).unwrap(),
+				netaddr
+			);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_masks_address() {
+		let netaddr: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv4Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 4 },
+				Token::U8(192),
+				Token::U8(0),
+				Token::U8(2),
+				Token::U8(255),
+				Token::TupleEnd,
+				Token::U8(24),
+				Token::TupleVariantEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_compact_prefix_too_long() {
+		assert_de_tokens_error::<Compact<Netv4Addr>>(
+			&[
+				Token::TupleVariant {
+					name: "Netv4Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 4 },
+				Token::U8(192),
+				Token::U8(0),
+				Token::U8(2),
+				Token::U8(0),
+				Token::TupleEnd,
+				Token::U8(33),
+				Token::TupleVariantEnd,
+			],
+			"invalid value: integer `33`, expected a prefix length of at most 32",
+		);
+	}
+
+	#[test]
+	fn test_de_bincode_round_trip() {
+		for net in &["0.0.0.0/0", "192.0.2.0/24", "127.0.0.1/251.255.255.7"] {
+			let netaddr: Netv4Addr = net.parse().unwrap();
+			let bytes = bincode::serialize(&netaddr).unwrap();
+
+			assert_eq!(bincode::deserialize::<Netv4Addr>(&bytes).unwrap(), netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_bincode_layout() {
+		let netaddr: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+
+		assert_eq!(
+			bincode::serialize(&netaddr).unwrap(),
+			[0, 0, 0, 0, 192, 0, 2, 0, 24]
+		);
 	}
 }
@@ -0,0 +1,114 @@
+use super::Netv4Addr;
+use crate::inet;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{Cidr, Inet};
+use std::io::Write;
+
+impl ToSql<Inet, Pg> for Netv4Addr {
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+		out.write_all(&inet::encode(&(*self).into(), false)?)?;
+		Ok(IsNull::No)
+	}
+}
+
+impl ToSql<Cidr, Pg> for Netv4Addr {
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+		out.write_all(&inet::encode(&(*self).into(), true)?)?;
+		Ok(IsNull::No)
+	}
+}
+
+impl FromSql<Inet, Pg> for Netv4Addr {
+	fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+		Ok(inet::v4(inet::decode(value.as_bytes())?)?)
+	}
+}
+
+impl FromSql<Cidr, Pg> for Netv4Addr {
+	fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+		Ok(inet::v4(inet::decode(value.as_bytes())?)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use diesel::pg::{PgMetadataLookup, PgTypeMetadata};
+	use diesel::query_builder::bind_collector::RawBytesBindCollector;
+	use diesel::query_builder::BindCollector;
+	use std::num::NonZeroU32;
+
+	/// A metadata lookup for built-in types, which never need to be looked up.
+	struct BuiltinLookup;
+
+	impl PgMetadataLookup for BuiltinLookup {
+		fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+			unreachable!("inet and cidr are built-in types")
+		}
+	}
+
+	fn to_sql<T>(net: &Netv4Addr) -> Vec<u8>
+	where
+		Pg: diesel::sql_types::HasSqlType<T>,
+		Netv4Addr: ToSql<T, Pg>,
+	{
+		let mut collector = RawBytesBindCollector::<Pg>::new();
+		collector
+			.push_bound_value::<T, Netv4Addr>(net, &mut BuiltinLookup)
+			.unwrap();
+		collector.binds.remove(0).unwrap()
+	}
+
+	fn from_sql<T>(bytes: &[u8]) -> deserialize::Result<Netv4Addr>
+	where
+		Netv4Addr: FromSql<T, Pg>,
+	{
+		let oid = NonZeroU32::new(869).unwrap();
+		<Netv4Addr as FromSql<T, Pg>>::from_sql(PgValue::new(bytes, &oid))
+	}
+
+	#[test]
+	fn to_sql_inet() {
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert_eq!(
+			to_sql::<Inet>(&net),
+			[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x00]
+		);
+	}
+
+	#[test]
+	fn to_sql_cidr() {
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert_eq!(
+			to_sql::<Cidr>(&net),
+			[0x02, 0x18, 0x01, 0x04, 0xc0, 0xa8, 0x01, 0x00]
+		);
+	}
+
+	#[test]
+	fn from_sql_inet_and_cidr() {
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert_eq!(
+			from_sql::<Inet>(&[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x00]).unwrap(),
+			net
+		);
+		assert_eq!(
+			from_sql::<Cidr>(&[0x02, 0x18, 0x01, 0x04, 0xc0, 0xa8, 0x01, 0x00]).unwrap(),
+			net
+		);
+	}
+
+	#[test]
+	fn from_sql_wrong_family_fails() {
+		assert!(from_sql::<Inet>(&[
+			0x03, 0x20, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+			0x00, 0x00, 0x00, 0x00, 0x00,
+		])
+		.is_err());
+	}
+}
@@ -1,17 +1,50 @@
 use super::Netv4Addr;
+use crate::{MaskNotation, NetFormat, Notation};
 use core::fmt;
 
+impl Netv4Addr {
+	/// Start writing this network in a notation other than the usual one.
+	///
+	/// See [`NetFormat`] for the notations available.
+	pub fn format(&self) -> NetFormat {
+		NetFormat::new(*self)
+	}
+}
+
 impl fmt::Display for Netv4Addr {
+	/// Write the network in the usual notation, with a prefix length where the
+	/// netmask is contiguous, or with the alternate flag (`{:#}`) as the address
+	/// and netmask separated by a space.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let mask: u32 = self.mask().into();
-		let ones = mask.count_ones();
-		let cidr_mask: u32 = u32::max_value().checked_shl(32 - ones).unwrap_or(0);
-
-		if mask == cidr_mask {
-			write!(f, "{}/{}", self.addr(), ones)
-		} else {
-			write!(f, "{}/{}", self.addr(), self.mask())
+		if f.alternate() {
+			return fmt::Display::fmt(&self.format().mask(MaskNotation::Netmask), f);
 		}
+
+		match self.netmask().prefix_len() {
+			Some(len) => write!(f, "{}/{}", self.addr(), len),
+			None => write!(f, "{}/{}", self.addr(), self.mask()),
+		}
+	}
+}
+
+impl fmt::LowerHex for Netv4Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::LowerHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::UpperHex for Netv4Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::UpperHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::Binary for Netv4Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::Binary);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
 	}
 }
 
@@ -41,4 +74,24 @@ mod tests {
 		let addr: Netv4Addr = "127.0.0.1/251.255.255.0".parse().unwrap();
 		assert_eq!(format!("{}", addr), "123.0.0.0/251.255.255.0")
 	}
+
+	#[test]
+	fn alternate_has_netmask() {
+		let addr: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+		assert_eq!(format!("{:#}", addr), "192.0.2.0 255.255.255.0");
+
+		let addr: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+		assert_eq!(format!("{:#}", addr), "10.0.0.0 255.0.255.0");
+	}
+
+	#[test]
+	fn radixes() {
+		let addr: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+		assert_eq!(format!("{:x}", addr), "c0000200/24");
+		assert_eq!(format!("{:#X}", addr), "0xC0000200/24");
+		assert_eq!(format!("{:b}", addr), "110000000000000000000010/00000000");
+
+		let addr: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+		assert_eq!(format!("{:#x}", addr), "0x0a000000/0xff00ff00");
+	}
 }
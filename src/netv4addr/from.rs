@@ -1,2 +1,9 @@
 use super::Netv4Addr;
 mod ipv4addr;
+
+#[cfg(feature = "cidr")]
+mod cidr;
+#[cfg(feature = "ipnet")]
+mod ipnet;
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork;
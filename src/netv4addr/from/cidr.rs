@@ -0,0 +1,56 @@
+use super::Netv4Addr;
+use crate::Error;
+use cidr::Ipv4Cidr;
+use core::convert::TryFrom;
+
+/// Converts the network, keeping its address and mask.
+impl From<Ipv4Cidr> for Netv4Addr {
+	fn from(net: Ipv4Cidr) -> Self {
+		Self::new(net.first_address(), net.mask())
+	}
+}
+
+/// Converts the network, failing with [`Error::NonContiguousMask`] if its
+/// mask is not contiguous.
+impl TryFrom<Netv4Addr> for Ipv4Cidr {
+	type Error = Error;
+
+	fn try_from(net: Netv4Addr) -> Result<Self, Self::Error> {
+		if !net.is_cidr() {
+			return Err(Error::NonContiguousMask);
+		}
+
+		let prefix = u32::from(net.mask()).count_ones() as u8;
+
+		Ok(Self::new(net.addr(), prefix).expect("network has no host bits set"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_cidr() {
+		let net: Ipv4Cidr = "192.0.2.0/24".parse().unwrap();
+
+		assert_eq!(
+			Netv4Addr::from(net),
+			"192.0.2.0/24".parse::<Netv4Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn try_from_cidr() {
+		let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+
+		assert_eq!(Ipv4Cidr::try_from(net), Ok("192.0.2.0/24".parse().unwrap()));
+	}
+
+	#[test]
+	fn try_from_non_cidr_fails() {
+		let net: Netv4Addr = "192.0.2.0/255.255.0.255".parse().unwrap();
+
+		assert_eq!(Ipv4Cidr::try_from(net), Err(Error::NonContiguousMask));
+	}
+}
@@ -0,0 +1,70 @@
+use super::Netv4Addr;
+use crate::Error;
+use core::convert::TryFrom;
+use ipnetwork::Ipv4Network;
+
+/// Converts the network, discarding any bits of its address outside of its
+/// prefix.
+impl From<Ipv4Network> for Netv4Addr {
+	fn from(net: Ipv4Network) -> Self {
+		Self::new(net.ip(), net.mask())
+	}
+}
+
+/// Converts the network, failing with [`Error::NonContiguousMask`] if its
+/// mask is not contiguous.
+impl TryFrom<Netv4Addr> for Ipv4Network {
+	type Error = Error;
+
+	fn try_from(net: Netv4Addr) -> Result<Self, Self::Error> {
+		if !net.is_cidr() {
+			return Err(Error::NonContiguousMask);
+		}
+
+		let prefix = u32::from(net.mask()).count_ones() as u8;
+
+		Ok(Self::new(net.addr(), prefix).expect("prefix length is valid"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_cidr() {
+		let net: Ipv4Network = "192.0.2.0/24".parse().unwrap();
+
+		assert_eq!(
+			Netv4Addr::from(net),
+			"192.0.2.0/24".parse::<Netv4Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn from_discards_host_bits() {
+		let net: Ipv4Network = "192.0.2.77/24".parse().unwrap();
+
+		assert_eq!(
+			Netv4Addr::from(net),
+			"192.0.2.0/24".parse::<Netv4Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn try_from_cidr() {
+		let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+
+		assert_eq!(
+			Ipv4Network::try_from(net),
+			Ok("192.0.2.0/24".parse().unwrap())
+		);
+	}
+
+	#[test]
+	fn try_from_non_cidr_fails() {
+		let net: Netv4Addr = "192.0.2.0/255.255.0.255".parse().unwrap();
+
+		assert_eq!(Ipv4Network::try_from(net), Err(Error::NonContiguousMask));
+	}
+}
@@ -1,7 +1,26 @@
 use super::Netv4Addr;
 use crate::{Error, Result};
 use core::str::FromStr;
-use std::net::Ipv4Addr;
+
+impl Netv4Addr {
+	/// Parse a `Netv4Addr` from a byte string.
+	///
+	/// This accepts exactly the same formats as the [`FromStr`] implementation,
+	/// but skips UTF-8 validation, which is useful when reading networks out of
+	/// binary data or large text dumps.  Parsing is done in a single pass
+	/// without allocating.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv4Addr;
+	/// let parsed: Netv4Addr = Netv4Addr::parse_ascii(b"192.0.2.16/29").unwrap();
+	/// assert_eq!(parsed, "192.0.2.16/29".parse().unwrap());
+	/// ```
+	pub fn parse_ascii(bytes: &[u8]) -> Result<Self> {
+		crate::parser::netv4addr(bytes)
+	}
+}
 
 impl FromStr for Netv4Addr {
 	type Err = Error;
@@ -39,28 +58,7 @@ impl FromStr for Netv4Addr {
 	/// assert_eq!(parsed, Netv4Addr::new(addr, mask));
 	/// ```
 	fn from_str(string: &str) -> Result<Self> {
-		let split: Vec<&str> = string.split(|c| c == '/' || c == ' ').collect();
-
-		let lhs: &str = split[0];
-		let rhs: &str = split
-			.get(1)
-			.ok_or_else(|| Error::ParseError("could not split provided input".to_string()))?;
-
-		let address = lhs.parse::<Ipv4Addr>();
-		let cidr = rhs.parse::<u32>();
-		let right_addr = rhs.parse::<Ipv4Addr>();
-
-		match (address, cidr, right_addr) {
-			(Ok(addr), Ok(cidr), _) => {
-				let mask: u32 = u32::max_value() ^ u32::max_value().checked_shr(cidr).unwrap_or(0_u32);
-				let mask: Ipv4Addr = mask.into();
-
-				Ok(Self::new(addr, mask))
-			}
-			(Ok(addr), Err(_), Ok(mask)) => Ok(Self::new(addr, mask)),
-			(Ok(addr), Err(_), Err(_)) => Ok(Self::from(addr)),
-			(Err(e), _, _) => Err(e.into()),
-		}
+		Self::parse_ascii(string.as_bytes())
 	}
 }
 
@@ -84,9 +82,29 @@ mod tests {
 		let result = "zoop".parse::<Netv4Addr>();
 		assert_eq!(
 			result,
-			Err(Error::ParseError(
-				"could not split provided input".to_string()
-			))
+			Err(Error::ParseError("could not split provided input"))
 		);
 	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::netv4addr;
+		use core::net::Ipv4Addr;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn display_round_trips(net in netv4addr()) {
+				prop_assert_eq!(net.to_string().parse::<Netv4Addr>(), Ok(net));
+			}
+
+			#[test]
+			fn hosts_round_trip(addr in any::<Ipv4Addr>()) {
+				let net: Netv4Addr = Netv4Addr::from(addr);
+
+				prop_assert_eq!(net.to_string().parse::<Netv4Addr>(), Ok(net));
+				prop_assert_eq!(format!("{}/32", addr).parse::<Netv4Addr>(), Ok(net));
+			}
+		}
+	}
 }
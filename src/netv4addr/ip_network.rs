@@ -0,0 +1,154 @@
+use super::Netv4Addr;
+use crate::iter::host::Hosts;
+use crate::traits::{Addresses, Broadcast, IpNetwork};
+use core::net::Ipv4Addr;
+
+impl IpNetwork for Netv4Addr {
+	type Addr = Ipv4Addr;
+	type Bits = u32;
+
+	const BITS: u32 = 32;
+
+	fn new(addr: Ipv4Addr, mask: Ipv4Addr) -> Self {
+		Netv4Addr::new(addr, mask)
+	}
+
+	fn from_prefix_len(addr: Ipv4Addr, prefix_len: u32) -> Option<Self> {
+		if prefix_len > 32 {
+			return None;
+		}
+
+		let mask: u32 = u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0);
+		Some(Netv4Addr::new(addr, Ipv4Addr::from(mask)))
+	}
+
+	fn addr(&self) -> Ipv4Addr {
+		Netv4Addr::addr(self)
+	}
+
+	fn mask(&self) -> Ipv4Addr {
+		Netv4Addr::mask(self)
+	}
+
+	fn prefix_len(&self) -> Option<u32> {
+		if Netv4Addr::is_cidr(self) {
+			Some(u32::from(Netv4Addr::mask(self)).count_ones())
+		} else {
+			None
+		}
+	}
+
+	fn is_cidr(&self) -> bool {
+		Netv4Addr::is_cidr(self)
+	}
+
+	fn host_bits(&self) -> u32 {
+		Hosts::host_bits(self)
+	}
+
+	fn last_addr(&self) -> Ipv4Addr {
+		self.broadcast()
+	}
+
+	fn len(&self) -> Option<u32> {
+		Netv4Addr::len(*self)
+	}
+
+	fn addresses(&self) -> Addresses<Self> {
+		Addresses::new(*self, Hosts::host_bits(self))
+	}
+
+	fn checked_add(self, count: u32) -> Option<Self> {
+		Netv4Addr::checked_add(self, count)
+	}
+
+	fn checked_sub(self, count: u32) -> Option<Self> {
+		Netv4Addr::checked_sub(self, count)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn prefix_len() {
+		assert_eq!(
+			<Netv4Addr as IpNetwork>::from_prefix_len(Ipv4Addr::new(192, 0, 2, 1), 24),
+			Some(pu!("192.0.2.0/24" # Netv4Addr))
+		);
+		assert_eq!(
+			<Netv4Addr as IpNetwork>::from_prefix_len(Ipv4Addr::UNSPECIFIED, 0),
+			Some(pu!("0.0.0.0/0" # Netv4Addr))
+		);
+		assert_eq!(
+			<Netv4Addr as IpNetwork>::from_prefix_len(Ipv4Addr::UNSPECIFIED, 33),
+			None
+		);
+		assert_eq!(
+			IpNetwork::prefix_len(&pu!("10.0.0.0/8" # Netv4Addr)),
+			Some(8)
+		);
+		assert_eq!(
+			IpNetwork::prefix_len(&pu!("10.0.0.0/255.0.255.0" # Netv4Addr)),
+			None
+		);
+	}
+
+	#[test]
+	fn accessors() {
+		let net = pu!("192.0.2.0/255.255.254.128" # Netv4Addr);
+
+		assert_eq!(IpNetwork::addr(&net), Ipv4Addr::new(192, 0, 2, 0));
+		assert_eq!(IpNetwork::mask(&net), Ipv4Addr::new(255, 255, 254, 128));
+		assert_eq!(IpNetwork::host_bits(&net), 8);
+		assert_eq!(IpNetwork::len(&net), Some(256));
+		assert_eq!(IpNetwork::last_addr(&net), Ipv4Addr::new(192, 0, 3, 127));
+		assert!(!IpNetwork::is_empty(&net));
+	}
+
+	#[test]
+	fn addresses_cover_non_contiguous_masks() {
+		let net = pu!("192.0.2.0/255.255.254.254" # Netv4Addr);
+		let addrs: Vec<Ipv4Addr> = net.addresses().collect();
+
+		assert_eq!(
+			addrs,
+			[
+				Ipv4Addr::new(192, 0, 2, 0),
+				Ipv4Addr::new(192, 0, 2, 1),
+				Ipv4Addr::new(192, 0, 3, 0),
+				Ipv4Addr::new(192, 0, 3, 1),
+			]
+		);
+		assert_eq!(
+			net.addresses().next_back(),
+			Some(Ipv4Addr::new(192, 0, 3, 1))
+		);
+	}
+
+	#[test]
+	fn addresses_of_single_address() {
+		let net = pu!("192.0.2.1/32" # Netv4Addr);
+
+		assert_eq!(
+			net.addresses().collect::<Vec<_>>(),
+			[Ipv4Addr::new(192, 0, 2, 1)]
+		);
+	}
+
+	#[test]
+	fn steps() {
+		let net = pu!("192.0.2.0/24" # Netv4Addr);
+
+		assert_eq!(
+			IpNetwork::checked_next(net),
+			Some("192.0.3.0/24".parse().unwrap())
+		);
+		assert_eq!(
+			IpNetwork::checked_prev(net),
+			Some("192.0.1.0/24".parse().unwrap())
+		);
+		assert_eq!(IpNetwork::checked_add(net, 0xff_ff00), None);
+	}
+}
@@ -0,0 +1,86 @@
+use super::Netv4Addr;
+use crate::inet;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+impl ToSql for Netv4Addr {
+	fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		out.extend_from_slice(&inet::encode(&(*self).into(), *ty == Type::CIDR)?);
+		Ok(IsNull::No)
+	}
+
+	accepts!(INET, CIDR);
+
+	to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Netv4Addr {
+	fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		Ok(inet::v4(inet::decode(raw)?)?)
+	}
+
+	accepts!(INET, CIDR);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// `SELECT inet_send('192.168.1.0/24'::inet)`
+	const CAPTURED: &[u8] = &[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x00];
+
+	#[test]
+	fn to_sql_inet() {
+		let mut out = BytesMut::new();
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert!(matches!(net.to_sql(&Type::INET, &mut out), Ok(IsNull::No)));
+		assert_eq!(&out[..], CAPTURED);
+	}
+
+	#[test]
+	fn to_sql_cidr() {
+		let mut out = BytesMut::new();
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert!(net.to_sql(&Type::CIDR, &mut out).is_ok());
+		assert_eq!(&out[..], &[0x02, 0x18, 0x01, 0x04, 0xc0, 0xa8, 0x01, 0x00]);
+	}
+
+	#[test]
+	fn to_sql_non_cidr_fails() {
+		let mut out = BytesMut::new();
+		let net: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+
+		assert!(net.to_sql(&Type::INET, &mut out).is_err());
+	}
+
+	#[test]
+	fn from_sql() {
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert_eq!(Netv4Addr::from_sql(&Type::INET, CAPTURED).unwrap(), net);
+		assert_eq!(Netv4Addr::from_sql(&Type::CIDR, CAPTURED).unwrap(), net);
+	}
+
+	#[test]
+	fn from_sql_wrong_family_fails() {
+		assert!(Netv4Addr::from_sql(
+			&Type::INET,
+			&[
+				0x03, 0x20, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0x00, 0x00, 0x00,
+			]
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn accepts_inet_and_cidr() {
+		assert!(<Netv4Addr as ToSql>::accepts(&Type::INET));
+		assert!(<Netv4Addr as ToSql>::accepts(&Type::CIDR));
+		assert!(!<Netv4Addr as ToSql>::accepts(&Type::TEXT));
+		assert!(<Netv4Addr as FromSql>::accepts(&Type::CIDR));
+	}
+}
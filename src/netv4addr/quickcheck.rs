@@ -0,0 +1,60 @@
+use super::Netv4Addr;
+use core::net::Ipv4Addr;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generate `Netv4Addr`s for `quickcheck`.
+///
+/// Half of the generated networks are CIDR, since a uniformly random netmask is
+/// almost never CIDR.  Shrinking shrinks the address and netmask as integers.
+impl Arbitrary for Netv4Addr {
+	fn arbitrary(g: &mut Gen) -> Self {
+		let addr: Ipv4Addr = Ipv4Addr::arbitrary(g);
+
+		let mask: u32 = if bool::arbitrary(g) {
+			u32::MAX
+				.checked_shl(32 - u32::arbitrary(g) % 33)
+				.unwrap_or(0)
+		} else {
+			u32::arbitrary(g)
+		};
+
+		Self::new(addr, mask.into())
+	}
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		let parts: (u32, u32) = (self.addr().into(), self.mask().into());
+
+		Box::new(
+			parts
+				.shrink()
+				.map(|(addr, mask)| Self::new(addr.into(), mask.into())),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use quickcheck::quickcheck;
+
+	quickcheck! {
+		fn addr_is_masked(net: Netv4Addr) -> bool {
+			u32::from(net.addr()) & !u32::from(net.mask()) == 0
+		}
+
+		fn shrinks_are_valid(net: Netv4Addr) -> bool {
+			net
+				.shrink()
+				.all(|net| u32::from(net.addr()) & !u32::from(net.mask()) == 0)
+		}
+	}
+
+	#[test]
+	fn produces_cidr_and_non_cidr() {
+		let mut g = Gen::new(100);
+		let nets: Vec<Netv4Addr> = (0..64).map(|_| Netv4Addr::arbitrary(&mut g)).collect();
+
+		assert!(nets.iter().any(Netv4Addr::is_cidr));
+		assert!(!nets.iter().all(Netv4Addr::is_cidr));
+	}
+}
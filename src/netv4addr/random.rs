@@ -0,0 +1,222 @@
+use super::Netv4Addr;
+use crate::iter::host::Hosts;
+use core::net::Ipv4Addr;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+impl Netv4Addr {
+	/// Pick an address from this network uniformly at random.
+	///
+	/// Every address contained in the network is equally likely, including the
+	/// network and broadcast addresses.  Non-CIDR netmasks are supported.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Contains, Netv4Addr};
+	/// let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+	/// let addr = net.random_addr(&mut rand::thread_rng());
+	/// assert!(net.contains(&addr));
+	/// ```
+	pub fn random_addr<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv4Addr {
+		self.host(u128::from(rng.gen::<u32>()))
+	}
+
+	/// Pick an address from this network uniformly at random, excluding the
+	/// network and broadcast addresses (those whose host bits are all zeroes or
+	/// all ones).
+	///
+	/// Returns `None` if the network has fewer than four addresses, since then
+	/// there is nothing left once those two are excluded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Broadcast, Netv4Addr};
+	/// let net: Netv4Addr = "192.0.2.0/30".parse().unwrap();
+	/// let addr = net.random_host(&mut rand::thread_rng()).unwrap();
+	/// assert_ne!(addr, net.addr());
+	/// assert_ne!(addr, net.broadcast());
+	///
+	/// let net: Netv4Addr = "192.0.2.0/31".parse().unwrap();
+	/// assert_eq!(net.random_host(&mut rand::thread_rng()), None);
+	/// ```
+	pub fn random_host<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Ipv4Addr> {
+		let last: u32 = u32::MAX.checked_shr(32 - self.host_bits()).unwrap_or(0);
+
+		if last < 3 {
+			None
+		} else {
+			Some(self.host(u128::from(rng.gen_range(1..last))))
+		}
+	}
+
+	/// Pick a subnet of this network with the given prefix length uniformly at
+	/// random.
+	///
+	/// Returns `None` if `prefix_len` is greater than 32, or if the netmask of
+	/// this network has bits set beyond `prefix_len` (in which case no subnet of
+	/// that length exists).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Contains, Netv4Addr};
+	/// let net: Netv4Addr = "10.0.0.0/8".parse().unwrap();
+	///
+	/// let subnet = net.random_subnet(24, &mut rand::thread_rng()).unwrap();
+	/// assert!(net.contains(&subnet));
+	/// assert_eq!(subnet.mask(), "255.255.255.0".parse::<std::net::Ipv4Addr>().unwrap());
+	///
+	/// assert_eq!(net.random_subnet(4, &mut rand::thread_rng()), None);
+	/// ```
+	pub fn random_subnet<R: Rng + ?Sized>(&self, prefix_len: u32, rng: &mut R) -> Option<Self> {
+		if prefix_len > 32 {
+			return None;
+		}
+
+		let mask: u32 = u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0);
+
+		if u32::from(self.mask()) & !mask == 0 {
+			Some(Self::new(self.random_addr(rng), Ipv4Addr::from(mask)))
+		} else {
+			None
+		}
+	}
+}
+
+/// A `Netv4Addr` is a uniform distribution over its contained addresses.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Contains, Netv4Addr};
+/// # use rand::Rng;
+/// let net: Netv4Addr = "198.51.100.0/24".parse().unwrap();
+/// let addr: std::net::Ipv4Addr = rand::thread_rng().sample(net);
+/// assert!(net.contains(&addr));
+/// ```
+impl Distribution<Ipv4Addr> for Netv4Addr {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv4Addr {
+		self.random_addr(rng)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Broadcast, Contains};
+	use rand::rngs::StdRng;
+	use rand::SeedableRng;
+	use std::collections::HashSet;
+
+	fn rng() -> StdRng {
+		StdRng::seed_from_u64(0x5eed)
+	}
+
+	#[test]
+	fn random_addr_is_contained() {
+		let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			assert!(net.contains(&net.random_addr(&mut rng)));
+		}
+	}
+
+	#[test]
+	fn random_addr_covers_small_network() {
+		let net: Netv4Addr = "192.0.2.0/29".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Ipv4Addr> = (0..1000).map(|_| net.random_addr(&mut rng)).collect();
+		assert_eq!(seen.len(), 8);
+	}
+
+	#[test]
+	fn random_addr_respects_non_cidr_mask() {
+		let net: Netv4Addr = "10.0.0.0/255.255.0.255".parse().unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			let addr: Ipv4Addr = net.random_addr(&mut rng);
+			assert!(net.contains(&addr));
+			assert_eq!(addr.octets()[3], 0);
+		}
+	}
+
+	#[test]
+	fn random_addr_slash_32_is_constant() {
+		let net: Netv4Addr = "192.0.2.42/32".parse().unwrap();
+		assert_eq!(net.random_addr(&mut rng()), Ipv4Addr::new(192, 0, 2, 42));
+	}
+
+	#[test]
+	fn random_host_excludes_network_and_broadcast() {
+		let net: Netv4Addr = "192.0.2.0/30".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Ipv4Addr> = (0..1000)
+			.map(|_| net.random_host(&mut rng).unwrap())
+			.collect();
+
+		assert_eq!(seen.len(), 2);
+		assert!(!seen.contains(&net.addr()));
+		assert!(!seen.contains(&net.broadcast()));
+	}
+
+	#[test]
+	fn random_host_too_small_returns_none() {
+		let net: Netv4Addr = "192.0.2.0/31".parse().unwrap();
+		assert_eq!(net.random_host(&mut rng()), None);
+
+		let net: Netv4Addr = "192.0.2.0/32".parse().unwrap();
+		assert_eq!(net.random_host(&mut rng()), None);
+	}
+
+	#[test]
+	fn random_subnet_is_contained_and_sized() {
+		let net: Netv4Addr = "10.0.0.0/16".parse().unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			let subnet: Netv4Addr = net.random_subnet(20, &mut rng).unwrap();
+			assert!(net.contains(&subnet));
+			assert_eq!(subnet.len(), Some(4096));
+		}
+	}
+
+	#[test]
+	fn random_subnet_covers_all_subnets() {
+		let net: Netv4Addr = "10.0.0.0/16".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Netv4Addr> = (0..1000)
+			.map(|_| net.random_subnet(18, &mut rng).unwrap())
+			.collect();
+		assert_eq!(seen.len(), 4);
+	}
+
+	#[test]
+	fn random_subnet_shorter_prefix_returns_none() {
+		let net: Netv4Addr = "10.0.0.0/16".parse().unwrap();
+		assert_eq!(net.random_subnet(15, &mut rng()), None);
+		assert_eq!(net.random_subnet(33, &mut rng()), None);
+	}
+
+	#[test]
+	fn random_subnet_same_prefix_returns_self() {
+		let net: Netv4Addr = "10.0.0.0/16".parse().unwrap();
+		assert_eq!(net.random_subnet(16, &mut rng()), Some(net));
+	}
+
+	#[test]
+	fn distribution_samples_contained_addresses() {
+		let net: Netv4Addr = "172.16.0.0/12".parse().unwrap();
+
+		assert!(net
+			.sample_iter(rng())
+			.take(1000)
+			.all(|addr: Ipv4Addr| net.contains(&addr)));
+	}
+}
@@ -0,0 +1,161 @@
+use super::Netv4Addr;
+use crate::relation::relation;
+use crate::NetRelation;
+
+impl Netv4Addr {
+	/// Determine how this network relates to `other`, as sets of addresses.
+	///
+	/// This is correct for non-contiguous netmasks, which are the only way for
+	/// two networks to be [`NetRelation::Overlapping`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{NetRelation, Netv4Addr};
+	/// let net: Netv4Addr = "10.0.0.0/24".parse().unwrap();
+	///
+	/// assert_eq!(net.relation(&"10.0.0.0/16".parse().unwrap()), NetRelation::ContainedBy);
+	/// assert_eq!(net.relation(&"10.0.1.0/24".parse().unwrap()), NetRelation::Adjacent);
+	/// assert_eq!(net.relation(&"10.0.2.0/24".parse().unwrap()), NetRelation::Disjoint);
+	/// ```
+	pub fn relation(&self, other: &Self) -> NetRelation {
+		relation(self.bits(), other.bits(), 32)
+	}
+
+	/// Determine whether this network shares any addresses with `other`.
+	pub fn overlaps(&self, other: &Self) -> bool {
+		!matches!(
+			self.relation(other),
+			NetRelation::Adjacent | NetRelation::Disjoint
+		)
+	}
+
+	/// Determine whether every address of this network is in `other`.
+	///
+	/// A network is a subnet of itself.
+	pub fn is_subnet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::ContainedBy
+		)
+	}
+
+	/// Determine whether every address of `other` is in this network.
+	///
+	/// A network is a supernet of itself.
+	pub fn is_supernet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::Contains
+		)
+	}
+
+	/// Determine whether this network shares no addresses with `other`.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		!self.overlaps(other)
+	}
+
+	/// Determine whether this network shares no addresses with `other`, but an
+	/// address of one is immediately followed by an address of the other.
+	pub fn is_adjacent(&self, other: &Self) -> bool {
+		self.relation(other) == NetRelation::Adjacent
+	}
+
+	fn bits(&self) -> (u128, u128) {
+		(u32::from(self.addr()).into(), u32::from(self.mask()).into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use crate::Contains;
+	use core::net::Ipv4Addr;
+	use proptest::prelude::*;
+
+	#[test]
+	fn predicates() {
+		let a: Netv4Addr = "10.0.0.0/16".parse().unwrap();
+		let b: Netv4Addr = "10.0.1.0/24".parse().unwrap();
+		let c: Netv4Addr = "10.1.0.0/16".parse().unwrap();
+
+		assert!(a.overlaps(&b) && b.overlaps(&a));
+		assert!(b.is_subnet_of(&a) && !a.is_subnet_of(&b));
+		assert!(a.is_supernet_of(&b) && !b.is_supernet_of(&a));
+		assert!(a.is_subnet_of(&a) && a.is_supernet_of(&a));
+		assert!(a.is_disjoint(&c) && a.is_adjacent(&c));
+		assert!(!a.is_adjacent(&b));
+	}
+
+	#[test]
+	fn non_contiguous_overlap() {
+		let a: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+		let b: Netv4Addr = "10.0.0.0/255.255.0.0".parse().unwrap();
+
+		assert_eq!(a.relation(&b), NetRelation::Overlapping);
+		assert_eq!(b.relation(&a), NetRelation::Overlapping);
+		assert!(a.overlaps(&b) && !a.is_subnet_of(&b) && !a.is_supernet_of(&b));
+	}
+
+	proptest! {
+		#[test]
+		fn agrees_with_contains(a in strategy::netv4addr(), b in strategy::netv4addr()) {
+			prop_assert_eq!(a.is_supernet_of(&b), a.contains(&b));
+			prop_assert_eq!(a.is_subnet_of(&b), b.contains(&a));
+		}
+
+		#[test]
+		fn is_symmetric(a in strategy::netv4addr(), b in strategy::netv4addr()) {
+			let mirrored = match a.relation(&b) {
+				NetRelation::Contains => NetRelation::ContainedBy,
+				NetRelation::ContainedBy => NetRelation::Contains,
+				relation => relation,
+			};
+
+			prop_assert_eq!(b.relation(&a), mirrored);
+		}
+
+		#[test]
+		fn contains_its_subnets(
+			(net, subnet) in strategy::netv4addr().prop_flat_map(|net| (Just(net), strategy::contained_netv4addr(net)))
+		) {
+			prop_assert!(net.is_supernet_of(&subnet));
+			prop_assert!(net.overlaps(&subnet));
+		}
+	}
+
+	#[test]
+	fn adjacency_matches_brute_force() {
+		// Every pair of networks with masks and addresses confined to the low
+		// four bits, checked against the definition.
+		let nets: Vec<Netv4Addr> = (0..16_u32)
+			.flat_map(|mask| (0..16_u32).map(move |addr| (addr & mask, mask)))
+			.map(|(addr, mask)| Netv4Addr::new((addr | 0xffff_fff0).into(), (mask | 0xffff_fff0).into()))
+			.collect();
+
+		let addrs = |net: &Netv4Addr| {
+			let net = *net;
+			(0xffff_fff0..=u32::MAX)
+				.map(Ipv4Addr::from)
+				.filter(move |addr| net.contains(addr))
+		};
+		let precedes = |a: &Netv4Addr, b: &Netv4Addr| {
+			addrs(a).any(|addr| {
+				u32::from(addr)
+					.checked_add(1)
+					.is_some_and(|next| b.contains(&Ipv4Addr::from(next)))
+			})
+		};
+
+		for a in &nets {
+			for b in &nets {
+				let shared = addrs(a).any(|addr| b.contains(&addr));
+				let adjacent = !shared && (precedes(a, b) || precedes(b, a));
+
+				assert_eq!(a.overlaps(b), shared, "{} {}", a, b);
+				assert_eq!(a.is_adjacent(b), adjacent, "{} {}", a, b);
+			}
+		}
+	}
+}
@@ -0,0 +1,100 @@
+use super::Netv4Addr;
+use core::net::Ipv4Addr;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+/// Stores the network as text, like `"192.0.2.0/24"`.
+impl ToSql for Netv4Addr {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		Ok(ToSqlOutput::from(self.to_string()))
+	}
+}
+
+/// Reads the network from text, in any notation accepted by `parse_ascii` or
+/// as a bare address, or from a blob of the four address octets followed by
+/// the four mask octets.
+impl FromSql for Netv4Addr {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		match value {
+			ValueRef::Text(text) => Self::parse_ascii(text)
+				.or_else(|error| crate::parser::ipv4addr(text).map(Self::from).ok_or(error))
+				.map_err(|error| FromSqlError::Other(Box::new(error))),
+			ValueRef::Blob(blob) => match blob {
+				[a, b, c, d, e, f, g, h] => Ok(Self::new(
+					Ipv4Addr::new(*a, *b, *c, *d),
+					Ipv4Addr::new(*e, *f, *g, *h),
+				)),
+				_ => Err(FromSqlError::InvalidBlobSize {
+					expected_size: 8,
+					blob_size: blob.len(),
+				}),
+			},
+			_ => Err(FromSqlError::InvalidType),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rusqlite::Connection;
+
+	fn round_trip(net: &Netv4Addr) -> Netv4Addr {
+		let conn = Connection::open_in_memory().unwrap();
+		conn
+			.execute("CREATE TABLE nets (net TEXT NOT NULL)", [])
+			.unwrap();
+		conn
+			.execute("INSERT INTO nets (net) VALUES (?1)", [net])
+			.unwrap();
+		conn
+			.query_row("SELECT net FROM nets", [], |row| row.get(0))
+			.unwrap()
+	}
+
+	fn select(sql: &str) -> rusqlite::Result<Netv4Addr> {
+		let conn = Connection::open_in_memory().unwrap();
+		conn.query_row(sql, [], |row| row.get(0))
+	}
+
+	#[test]
+	fn round_trips_through_text() {
+		for net in &["192.0.2.0/24", "10.0.0.0/255.0.255.0"] {
+			let net: Netv4Addr = net.parse().unwrap();
+
+			assert_eq!(round_trip(&net), net);
+		}
+	}
+
+	#[test]
+	fn stores_text() {
+		let conn = Connection::open_in_memory().unwrap();
+		let net: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+		let text: String = conn
+			.query_row("SELECT ?1", [net], |row| row.get(0))
+			.unwrap();
+
+		assert_eq!(text, "192.0.2.0/24");
+	}
+
+	#[test]
+	fn reads_bare_address() {
+		let net: Netv4Addr = "192.0.2.1/32".parse().unwrap();
+
+		assert_eq!(select("SELECT '192.0.2.1'").unwrap(), net);
+	}
+
+	#[test]
+	fn reads_blob() {
+		let net: Netv4Addr = "10.0.0.0/255.0.255.0".parse().unwrap();
+
+		assert_eq!(select("SELECT x'0a000000ff00ff00'").unwrap(), net);
+	}
+
+	#[test]
+	fn rejects_other_values() {
+		assert!(select("SELECT 'not a network'").is_err());
+		assert!(select("SELECT x'0a000000'").is_err());
+		assert!(select("SELECT 42").is_err());
+		assert!(select("SELECT NULL").is_err());
+	}
+}
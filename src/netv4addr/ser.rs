@@ -1,30 +1,119 @@
 use super::Netv4Addr;
 
-use serde::{Serialize, Serializer};
+use serde::ser::{SerializeTupleVariant, Serializer};
+use serde::Serialize;
 
+/// Serializes the network as a string (e.g. `"192.0.2.0/24"`) for
+/// human-readable formats.
+///
+/// For compact formats, the network is instead serialized as the `Cidr`
+/// variant holding the address octets and prefix length when its mask is
+/// contiguous, or as the `Extended` variant holding the address and mask
+/// octets otherwise.
 #[cfg(feature = "serde")]
 impl Serialize for Netv4Addr {
 	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		serializer.serialize_str(&self.to_string())
+		if serializer.is_human_readable() {
+			return serializer.collect_str(self);
+		}
+
+		let mask: u32 = self.mask().into();
+		let ones = mask.count_ones();
+		let cidr_mask: u32 = u32::MAX.checked_shl(32 - ones).unwrap_or(0);
+
+		if mask == cidr_mask {
+			let mut variant = serializer.serialize_tuple_variant("Netv4Addr", 0, "Cidr", 2)?;
+			variant.serialize_field(&self.addr().octets())?;
+			variant.serialize_field(&(ones as u8))?;
+			variant.end()
+		} else {
+			let mut variant = serializer.serialize_tuple_variant("Netv4Addr", 1, "Extended", 2)?;
+			variant.serialize_field(&self.addr().octets())?;
+			variant.serialize_field(&self.mask().octets())?;
+			variant.end()
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::Netv4Addr;
-	use serde_test::{assert_tokens, Token};
+	use serde_test::{assert_ser_tokens, assert_tokens, Configure, Token};
 
 	#[test]
 	fn test_ser_cidr_localhost() {
 		let netaddr: Netv4Addr = "127.0.0.1/8".parse().unwrap();
 
-		assert_tokens(&netaddr, &[Token::Str("127.0.0.0/8")]);
+		assert_tokens(&netaddr.readable(), &[Token::Str("127.0.0.0/8")]);
 	}
 
 	#[test]
 	fn test_ser_non_cidr_localhost() {
 		let netaddr: Netv4Addr = "127.0.0.1/251.255.255.7".parse().unwrap();
 
-		assert_tokens(&netaddr, &[Token::Str("123.0.0.1/251.255.255.7")]);
+		assert_tokens(
+			&netaddr.readable(),
+			&[Token::Str("123.0.0.1/251.255.255.7")],
+		);
+	}
+
+	#[test]
+	fn test_ser_readable_is_string() {
+		let netaddr: Netv4Addr = "192.0.2.0/24".parse().unwrap();
+
+		assert_ser_tokens(&netaddr.readable(), &[Token::Str("192.0.2.0/24")]);
+	}
+
+	#[test]
+	fn test_ser_compact_cidr() {
+		let netaddr: Netv4Addr = "127.0.0.1/8".parse().unwrap();
+
+		assert_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv4Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 4 },
+				Token::U8(127),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::TupleEnd,
+				Token::U8(8),
+				Token::TupleVariantEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_ser_compact_non_cidr() {
+		let netaddr: Netv4Addr = "127.0.0.1/251.255.255.7".parse().unwrap();
+
+		assert_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv4Addr",
+					variant: "Extended",
+					len: 2,
+				},
+				Token::Tuple { len: 4 },
+				Token::U8(123),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(1),
+				Token::TupleEnd,
+				Token::Tuple { len: 4 },
+				Token::U8(251),
+				Token::U8(255),
+				Token::U8(255),
+				Token::U8(7),
+				Token::TupleEnd,
+				Token::TupleVariantEnd,
+			],
+		);
 	}
 }
@@ -0,0 +1,76 @@
+use super::Netv4Addr;
+use crate::inet;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{
+	PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+impl Type<Postgres> for Netv4Addr {
+	fn type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_OID))
+	}
+
+	fn compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_OID))
+	}
+}
+
+impl PgHasArrayType for Netv4Addr {
+	fn array_type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+	}
+
+	fn array_compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_ARRAY_OID))
+	}
+}
+
+impl Encode<'_, Postgres> for Netv4Addr {
+	fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+		buf.extend_from_slice(&inet::encode(&(*self).into(), false)?);
+		Ok(IsNull::No)
+	}
+}
+
+impl<'r> Decode<'r, Postgres> for Netv4Addr {
+	fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+		let net = match value.format() {
+			PgValueFormat::Binary => inet::decode(value.as_bytes()?)?,
+			PgValueFormat::Text => inet::decode_text(value.as_str()?)?,
+		};
+
+		Ok(inet::v4(net)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encodes_captured_bytes() {
+		let mut buf = PgArgumentBuffer::default();
+		let net: Netv4Addr = "192.168.1.0/24".parse().unwrap();
+
+		assert!(matches!(net.encode_by_ref(&mut buf), Ok(IsNull::No)));
+		assert_eq!(&buf[..], &[0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x00]);
+	}
+
+	#[test]
+	fn compatible_with_inet_and_cidr() {
+		assert!(<Netv4Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(869))
+		));
+		assert!(<Netv4Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(650))
+		));
+		assert!(!<Netv4Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(25))
+		));
+	}
+}
@@ -1,5 +1,6 @@
 use crate::traits::Mask;
-use std::net::Ipv6Addr;
+use crate::{Netmaskv6, PrefixLen, ShuffledAddressIterator};
+use core::net::Ipv6Addr;
 
 /// A structure representing an IPv6 network.
 ///
@@ -7,6 +8,12 @@ use std::net::Ipv6Addr;
 /// representing the network address (`addr`), and another
 /// representing the netmask (`mask`).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+	feature = "diesel",
+	derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Inet))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Cidr))]
 pub struct Netv6Addr {
 	addr: Ipv6Addr,
 	mask: Ipv6Addr,
@@ -22,10 +29,12 @@ impl Netv6Addr {
 	}
 
 	pub fn is_cidr(&self) -> bool {
-		let mask: u128 = self.mask.into();
-		let ones: u32 = mask.count_ones();
-		let cidr_mask: u128 = u128::max_value().checked_shl(128 - ones).unwrap_or(0);
-		mask == cidr_mask
+		self.netmask().is_contiguous()
+	}
+
+	/// Get the netmask as a [`Netmaskv6`].
+	pub const fn netmask(&self) -> Netmaskv6 {
+		Netmaskv6::new(self.mask)
 	}
 
 	/// Create a new `Netv6Addr` from the given `addr` and `mask`.
@@ -47,6 +56,81 @@ impl Netv6Addr {
 		Self { addr, mask }
 	}
 
+	/// Create a new `Netv6Addr` from the given `addr` and `mask`, in a `const`
+	/// context.
+	///
+	/// This is the same as [`Netv6Addr::new`], but can be used to initialize `const`
+	/// and `static` items.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// # use std::net::Ipv6Addr;
+	/// const NET: Netv6Addr = Netv6Addr::new_const(
+	///     Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+	///     Ipv6Addr::new(0xffff, 0xffff, 0, 0, 0, 0, 0, 0),
+	/// );
+	///
+	/// assert_eq!(NET, "2001:db8::/32".parse().unwrap());
+	/// ```
+	pub const fn new_const(addr: Ipv6Addr, mask: Ipv6Addr) -> Self {
+		let addr: Ipv6Addr = Ipv6Addr::from_bits(addr.to_bits() & mask.to_bits());
+		Self { addr, mask }
+	}
+
+	/// Create a new CIDR `Netv6Addr` from the given `addr` and prefix length, in a
+	/// `const` context.
+	///
+	/// Masks the given `addr` with a netmask of `prefix` leading ones.
+	///
+	/// # Panics
+	///
+	/// Panics if `prefix` is greater than 128, which is a compile error when
+	/// evaluated in a `const` context.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// # use std::net::Ipv6Addr;
+	/// const NET: Netv6Addr = Netv6Addr::from_prefix(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 32);
+	///
+	/// assert_eq!(NET, "2001:db8::/32".parse().unwrap());
+	/// ```
+	pub const fn from_prefix(addr: Ipv6Addr, prefix: u8) -> Self {
+		assert!(prefix <= 128, "prefix length is too long");
+
+		let mask: u128 = match u128::MAX.checked_shl(128 - prefix as u32) {
+			Some(mask) => mask,
+			None => 0,
+		};
+		Self::new_const(addr, Ipv6Addr::from_bits(mask))
+	}
+
+	/// Create a new `Netv6Addr` from the given `addr` and [`Netmaskv6`], masking the
+	/// `addr`.
+	pub const fn with_netmask(addr: Ipv6Addr, mask: Netmaskv6) -> Self {
+		Self::new_const(addr, mask.addr())
+	}
+
+	/// Create a new CIDR `Netv6Addr` from the given `addr` and [`PrefixLen`],
+	/// masking the `addr`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Netv6Addr, PrefixLen};
+	/// # use std::net::Ipv6Addr;
+	/// let len: PrefixLen = PrefixLen::new(32).unwrap();
+	/// let net = Netv6Addr::with_prefix_len(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), len);
+	///
+	/// assert_eq!(net, "2001:db8::/32".parse().unwrap());
+	/// ```
+	pub fn with_prefix_len(addr: Ipv6Addr, len: PrefixLen) -> Self {
+		Self::with_netmask(addr, Netmaskv6::from(len))
+	}
+
 	/// Compute the number of addresses in this network.
 	///
 	/// This is done by raising `2_u128` to the number of zeroes in the netmask,
@@ -85,6 +169,14 @@ impl Netv6Addr {
 		2_u128.checked_pow(u128::from(self.mask).count_zeros())
 	}
 
+	/// Produce an iterator over the _contained addresses_ of this `Netv6Addr` in
+	/// a pseudo-random order determined by `seed`.
+	///
+	/// See [`ShuffledAddressIterator`] for details.
+	pub fn shuffled(&self, seed: u64) -> ShuffledAddressIterator<[Netv6Addr; 1], Netv6Addr> {
+		ShuffledAddressIterator::single(*self, seed)
+	}
+
 	/// Determine if the network is empty.
 	///
 	/// (Plot twist, it isn't.)  Even a /128 has one device in it.
@@ -94,19 +186,39 @@ impl Netv6Addr {
 	}
 }
 
+mod arith;
+mod bitops;
 mod contains;
 mod display;
 mod from;
 mod fromstr;
 mod hash;
+mod ip_network;
 mod merge;
 mod ord;
 mod partialord;
+mod relation;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "serde")]
 mod de;
+#[cfg(feature = "diesel")]
+mod diesel_pg;
+#[cfg(feature = "postgres-types")]
+mod postgres;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
 mod ser;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 
 #[cfg(test)]
 mod tests {
@@ -188,6 +300,61 @@ mod tests {
 		}
 	}
 
+	mod new_const {
+		use super::*;
+
+		#[test]
+		fn matches_new() {
+			let addr: Ipv6Addr = "2001:db8:dead:beef::1".parse().unwrap();
+			let mask: Ipv6Addr = "ffff:ffff:ffff:ff00::0".parse().unwrap();
+
+			assert_eq!(Netv6Addr::new_const(addr, mask), Netv6Addr::new(addr, mask));
+		}
+	}
+
+	mod from_prefix {
+		use super::*;
+
+		#[test]
+		fn masks_addr() {
+			let addr: Ipv6Addr = "2001:db8:dead:beef::1".parse().unwrap();
+
+			assert_eq!(Netv6Addr::from_prefix(addr, 0), pu!("::/0" # Netv6Addr));
+			assert_eq!(
+				Netv6Addr::from_prefix(addr, 128),
+				pu!("2001:db8:dead:beef::1/128" # Netv6Addr)
+			);
+		}
+
+		#[test]
+		#[should_panic(expected = "prefix length is too long")]
+		fn rejects_long_prefix() {
+			Netv6Addr::from_prefix(Ipv6Addr::UNSPECIFIED, 129);
+		}
+	}
+
+	mod with_prefix_len {
+		use super::*;
+
+		#[test]
+		fn masks_addr() {
+			let addr: Ipv6Addr = "2001:db8:dead:beef::1".parse().unwrap();
+
+			assert_eq!(
+				Netv6Addr::with_prefix_len(addr, PrefixLen::new(48).unwrap()),
+				pu!("2001:db8:dead::/48" # Netv6Addr)
+			);
+		}
+
+		#[test]
+		fn netmask_round_trips() {
+			let net: Netv6Addr = pu!("2001:db8::/ffff:0:ffff::" # Netv6Addr);
+
+			assert_eq!(Netv6Addr::with_netmask(net.addr(), net.netmask()), net);
+			assert_eq!(net.netmask().prefix_len(), None);
+		}
+	}
+
 	mod len {
 		use super::*;
 
@@ -0,0 +1,53 @@
+use super::Netv6Addr;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::net::Ipv6Addr;
+
+/// Build a `Netv6Addr` from unstructured fuzzer input.
+///
+/// Half of the input space yields CIDR networks, since a uniformly random
+/// netmask is almost never CIDR.
+impl<'a> Arbitrary<'a> for Netv6Addr {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let addr: Ipv6Addr = u128::arbitrary(u)?.into();
+
+		let mask: u128 = if bool::arbitrary(u)? {
+			u128::MAX
+				.checked_shl(128 - u.int_in_range(0..=128_u32)?)
+				.unwrap_or(0)
+		} else {
+			u128::arbitrary(u)?
+		};
+
+		Ok(Self::new(addr, mask.into()))
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		<(u128, bool, u128)>::size_hint(depth)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cidr_from_bytes() {
+		let bytes: [u8; 18] = [
+			0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x42, 1, 64,
+		];
+		let net: Netv6Addr = Netv6Addr::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+		assert!(net.is_cidr());
+	}
+
+	#[test]
+	fn addr_is_masked() {
+		let bytes: Vec<u8> = (0..=255).collect();
+		let mut u = Unstructured::new(&bytes);
+
+		for _ in 0..32 {
+			let net: Netv6Addr = Netv6Addr::arbitrary(&mut u).unwrap();
+			assert_eq!(u128::from(net.addr()) & !u128::from(net.mask()), 0);
+		}
+	}
+}
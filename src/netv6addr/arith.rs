@@ -0,0 +1,215 @@
+use super::Netv6Addr;
+use crate::iter::host::deposit;
+use core::net::Ipv6Addr;
+use core::ops::{Add, Sub};
+
+impl Netv6Addr {
+	/// Move forward by `count` networks of the same size, or give `None` if
+	/// that would go past `ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff`.
+	///
+	/// The network keeps its netmask, and its network bits are counted up as
+	/// though they were one number.  For a CIDR network, this is the same as
+	/// adding `count` times its size to its address; non-contiguous netmasks
+	/// skip over the addresses in their host bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+	/// assert_eq!(net.checked_add(2), Some("2001:dba::/32".parse().unwrap()));
+	/// assert_eq!(net.checked_add(0xdfff_0000), None);
+	///
+	/// let net: Netv6Addr = "2001:db8::/ffff:0:ffff::".parse().unwrap();
+	/// assert_eq!(net.checked_add(0x1_0001), Some("2002:0:1::/ffff:0:ffff::".parse().unwrap()));
+	/// ```
+	pub fn checked_add(self, count: u128) -> Option<Self> {
+		let mask: u128 = self.mask().into();
+		let (step, overflowed) = deposit(count, mask);
+
+		if overflowed {
+			return None;
+		}
+
+		(u128::from(self.addr()) | !mask)
+			.checked_add(step)
+			.map(|addr: u128| Self::new(Ipv6Addr::from(addr), self.mask()))
+	}
+
+	/// Move backward by `count` networks of the same size, or give `None` if
+	/// that would go past `::`.
+	///
+	/// See [`checked_add`](Self::checked_add) for how networks are counted.
+	pub fn checked_sub(self, count: u128) -> Option<Self> {
+		let mask: u128 = self.mask().into();
+		let (step, overflowed) = deposit(count, mask);
+
+		if overflowed {
+			return None;
+		}
+
+		u128::from(self.addr())
+			.checked_sub(step)
+			.map(|addr: u128| Self::new(Ipv6Addr::from(addr), self.mask()))
+	}
+
+	/// Move forward by `count` networks of the same size, wrapping around from
+	/// the last network to the first.
+	pub fn wrapping_add(self, count: u128) -> Self {
+		let mask: u128 = self.mask().into();
+		let (step, _) = deposit(count, mask);
+		let addr: u128 = (u128::from(self.addr()) | !mask).wrapping_add(step);
+
+		Self::new(Ipv6Addr::from(addr), self.mask())
+	}
+
+	/// Move backward by `count` networks of the same size, wrapping around from
+	/// the first network to the last.
+	pub fn wrapping_sub(self, count: u128) -> Self {
+		let mask: u128 = self.mask().into();
+		let (step, _) = deposit(count, mask);
+		let addr: u128 = u128::from(self.addr()).wrapping_sub(step);
+
+		Self::new(Ipv6Addr::from(addr), self.mask())
+	}
+
+	/// Get the next network of the same size, if there is one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// let net: Netv6Addr = "2001:db8::/48".parse().unwrap();
+	/// assert_eq!(net.checked_next(), Some("2001:db8:1::/48".parse().unwrap()));
+	///
+	/// let net: Netv6Addr = "ffff::/16".parse().unwrap();
+	/// assert_eq!(net.checked_next(), None);
+	/// ```
+	pub fn checked_next(self) -> Option<Self> {
+		self.checked_add(1)
+	}
+
+	/// Get the previous network of the same size, if there is one.
+	pub fn checked_prev(self) -> Option<Self> {
+		self.checked_sub(1)
+	}
+}
+
+/// Moves forward by whole networks, like [`Netv6Addr::checked_add`].
+///
+/// # Panics
+///
+/// Panics if the result would go past `ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff`.
+impl Add<u128> for Netv6Addr {
+	type Output = Self;
+
+	fn add(self, count: u128) -> Self {
+		self
+			.checked_add(count)
+			.expect("attempt to move network past the end of the address space")
+	}
+}
+
+/// Moves backward by whole networks, like [`Netv6Addr::checked_sub`].
+///
+/// # Panics
+///
+/// Panics if the result would go past `::`.
+impl Sub<u128> for Netv6Addr {
+	type Output = Self;
+
+	fn sub(self, count: u128) -> Self {
+		self
+			.checked_sub(count)
+			.expect("attempt to move network past the start of the address space")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use proptest::prelude::*;
+
+	#[test]
+	fn moves_by_network_sizes() {
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(net + 1, "2001:db9::/32".parse().unwrap());
+		assert_eq!(net - 0x2001_0db8, "::/32".parse().unwrap());
+		assert_eq!(net.checked_sub(0x2001_0db9), None);
+		assert_eq!(
+			net.checked_add(0xdffe_f247),
+			Some("ffff:ffff::/32".parse().unwrap())
+		);
+		assert_eq!(net.checked_add(0xdffe_f248), None);
+	}
+
+	#[test]
+	fn whole_space() {
+		let net: Netv6Addr = "::/0".parse().unwrap();
+
+		assert_eq!(net.checked_add(0), Some(net));
+		assert_eq!(net.checked_next(), None);
+		assert_eq!(net.checked_prev(), None);
+		assert_eq!(net.wrapping_add(1), net);
+	}
+
+	#[test]
+	fn wraps_around() {
+		let net: Netv6Addr = "ffff::/16".parse().unwrap();
+
+		assert_eq!(net.wrapping_add(1), "::/16".parse().unwrap());
+		assert_eq!(net.wrapping_add(1).wrapping_sub(1), net);
+	}
+
+	#[test]
+	fn non_contiguous_skips_host_bits() {
+		let net: Netv6Addr = "2001:0:ffff::/ffff:0:ffff::".parse().unwrap();
+
+		assert_eq!(
+			net.checked_next(),
+			Some("2002::/ffff:0:ffff::".parse().unwrap())
+		);
+		assert_eq!(
+			"2002::/ffff:0:ffff::"
+				.parse::<Netv6Addr>()
+				.unwrap()
+				.checked_prev(),
+			Some(net)
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "attempt to move network past the end of the address space")]
+	fn add_panics_on_overflow() {
+		let _ = "ffff::/16".parse::<Netv6Addr>().unwrap() + 1;
+	}
+
+	#[test]
+	#[should_panic(expected = "attempt to move network past the start of the address space")]
+	fn sub_panics_on_overflow() {
+		let _ = "::/16".parse::<Netv6Addr>().unwrap() - 1;
+	}
+
+	proptest! {
+		#[test]
+		fn next_and_prev_are_inverses(net in strategy::netv6addr()) {
+			if let Some(next) = net.checked_next() {
+				prop_assert_eq!(next.checked_prev(), Some(net));
+				prop_assert!(next > net);
+				prop_assert_eq!(next.mask(), net.mask());
+			}
+		}
+
+		#[test]
+		fn wrapping_agrees_with_checked(net in strategy::netv6addr(), count: u128) {
+			if let Some(moved) = net.checked_add(count) {
+				prop_assert_eq!(net.wrapping_add(count), moved);
+				prop_assert!(count == 0 || moved.is_disjoint(&net));
+			}
+
+			prop_assert_eq!(net.wrapping_add(count).wrapping_sub(count), net);
+		}
+	}
+}
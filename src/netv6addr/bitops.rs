@@ -0,0 +1,98 @@
+use super::Netv6Addr;
+use crate::traits::Mask;
+use core::net::Ipv6Addr;
+use core::ops::{BitAnd, BitOr, Not};
+
+/// Masks the address with the network's netmask, giving its network part.
+///
+/// This is the same as [`Mask::mask`]ing the address with the netmask.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv6Addr;
+/// # use std::net::Ipv6Addr;
+/// let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+/// assert_eq!(net & "2001:db9::7".parse::<Ipv6Addr>().unwrap(), "2001:db9::".parse::<Ipv6Addr>().unwrap());
+/// ```
+impl BitAnd<Ipv6Addr> for Netv6Addr {
+	type Output = Ipv6Addr;
+
+	fn bitand(self, addr: Ipv6Addr) -> Ipv6Addr {
+		addr.mask(&self.mask())
+	}
+}
+
+/// Combines the network's address with the host bits of the address, giving
+/// the address of that host within the network.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv6Addr;
+/// # use std::net::Ipv6Addr;
+/// let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+/// assert_eq!(net | Ipv6Addr::from(5), "2001:db8::5".parse::<Ipv6Addr>().unwrap());
+/// assert_eq!(net | "fe80::1:7".parse::<Ipv6Addr>().unwrap(), "2001:db8::1:7".parse::<Ipv6Addr>().unwrap());
+/// ```
+impl BitOr<Ipv6Addr> for Netv6Addr {
+	type Output = Ipv6Addr;
+
+	fn bitor(self, addr: Ipv6Addr) -> Ipv6Addr {
+		self.addr() | addr.mask(&!self.mask())
+	}
+}
+
+/// Gives the hostmask of the network, which is the inverse of its netmask.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Netv6Addr;
+/// # use std::net::Ipv6Addr;
+/// let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+/// assert_eq!(!net, "::ffff:ffff:ffff:ffff:ffff:ffff".parse::<Ipv6Addr>().unwrap());
+/// ```
+impl Not for Netv6Addr {
+	type Output = Ipv6Addr;
+
+	fn not(self) -> Ipv6Addr {
+		!self.mask()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{strategy, Contains};
+	use proptest::prelude::*;
+
+	#[test]
+	fn non_contiguous() {
+		let net: Netv6Addr = "2001:0:1::/ffff:0:ffff::".parse().unwrap();
+		let addr: Ipv6Addr = "fe80:1:2::3".parse().unwrap();
+
+		assert_eq!(net & addr, "fe80:0:2::".parse::<Ipv6Addr>().unwrap());
+		assert_eq!(net | addr, "2001:1:1::3".parse::<Ipv6Addr>().unwrap());
+		assert_eq!(
+			!net,
+			"0:ffff:0:ffff:ffff:ffff:ffff:ffff"
+				.parse::<Ipv6Addr>()
+				.unwrap()
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn host_is_contained(net in strategy::netv6addr(), addr: Ipv6Addr) {
+			prop_assert!(net.contains(&(net | addr)));
+			prop_assert_eq!((net | addr) & !net, addr & !net);
+		}
+
+		#[test]
+		fn network_part_is_masked(net in strategy::netv6addr(), addr: Ipv6Addr) {
+			prop_assert_eq!(net & addr, addr & net.mask());
+			prop_assert_eq!(net & addr & !net, Ipv6Addr::UNSPECIFIED);
+		}
+	}
+}
@@ -2,17 +2,17 @@ use super::Netv6Addr;
 use crate::traits::Contains;
 use crate::traits::Mask;
 
-impl Contains<std::net::IpAddr> for Netv6Addr {
-	fn contains(&self, other: &std::net::IpAddr) -> bool {
+impl Contains<core::net::IpAddr> for Netv6Addr {
+	fn contains(&self, other: &core::net::IpAddr) -> bool {
 		match other {
-			std::net::IpAddr::V6(other) => self.contains(other),
+			core::net::IpAddr::V6(other) => self.contains(other),
 			_ => false,
 		}
 	}
 }
 
-impl Contains<std::net::Ipv6Addr> for Netv6Addr {
-	fn contains(&self, other: &std::net::Ipv6Addr) -> bool {
+impl Contains<core::net::Ipv6Addr> for Netv6Addr {
+	fn contains(&self, other: &core::net::Ipv6Addr) -> bool {
 		other.mask(&self.mask()) == self.addr()
 	}
 }
@@ -28,14 +28,14 @@ impl Contains<crate::NetAddr> for Netv6Addr {
 
 impl Contains<Netv6Addr> for Netv6Addr {
 	fn contains(&self, other: &Netv6Addr) -> bool {
-		other.addr().mask(&self.mask()) == self.addr()
+		other.addr().mask(&self.mask()) == self.addr() && other.mask().mask(&self.mask()) == self.mask()
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::net::Ipv6Addr;
+	use core::net::Ipv6Addr;
 
 	#[test]
 	fn ip() {
@@ -53,4 +53,45 @@ mod tests {
 		let net_inner: Netv6Addr = "2001:db8:d00b::/48".parse().unwrap();
 		assert!(net.contains(&net_inner));
 	}
+
+	#[test]
+	fn supernet() {
+		let net: Netv6Addr = "2001:db8::/33".parse().unwrap();
+		let net_outer: Netv6Addr = "2001:db8::/32".parse().unwrap();
+		assert!(!net.contains(&net_outer));
+	}
+
+	mod properties {
+		use crate::strategy::{cidr_netv6addr, contained_ipv6addr, contained_netv6addr, netv6addr};
+		use crate::Contains;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn contains_itself(net in netv6addr()) {
+				prop_assert!(net.contains(&net));
+				prop_assert!(net.contains(&net.addr()));
+			}
+
+			#[test]
+			fn contains_contained_addresses(
+				(net, addr) in netv6addr().prop_flat_map(|net| (Just(net), contained_ipv6addr(net)))
+			) {
+				prop_assert!(net.contains(&addr));
+			}
+
+			#[test]
+			fn contains_contained_networks(
+				(net, inner) in netv6addr().prop_flat_map(|net| (Just(net), contained_netv6addr(net)))
+			) {
+				prop_assert!(net.contains(&inner));
+				prop_assert!(net.contains(&inner.addr()));
+			}
+
+			#[test]
+			fn cidr_mutual_containment_is_equality(a in cidr_netv6addr(), b in cidr_netv6addr()) {
+				prop_assert_eq!(a.contains(&b) && b.contains(&a), a == b);
+			}
+		}
+	}
 }
@@ -1,6 +1,25 @@
 use serde::{de, Deserialize, Deserializer};
 
 use super::Netv6Addr;
+use crate::serde::Rhs;
+use core::net::Ipv6Addr;
+
+/// Build a network from its address and, if given, its prefix length or mask.
+#[cfg(feature = "serde")]
+fn from_parts<E: de::Error>(addr: Ipv6Addr, rhs: Option<Rhs<Ipv6Addr>>) -> Result<Netv6Addr, E> {
+	match rhs {
+		Some(Rhs::Prefix(prefix)) if prefix > 128 => Err(de::Error::invalid_value(
+			de::Unexpected::Unsigned(prefix.into()),
+			&"a prefix length of at most 128",
+		)),
+		Some(Rhs::Prefix(prefix)) => {
+			let mask: u128 = u128::MAX ^ u128::MAX.checked_shr(prefix).unwrap_or(0_u128);
+			Ok(Netv6Addr::new(addr, mask.into()))
+		}
+		Some(Rhs::Mask(mask)) => Ok(Netv6Addr::new(addr, mask)),
+		None => Ok(Netv6Addr::from(addr)),
+	}
+}
 
 #[cfg(feature = "serde")]
 struct Netv6AddrVisitor;
@@ -16,25 +35,181 @@ impl<'de> de::Visitor<'de> for Netv6AddrVisitor {
 	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
 		use core::str::FromStr;
 		Self::Value::from_str(value)
+			.or_else(|_| Ipv6Addr::from_str(value).map(Self::Value::from))
 			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
 	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		self.visit_u128(value.into())
+	}
+
+	fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+		Ok(Self::Value::from(Ipv6Addr::from(value)))
+	}
+
+	fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_map(map)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+		let (addr, rhs) = crate::serde::visit_seq(seq, &self)?;
+		from_parts(addr, rhs)
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		Self::Value::parse_ascii(value)
+			.ok()
+			.or_else(|| crate::parser::ipv6addr(value).map(Self::Value::from))
+			.ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(value), &self))
+	}
+
+	fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+		use de::VariantAccess;
+
+		match data.variant()? {
+			(Form::Cidr, variant) => variant.tuple_variant(2, CidrVisitor),
+			(Form::Extended, variant) => variant.tuple_variant(2, ExtendedVisitor),
+			(Form::Network(net), variant) => variant.unit_variant().map(|()| net),
+		}
+	}
+}
+
+/// The variants of the compact representation.
+#[cfg(feature = "serde")]
+enum Form {
+	Cidr,
+	Extended,
+	/// A network in string form, as written by versions before the compact
+	/// representation, which is accepted in place of a variant.
+	Network(Netv6Addr),
+}
+
+#[cfg(feature = "serde")]
+const FORMS: &[&str] = &["Cidr", "Extended"];
+
+#[cfg(feature = "serde")]
+struct FormVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for FormVisitor {
+	type Value = Form;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("`Cidr`, `Extended`, or a network")
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		match value {
+			0 => Ok(Form::Cidr),
+			1 => Ok(Form::Extended),
+			_ => Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(value),
+				&"variant index 0 <= i < 2",
+			)),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match value {
+			"Cidr" => Ok(Form::Cidr),
+			"Extended" => Ok(Form::Extended),
+			_ => de::Visitor::visit_str(Netv6AddrVisitor, value).map(Form::Network),
+		}
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		match value {
+			b"Cidr" => Ok(Form::Cidr),
+			b"Extended" => Ok(Form::Extended),
+			_ => de::Visitor::visit_bytes(Netv6AddrVisitor, value).map(Form::Network),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Form {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(FormVisitor)
+	}
+}
+
+/// Visits the address octets and prefix length of the `Cidr` variant.
+#[cfg(feature = "serde")]
+struct CidrVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for CidrVisitor {
+	type Value = Netv6Addr;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("an address and prefix length")
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let addr: [u8; 16] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let prefix: u8 = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+		if prefix > 128 {
+			return Err(de::Error::invalid_value(
+				de::Unexpected::Unsigned(prefix.into()),
+				&"a prefix length of at most 128",
+			));
+		}
+
+		let mask: u128 = u128::MAX ^ u128::MAX.checked_shr(prefix.into()).unwrap_or(0_u128);
+
+		Ok(Netv6Addr::new(addr.into(), mask.into()))
+	}
+}
+
+/// Visits the address and mask octets of the `Extended` variant.
+#[cfg(feature = "serde")]
+struct ExtendedVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for ExtendedVisitor {
+	type Value = Netv6Addr;
+
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("an address and mask")
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let addr: [u8; 16] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let mask: [u8; 16] = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+		Ok(Netv6Addr::new(addr.into(), mask.into()))
+	}
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Netv6Addr {
 	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		deserializer.deserialize_str(Netv6AddrVisitor)
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(Netv6AddrVisitor)
+		} else {
+			deserializer.deserialize_enum("Netv6Addr", FORMS, Netv6AddrVisitor)
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::Netv6Addr;
-	use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+	use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Readable, Token};
 
 	#[test]
 	fn malformed_produces_correct_error() {
-		assert_de_tokens_error::<Netv6Addr>(
+		assert_de_tokens_error::<Readable<Netv6Addr>>(
 			&[Token::Str("asdf")],
 			"invalid value: string \"asdf\", expected a valid cidr/extended network address",
 		)
@@ -44,7 +219,7 @@ mod tests {
 	fn test_de_cidr_localhost() {
 		let netaddr: Netv6Addr = "ff02::1/60".parse().unwrap();
 
-		assert_de_tokens(&netaddr, &[Token::Str("ff02::/60")]);
+		assert_de_tokens(&netaddr.readable(), &[Token::Str("ff02::/60")]);
 	}
 
 	#[test]
@@ -52,8 +227,228 @@ mod tests {
 		let netaddr: Netv6Addr = "ff02:dead:beef::1/ff02:eeee:eeee::1".parse().unwrap();
 
 		assert_de_tokens(
-			&netaddr,
+			&netaddr.readable(),
 			&[Token::Str("ff02:ceac:aeee::1/ff02:eeee:eeee::1")],
 		);
 	}
+
+	#[test]
+	fn test_de_bare_address() {
+		let netaddr: Netv6Addr = "2001:db8::1/128".parse().unwrap();
+
+		assert_de_tokens(&netaddr.readable(), &[Token::Str("2001:db8::1")]);
+		assert_de_tokens(
+			&"::1/128".parse::<Netv6Addr>().unwrap().readable(),
+			&[Token::U64(1)],
+		);
+	}
+
+	#[test]
+	fn test_de_map_prefix() {
+		let netaddr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("2001:db8::1"),
+				Token::Str("prefix"),
+				Token::U8(32),
+				Token::MapEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_map_mask() {
+		let netaddr: Netv6Addr = "ff02:ceac:aeee::1/ff02:eeee:eeee::1".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("addr"),
+				Token::Str("ff02:dead:beef::1"),
+				Token::Str("mask"),
+				Token::Str("ff02:eeee:eeee::1"),
+				Token::MapEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_map_prefix_too_long() {
+		assert_de_tokens_error::<Readable<Netv6Addr>>(
+			&[
+				Token::Map { len: Some(2) },
+				Token::Str("address"),
+				Token::Str("2001:db8::"),
+				Token::Str("prefix"),
+				Token::U8(129),
+				Token::MapEnd,
+			],
+			"invalid value: integer `129`, expected a prefix length of at most 128",
+		);
+	}
+
+	#[test]
+	fn test_de_seq() {
+		let netaddr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.readable(),
+			&[
+				Token::Seq { len: Some(2) },
+				Token::Str("2001:db8::"),
+				Token::U8(32),
+				Token::SeqEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_json_forms() {
+		let netaddr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		for json in &[
+			r#""2001:db8::/32""#,
+			r#"{ "address": "2001:db8::", "prefix": 32 }"#,
+			r#"{ "addr": "2001:db8::", "mask": "ffff:ffff::" }"#,
+			r#"["2001:db8::", 32]"#,
+		] {
+			assert_eq!(serde_json::from_str::<Netv6Addr>(json).unwrap(), netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string() {
+		let netaddr: Netv6Addr = "ff02::1/60".parse().unwrap();
+
+		assert_de_tokens(&netaddr.compact(), &[Token::Str("ff02::/60")]);
+		assert_de_tokens(&netaddr.compact(), &[Token::Bytes(b"ff02::/60")]);
+	}
+
+	#[test]
+	fn test_de_compact_accepts_string_from_msgpack() {
+		for string in &["2001:db8::/32", "2001:db8::/ffff::ffff"] {
+			let netaddr: Netv6Addr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(string).unwrap();
+
+			assert_eq!(
+				rmp_serde::from_slice::<Netv6Addr>(&encoded).unwrap(),
+				netaddr
+			);
+		}
+
+		let encoded: Vec<u8> = rmp_serde::to_vec("not a network").unwrap();
+		assert!(rmp_serde::from_slice::<Netv6Addr>(&encoded).is_err());
+	}
+
+	#[test]
+	fn test_de_compact_round_trips_through_msgpack() {
+		for string in &["2001:db8::/32", "2001:db8::/ffff::ffff"] {
+			let netaddr: Netv6Addr = string.parse().unwrap();
+			let encoded: Vec<u8> = rmp_serde::to_vec(&netaddr).unwrap();
+
+			assert_eq!(
+				rmp_serde::from_slice::<Netv6Addr>(&encoded).unwrap(),
+				netaddr
+			);
+		}
+	}
+
+	#[test]
+	fn test_de_compact_masks_address() {
+		let netaddr: Netv6Addr = "ff02::/60".parse().unwrap();
+
+		assert_de_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv6Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 16 },
+				Token::U8(255),
+				Token::U8(2),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(1),
+				Token::TupleEnd,
+				Token::U8(60),
+				Token::TupleVariantEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_de_compact_prefix_too_long() {
+		assert_de_tokens_error::<Compact<Netv6Addr>>(
+			&[
+				Token::TupleVariant {
+					name: "Netv6Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 16 },
+				Token::U8(255),
+				Token::U8(2),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::TupleEnd,
+				Token::U8(129),
+				Token::TupleVariantEnd,
+			],
+			"invalid value: integer `129`, expected a prefix length of at most 128",
+		);
+	}
+
+	#[test]
+	fn test_de_bincode_round_trip() {
+		for net in &[
+			"::/0",
+			"2001:db8::/32",
+			"ff02:dead:beef::1/ff02:eeee:eeee::1",
+		] {
+			let netaddr: Netv6Addr = net.parse().unwrap();
+			let bytes = bincode::serialize(&netaddr).unwrap();
+
+			assert_eq!(bincode::deserialize::<Netv6Addr>(&bytes).unwrap(), netaddr);
+		}
+	}
+
+	#[test]
+	fn test_de_bincode_layout() {
+		let netaddr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			bincode::serialize(&netaddr).unwrap(),
+			[0, 0, 0, 0, 32, 1, 13, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32]
+		);
+	}
 }
@@ -1,17 +1,50 @@
 use super::Netv6Addr;
+use crate::{MaskNotation, NetFormat, Notation};
 use core::fmt;
 
+impl Netv6Addr {
+	/// Start writing this network in a notation other than the usual one.
+	///
+	/// See [`NetFormat`] for the notations available.
+	pub fn format(&self) -> NetFormat {
+		NetFormat::new(*self)
+	}
+}
+
 impl fmt::Display for Netv6Addr {
+	/// Write the network in the usual notation, with a prefix length where the
+	/// netmask is contiguous, or with the alternate flag (`{:#}`) as the address
+	/// and netmask separated by a space.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let mask: u128 = self.mask().into();
-		let ones = mask.count_ones();
-		let cidr_mask: u128 = u128::max_value().checked_shl(128 - ones).unwrap_or(0);
-
-		if mask == cidr_mask {
-			write!(f, "{}/{}", self.addr(), ones)
-		} else {
-			write!(f, "{}/{}", self.addr(), self.mask())
+		if f.alternate() {
+			return fmt::Display::fmt(&self.format().mask(MaskNotation::Netmask), f);
 		}
+
+		match self.netmask().prefix_len() {
+			Some(len) => write!(f, "{}/{}", self.addr(), len),
+			None => write!(f, "{}/{}", self.addr(), self.mask()),
+		}
+	}
+}
+
+impl fmt::LowerHex for Netv6Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::LowerHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::UpperHex for Netv6Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::UpperHex);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
+	}
+}
+
+impl fmt::Binary for Netv6Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let format = self.format().notation(Notation::Binary);
+		fmt::Display::fmt(&format.radix_prefix(f.alternate()), f)
 	}
 }
 
@@ -50,4 +83,23 @@ mod tests {
 			"2001:db8:dead:bccf::/ffff:ffff:ffff:fddf::"
 		)
 	}
+
+	#[test]
+	fn alternate_has_netmask() {
+		let addr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+		assert_eq!(format!("{:#}", addr), "2001:db8:: ffff:ffff::");
+	}
+
+	#[test]
+	fn radixes() {
+		let addr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+		assert_eq!(
+			format!("{:#x}", addr),
+			"0x20010db8000000000000000000000000/32"
+		);
+		assert_eq!(
+			format!("{:b}", "8000::/1".parse::<Netv6Addr>().unwrap()),
+			format!("1/{}", "0".repeat(127))
+		);
+	}
 }
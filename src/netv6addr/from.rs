@@ -1,2 +1,9 @@
 use super::Netv6Addr;
 mod ipv6addr;
+
+#[cfg(feature = "cidr")]
+mod cidr;
+#[cfg(feature = "ipnet")]
+mod ipnet;
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork;
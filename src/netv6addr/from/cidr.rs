@@ -0,0 +1,59 @@
+use super::Netv6Addr;
+use crate::Error;
+use cidr::Ipv6Cidr;
+use core::convert::TryFrom;
+
+/// Converts the network, keeping its address and mask.
+impl From<Ipv6Cidr> for Netv6Addr {
+	fn from(net: Ipv6Cidr) -> Self {
+		Self::new(net.first_address(), net.mask())
+	}
+}
+
+/// Converts the network, failing with [`Error::NonContiguousMask`] if its
+/// mask is not contiguous.
+impl TryFrom<Netv6Addr> for Ipv6Cidr {
+	type Error = Error;
+
+	fn try_from(net: Netv6Addr) -> Result<Self, Self::Error> {
+		if !net.is_cidr() {
+			return Err(Error::NonContiguousMask);
+		}
+
+		let prefix = u128::from(net.mask()).count_ones() as u8;
+
+		Ok(Self::new(net.addr(), prefix).expect("network has no host bits set"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_cidr() {
+		let net: Ipv6Cidr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			Netv6Addr::from(net),
+			"2001:db8::/32".parse::<Netv6Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn try_from_cidr() {
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			Ipv6Cidr::try_from(net),
+			Ok("2001:db8::/32".parse().unwrap())
+		);
+	}
+
+	#[test]
+	fn try_from_non_cidr_fails() {
+		let net: Netv6Addr = "2001:db8::/ffff::ffff".parse().unwrap();
+
+		assert_eq!(Ipv6Cidr::try_from(net), Err(Error::NonContiguousMask));
+	}
+}
@@ -0,0 +1,70 @@
+use super::Netv6Addr;
+use crate::Error;
+use core::convert::TryFrom;
+use ipnetwork::Ipv6Network;
+
+/// Converts the network, discarding any bits of its address outside of its
+/// prefix.
+impl From<Ipv6Network> for Netv6Addr {
+	fn from(net: Ipv6Network) -> Self {
+		Self::new(net.ip(), net.mask())
+	}
+}
+
+/// Converts the network, failing with [`Error::NonContiguousMask`] if its
+/// mask is not contiguous.
+impl TryFrom<Netv6Addr> for Ipv6Network {
+	type Error = Error;
+
+	fn try_from(net: Netv6Addr) -> Result<Self, Self::Error> {
+		if !net.is_cidr() {
+			return Err(Error::NonContiguousMask);
+		}
+
+		let prefix = u128::from(net.mask()).count_ones() as u8;
+
+		Ok(Self::new(net.addr(), prefix).expect("prefix length is valid"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_cidr() {
+		let net: Ipv6Network = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			Netv6Addr::from(net),
+			"2001:db8::/32".parse::<Netv6Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn from_discards_host_bits() {
+		let net: Ipv6Network = "2001:db8::77/32".parse().unwrap();
+
+		assert_eq!(
+			Netv6Addr::from(net),
+			"2001:db8::/32".parse::<Netv6Addr>().unwrap()
+		);
+	}
+
+	#[test]
+	fn try_from_cidr() {
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			Ipv6Network::try_from(net),
+			Ok("2001:db8::/32".parse().unwrap())
+		);
+	}
+
+	#[test]
+	fn try_from_non_cidr_fails() {
+		let net: Netv6Addr = "2001:db8::/ffff::ffff".parse().unwrap();
+
+		assert_eq!(Ipv6Network::try_from(net), Err(Error::NonContiguousMask));
+	}
+}
@@ -1,7 +1,26 @@
 use super::Netv6Addr;
 use crate::{Error, Result};
 use core::str::FromStr;
-use std::net::Ipv6Addr;
+
+impl Netv6Addr {
+	/// Parse a `Netv6Addr` from a byte string.
+	///
+	/// This accepts exactly the same formats as the [`FromStr`] implementation,
+	/// but skips UTF-8 validation, which is useful when reading networks out of
+	/// binary data or large text dumps.  Parsing is done in a single pass
+	/// without allocating.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// let parsed: Netv6Addr = Netv6Addr::parse_ascii(b"2001:db8::/32").unwrap();
+	/// assert_eq!(parsed, "2001:db8::/32".parse().unwrap());
+	/// ```
+	pub fn parse_ascii(bytes: &[u8]) -> Result<Self> {
+		crate::parser::netv6addr(bytes)
+	}
+}
 
 impl FromStr for Netv6Addr {
 	type Err = Error;
@@ -39,29 +58,7 @@ impl FromStr for Netv6Addr {
 	/// assert_eq!(parsed, Netv6Addr::new(addr, mask))
 	/// ```
 	fn from_str(string: &str) -> Result<Self> {
-		let split: Vec<&str> = string.split(|c| c == '/' || c == ' ').collect();
-
-		let lhs: &str = split[0];
-		let rhs: &str = split
-			.get(1)
-			.ok_or_else(|| Error::ParseError("could not split provided input".to_string()))?;
-
-		let address = lhs.parse::<Ipv6Addr>();
-		let cidr = rhs.parse::<u32>();
-		let right_addr = rhs.parse::<Ipv6Addr>();
-
-		match (address, cidr, right_addr) {
-			(Ok(addr), Ok(cidr), _) => {
-				let mask: u128 = u128::max_value() ^ u128::max_value().checked_shr(cidr).unwrap_or(0_u128);
-
-				let mask: Ipv6Addr = mask.into();
-
-				Ok(Self::new(addr, mask))
-			}
-			(Ok(addr), Err(_), Ok(mask)) => Ok(Self::new(addr, mask)),
-			(Ok(addr), Err(_), Err(_)) => Ok(Self::from(addr)),
-			(Err(e), _, _) => Err(e.into()),
-		}
+		Self::parse_ascii(string.as_bytes())
 	}
 }
 
@@ -85,9 +82,29 @@ mod tests {
 		let result = "zoop".parse::<Netv6Addr>();
 		assert_eq!(
 			result,
-			Err(Error::ParseError(
-				"could not split provided input".to_string()
-			))
+			Err(Error::ParseError("could not split provided input"))
 		);
 	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::netv6addr;
+		use core::net::Ipv6Addr;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn display_round_trips(net in netv6addr()) {
+				prop_assert_eq!(net.to_string().parse::<Netv6Addr>(), Ok(net));
+			}
+
+			#[test]
+			fn hosts_round_trip(addr in any::<Ipv6Addr>()) {
+				let net: Netv6Addr = Netv6Addr::from(addr);
+
+				prop_assert_eq!(net.to_string().parse::<Netv6Addr>(), Ok(net));
+				prop_assert_eq!(format!("{}/128", addr).parse::<Netv6Addr>(), Ok(net));
+			}
+		}
+	}
 }
@@ -0,0 +1,153 @@
+use super::Netv6Addr;
+use crate::iter::host::Hosts;
+use crate::traits::{Addresses, IpNetwork};
+use core::net::Ipv6Addr;
+
+impl IpNetwork for Netv6Addr {
+	type Addr = Ipv6Addr;
+	type Bits = u128;
+
+	const BITS: u32 = 128;
+
+	fn new(addr: Ipv6Addr, mask: Ipv6Addr) -> Self {
+		Netv6Addr::new(addr, mask)
+	}
+
+	fn from_prefix_len(addr: Ipv6Addr, prefix_len: u32) -> Option<Self> {
+		if prefix_len > 128 {
+			return None;
+		}
+
+		let mask: u128 = u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0);
+		Some(Netv6Addr::new(addr, Ipv6Addr::from(mask)))
+	}
+
+	fn addr(&self) -> Ipv6Addr {
+		Netv6Addr::addr(self)
+	}
+
+	fn mask(&self) -> Ipv6Addr {
+		Netv6Addr::mask(self)
+	}
+
+	fn prefix_len(&self) -> Option<u32> {
+		if Netv6Addr::is_cidr(self) {
+			Some(u128::from(Netv6Addr::mask(self)).count_ones())
+		} else {
+			None
+		}
+	}
+
+	fn is_cidr(&self) -> bool {
+		Netv6Addr::is_cidr(self)
+	}
+
+	fn host_bits(&self) -> u32 {
+		Hosts::host_bits(self)
+	}
+
+	fn last_addr(&self) -> Ipv6Addr {
+		Netv6Addr::addr(self) | !Netv6Addr::mask(self)
+	}
+
+	fn len(&self) -> Option<u128> {
+		Netv6Addr::len(*self)
+	}
+
+	fn addresses(&self) -> Addresses<Self> {
+		Addresses::new(*self, Hosts::host_bits(self))
+	}
+
+	fn checked_add(self, count: u128) -> Option<Self> {
+		Netv6Addr::checked_add(self, count)
+	}
+
+	fn checked_sub(self, count: u128) -> Option<Self> {
+		Netv6Addr::checked_sub(self, count)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn prefix_len() {
+		assert_eq!(
+			<Netv6Addr as IpNetwork>::from_prefix_len(pu!("2001:db8::1" # Ipv6Addr), 32),
+			Some(pu!("2001:db8::/32" # Netv6Addr))
+		);
+		assert_eq!(
+			<Netv6Addr as IpNetwork>::from_prefix_len(pu!("::1" # Ipv6Addr), 128),
+			Some(pu!("::1/128" # Netv6Addr))
+		);
+		assert_eq!(
+			<Netv6Addr as IpNetwork>::from_prefix_len(pu!("::" # Ipv6Addr), 129),
+			None
+		);
+		assert_eq!(
+			IpNetwork::prefix_len(&pu!("2001:db8::/32" # Netv6Addr)),
+			Some(32)
+		);
+		assert_eq!(
+			IpNetwork::prefix_len(&pu!("2001:db8::/ffff:0:ffff::" # Netv6Addr)),
+			None
+		);
+	}
+
+	#[test]
+	fn accessors() {
+		let net = pu!("2001:db8::/96" # Netv6Addr);
+
+		assert_eq!(IpNetwork::addr(&net), pu!("2001:db8::" # Ipv6Addr));
+		assert_eq!(
+			IpNetwork::mask(&net),
+			pu!("ffff:ffff:ffff:ffff:ffff:ffff::" # Ipv6Addr)
+		);
+		assert_eq!(IpNetwork::host_bits(&net), 32);
+		assert_eq!(IpNetwork::len(&net), Some(1 << 32));
+		assert_eq!(
+			IpNetwork::last_addr(&net),
+			pu!("2001:db8::ffff:ffff" # Ipv6Addr)
+		);
+		assert_eq!(IpNetwork::len(&"::/0".parse::<Netv6Addr>().unwrap()), None);
+	}
+
+	#[test]
+	fn addresses_of_whole_space() {
+		let net = pu!("::/0" # Netv6Addr);
+		let mut addrs = net.addresses();
+
+		assert_eq!(addrs.next(), Some(pu!("::" # Ipv6Addr)));
+		assert_eq!(addrs.next(), Some(pu!("::1" # Ipv6Addr)));
+		assert_eq!(
+			addrs.next_back(),
+			Some(pu!("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff" # Ipv6Addr))
+		);
+	}
+
+	#[test]
+	fn addresses_cover_non_contiguous_masks() {
+		let net = pu!("2001:db8::/ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe" # Netv6Addr);
+		let addrs: Vec<Ipv6Addr> = net.addresses().collect();
+
+		assert_eq!(
+			addrs,
+			[pu!("2001:db8::" # Ipv6Addr), pu!("2001:db8::1" # Ipv6Addr)]
+		);
+	}
+
+	#[test]
+	fn steps() {
+		let net = pu!("2001:db8::/32" # Netv6Addr);
+
+		assert_eq!(
+			IpNetwork::checked_next(net),
+			Some(pu!("2001:db9::/32" # Netv6Addr))
+		);
+		assert_eq!(
+			IpNetwork::checked_prev(net),
+			Some(pu!("2001:db7::/32" # Netv6Addr))
+		);
+	}
+}
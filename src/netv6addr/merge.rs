@@ -1,7 +1,7 @@
 use super::Netv6Addr;
 use crate::traits::Merge;
 use core::cmp::Ordering;
-use std::net::Ipv6Addr;
+use core::net::Ipv6Addr;
 
 impl Merge for Netv6Addr {
 	type Output = Option<Self>;
@@ -74,4 +74,49 @@ mod tests {
 		assert_eq!(b.merge(&a), None);
 		assert_eq!(a.merge(&b), b.merge(&a));
 	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::cidr_netv6addr;
+		use crate::Contains;
+		use proptest::prelude::*;
+
+		/// Generate a CIDR network other than `/0` along with its sibling, which
+		/// differs from it only in the last bit of the prefix.
+		fn siblings() -> impl Strategy<Value = (Netv6Addr, Netv6Addr)> {
+			cidr_netv6addr()
+				.prop_filter("/0 has no sibling", |net| u128::from(net.mask()) != 0)
+				.prop_map(|net| {
+					let mask: u128 = net.mask().into();
+					let bit: u128 = mask & mask.wrapping_neg();
+					let sibling: Ipv6Addr = (u128::from(net.addr()) ^ bit).into();
+					(net, Netv6Addr::new(sibling, net.mask()))
+				})
+		}
+
+		proptest! {
+			#[test]
+			fn is_commutative(a in cidr_netv6addr(), b in cidr_netv6addr()) {
+				prop_assert_eq!(a.merge(&b), b.merge(&a));
+			}
+
+			#[test]
+			fn result_contains_both(a in cidr_netv6addr(), b in cidr_netv6addr()) {
+				if let Some(merged) = a.merge(&b) {
+					prop_assert!(merged.contains(&a));
+					prop_assert!(merged.contains(&b));
+				}
+			}
+
+			#[test]
+			fn siblings_merge_into_parent((a, b) in siblings()) {
+				let merged: Netv6Addr = a.merge(&b).unwrap();
+
+				prop_assert!(merged.is_cidr());
+				prop_assert_eq!(u128::from(merged.mask()).count_zeros(), u128::from(a.mask()).count_zeros() + 1);
+				prop_assert!(merged.contains(&a));
+				prop_assert!(merged.contains(&b));
+			}
+		}
+	}
 }
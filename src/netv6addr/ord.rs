@@ -46,4 +46,35 @@ mod tests {
 
 		assert_eq!(a.cmp(&b), Ordering::Equal)
 	}
+
+	mod properties {
+		use super::*;
+		use crate::strategy::netv6addr;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn is_antisymmetric(a in netv6addr(), b in netv6addr()) {
+				prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+			}
+
+			#[test]
+			fn is_consistent_with_eq(a in netv6addr(), b in netv6addr()) {
+				prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+				prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+			}
+
+			#[test]
+			fn is_consistent_with_partial_ord(a in netv6addr(), b in netv6addr()) {
+				prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+			}
+
+			#[test]
+			fn is_transitive(a in netv6addr(), b in netv6addr(), c in netv6addr()) {
+				let mut sorted = [a, b, c];
+				sorted.sort();
+				prop_assert!(sorted[0] <= sorted[1] && sorted[1] <= sorted[2] && sorted[0] <= sorted[2]);
+			}
+		}
+	}
 }
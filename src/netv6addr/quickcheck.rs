@@ -0,0 +1,60 @@
+use super::Netv6Addr;
+use core::net::Ipv6Addr;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generate `Netv6Addr`s for `quickcheck`.
+///
+/// Half of the generated networks are CIDR, since a uniformly random netmask is
+/// almost never CIDR.  Shrinking shrinks the address and netmask as integers.
+impl Arbitrary for Netv6Addr {
+	fn arbitrary(g: &mut Gen) -> Self {
+		let addr: Ipv6Addr = Ipv6Addr::arbitrary(g);
+
+		let mask: u128 = if bool::arbitrary(g) {
+			u128::MAX
+				.checked_shl(128 - u32::arbitrary(g) % 129)
+				.unwrap_or(0)
+		} else {
+			u128::arbitrary(g)
+		};
+
+		Self::new(addr, mask.into())
+	}
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		let parts: (u128, u128) = (self.addr().into(), self.mask().into());
+
+		Box::new(
+			parts
+				.shrink()
+				.map(|(addr, mask)| Self::new(addr.into(), mask.into())),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use quickcheck::quickcheck;
+
+	quickcheck! {
+		fn addr_is_masked(net: Netv6Addr) -> bool {
+			u128::from(net.addr()) & !u128::from(net.mask()) == 0
+		}
+
+		fn shrinks_are_valid(net: Netv6Addr) -> bool {
+			net
+				.shrink()
+				.all(|net| u128::from(net.addr()) & !u128::from(net.mask()) == 0)
+		}
+	}
+
+	#[test]
+	fn produces_cidr_and_non_cidr() {
+		let mut g = Gen::new(100);
+		let nets: Vec<Netv6Addr> = (0..64).map(|_| Netv6Addr::arbitrary(&mut g)).collect();
+
+		assert!(nets.iter().any(Netv6Addr::is_cidr));
+		assert!(!nets.iter().all(Netv6Addr::is_cidr));
+	}
+}
@@ -0,0 +1,217 @@
+use super::Netv6Addr;
+use crate::iter::host::Hosts;
+use core::net::Ipv6Addr;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+impl Netv6Addr {
+	/// Pick an address from this network uniformly at random.
+	///
+	/// Every address contained in the network is equally likely, including the
+	/// all-zeroes and all-ones host addresses.  Non-CIDR netmasks are supported.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Contains, Netv6Addr};
+	/// let net: Netv6Addr = "2001:db8::/64".parse().unwrap();
+	/// let addr = net.random_addr(&mut rand::thread_rng());
+	/// assert!(net.contains(&addr));
+	/// ```
+	pub fn random_addr<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv6Addr {
+		self.host(rng.gen::<u128>())
+	}
+
+	/// Pick an address from this network uniformly at random, excluding the
+	/// addresses whose host bits are all zeroes (the Subnet-Router anycast
+	/// address) or all ones.
+	///
+	/// Returns `None` if the network has fewer than four addresses, since then
+	/// there is nothing left once those two are excluded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::Netv6Addr;
+	/// let net: Netv6Addr = "2001:db8::/126".parse().unwrap();
+	/// let addr = net.random_host(&mut rand::thread_rng()).unwrap();
+	/// assert_ne!(addr, net.addr());
+	///
+	/// let net: Netv6Addr = "2001:db8::/127".parse().unwrap();
+	/// assert_eq!(net.random_host(&mut rand::thread_rng()), None);
+	/// ```
+	pub fn random_host<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Ipv6Addr> {
+		let last: u128 = u128::MAX.checked_shr(128 - self.host_bits()).unwrap_or(0);
+
+		if last < 3 {
+			None
+		} else {
+			Some(self.host(rng.gen_range(1..last)))
+		}
+	}
+
+	/// Pick a subnet of this network with the given prefix length uniformly at
+	/// random.
+	///
+	/// Returns `None` if `prefix_len` is greater than 128, or if the netmask of
+	/// this network has bits set beyond `prefix_len` (in which case no subnet of
+	/// that length exists).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{Contains, Netv6Addr};
+	/// let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+	///
+	/// let subnet = net.random_subnet(64, &mut rand::thread_rng()).unwrap();
+	/// assert!(net.contains(&subnet));
+	/// assert_eq!(subnet.len(), Some(1 << 64));
+	///
+	/// assert_eq!(net.random_subnet(16, &mut rand::thread_rng()), None);
+	/// ```
+	pub fn random_subnet<R: Rng + ?Sized>(&self, prefix_len: u32, rng: &mut R) -> Option<Self> {
+		if prefix_len > 128 {
+			return None;
+		}
+
+		let mask: u128 = u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0);
+
+		if u128::from(self.mask()) & !mask == 0 {
+			Some(Self::new(self.random_addr(rng), Ipv6Addr::from(mask)))
+		} else {
+			None
+		}
+	}
+}
+
+/// A `Netv6Addr` is a uniform distribution over its contained addresses.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Contains, Netv6Addr};
+/// # use rand::Rng;
+/// let net: Netv6Addr = "2001:db8:dead:beef::/64".parse().unwrap();
+/// let addr: std::net::Ipv6Addr = rand::thread_rng().sample(net);
+/// assert!(net.contains(&addr));
+/// ```
+impl Distribution<Ipv6Addr> for Netv6Addr {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv6Addr {
+		self.random_addr(rng)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Contains;
+	use rand::rngs::StdRng;
+	use rand::SeedableRng;
+	use std::collections::HashSet;
+
+	fn rng() -> StdRng {
+		StdRng::seed_from_u64(0x5eed)
+	}
+
+	#[test]
+	fn random_addr_is_contained() {
+		let net: Netv6Addr = "2001:db8:dead:beef::/64".parse().unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			assert!(net.contains(&net.random_addr(&mut rng)));
+		}
+	}
+
+	#[test]
+	fn random_addr_covers_small_network() {
+		let net: Netv6Addr = "2001:db8::/125".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Ipv6Addr> = (0..1000).map(|_| net.random_addr(&mut rng)).collect();
+		assert_eq!(seen.len(), 8);
+	}
+
+	#[test]
+	fn random_addr_respects_non_cidr_mask() {
+		let net: Netv6Addr = "2001:db8::/ffff:ffff:ffff:ffff:0:ffff:ffff:ffff"
+			.parse()
+			.unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			let addr: Ipv6Addr = net.random_addr(&mut rng);
+			assert!(net.contains(&addr));
+			assert_eq!(addr.segments()[5..], [0, 0, 0]);
+		}
+	}
+
+	#[test]
+	fn random_addr_whole_space_does_not_panic() {
+		let net: Netv6Addr = "::/0".parse().unwrap();
+		let _ = net.random_addr(&mut rng());
+	}
+
+	#[test]
+	fn random_host_excludes_all_zeroes_and_all_ones() {
+		let net: Netv6Addr = "2001:db8::/126".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Ipv6Addr> = (0..1000)
+			.map(|_| net.random_host(&mut rng).unwrap())
+			.collect();
+
+		assert_eq!(seen.len(), 2);
+		assert!(!seen.contains(&"2001:db8::".parse().unwrap()));
+		assert!(!seen.contains(&"2001:db8::3".parse().unwrap()));
+	}
+
+	#[test]
+	fn random_host_too_small_returns_none() {
+		let net: Netv6Addr = "2001:db8::/127".parse().unwrap();
+		assert_eq!(net.random_host(&mut rng()), None);
+
+		let net: Netv6Addr = "2001:db8::/128".parse().unwrap();
+		assert_eq!(net.random_host(&mut rng()), None);
+	}
+
+	#[test]
+	fn random_subnet_is_contained_and_sized() {
+		let net: Netv6Addr = "2001:db8::/48".parse().unwrap();
+		let mut rng = rng();
+
+		for _ in 0..1000 {
+			let subnet: Netv6Addr = net.random_subnet(64, &mut rng).unwrap();
+			assert!(net.contains(&subnet));
+			assert_eq!(subnet.len(), Some(1 << 64));
+		}
+	}
+
+	#[test]
+	fn random_subnet_covers_all_subnets() {
+		let net: Netv6Addr = "2001:db8::/48".parse().unwrap();
+		let mut rng = rng();
+
+		let seen: HashSet<Netv6Addr> = (0..1000)
+			.map(|_| net.random_subnet(50, &mut rng).unwrap())
+			.collect();
+		assert_eq!(seen.len(), 4);
+	}
+
+	#[test]
+	fn random_subnet_shorter_prefix_returns_none() {
+		let net: Netv6Addr = "2001:db8::/48".parse().unwrap();
+		assert_eq!(net.random_subnet(47, &mut rng()), None);
+		assert_eq!(net.random_subnet(129, &mut rng()), None);
+	}
+
+	#[test]
+	fn distribution_samples_contained_addresses() {
+		let net: Netv6Addr = "fe80::/10".parse().unwrap();
+
+		assert!(net
+			.sample_iter(rng())
+			.take(1000)
+			.all(|addr: Ipv6Addr| net.contains(&addr)));
+	}
+}
@@ -0,0 +1,125 @@
+use super::Netv6Addr;
+use crate::relation::relation;
+use crate::NetRelation;
+
+impl Netv6Addr {
+	/// Determine how this network relates to `other`, as sets of addresses.
+	///
+	/// This is correct for non-contiguous netmasks, which are the only way for
+	/// two networks to be [`NetRelation::Overlapping`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{NetRelation, Netv6Addr};
+	/// let net: Netv6Addr = "2001:db8::/48".parse().unwrap();
+	///
+	/// assert_eq!(net.relation(&"2001:db8::/32".parse().unwrap()), NetRelation::ContainedBy);
+	/// assert_eq!(net.relation(&"2001:db8:1::/48".parse().unwrap()), NetRelation::Adjacent);
+	/// assert_eq!(net.relation(&"2001:db8:2::/48".parse().unwrap()), NetRelation::Disjoint);
+	/// ```
+	pub fn relation(&self, other: &Self) -> NetRelation {
+		relation(self.bits(), other.bits(), 128)
+	}
+
+	/// Determine whether this network shares any addresses with `other`.
+	pub fn overlaps(&self, other: &Self) -> bool {
+		!matches!(
+			self.relation(other),
+			NetRelation::Adjacent | NetRelation::Disjoint
+		)
+	}
+
+	/// Determine whether every address of this network is in `other`.
+	///
+	/// A network is a subnet of itself.
+	pub fn is_subnet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::ContainedBy
+		)
+	}
+
+	/// Determine whether every address of `other` is in this network.
+	///
+	/// A network is a supernet of itself.
+	pub fn is_supernet_of(&self, other: &Self) -> bool {
+		matches!(
+			self.relation(other),
+			NetRelation::Equal | NetRelation::Contains
+		)
+	}
+
+	/// Determine whether this network shares no addresses with `other`.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		!self.overlaps(other)
+	}
+
+	/// Determine whether this network shares no addresses with `other`, but an
+	/// address of one is immediately followed by an address of the other.
+	pub fn is_adjacent(&self, other: &Self) -> bool {
+		self.relation(other) == NetRelation::Adjacent
+	}
+
+	fn bits(&self) -> (u128, u128) {
+		(self.addr().into(), self.mask().into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use crate::Contains;
+	use proptest::prelude::*;
+
+	#[test]
+	fn predicates() {
+		let a: Netv6Addr = "2001:db8::/32".parse().unwrap();
+		let b: Netv6Addr = "2001:db8:1::/48".parse().unwrap();
+		let c: Netv6Addr = "2001:db9::/32".parse().unwrap();
+
+		assert!(a.overlaps(&b) && b.overlaps(&a));
+		assert!(b.is_subnet_of(&a) && !a.is_subnet_of(&b));
+		assert!(a.is_supernet_of(&b) && !b.is_supernet_of(&a));
+		assert!(a.is_disjoint(&c) && a.is_adjacent(&c));
+		assert!(!a.is_adjacent(&b));
+	}
+
+	#[test]
+	fn extremes_are_not_adjacent() {
+		let first: Netv6Addr = "::/1".parse().unwrap();
+		let last: Netv6Addr = "ffff::/16".parse().unwrap();
+
+		assert_eq!(first.relation(&last), NetRelation::Disjoint);
+		assert_eq!(
+			first.relation(&"8000::/1".parse().unwrap()),
+			NetRelation::Adjacent
+		);
+	}
+
+	#[test]
+	fn non_contiguous_overlap() {
+		let a: Netv6Addr = "2001:db8::/ffff:0:ffff::".parse().unwrap();
+		let b: Netv6Addr = "2001:db8::/ffff:ffff::".parse().unwrap();
+
+		assert_eq!(a.relation(&b), NetRelation::Overlapping);
+		assert!(a.overlaps(&b) && !a.is_subnet_of(&b) && !a.is_supernet_of(&b));
+	}
+
+	proptest! {
+		#[test]
+		fn agrees_with_contains(a in strategy::netv6addr(), b in strategy::netv6addr()) {
+			prop_assert_eq!(a.is_supernet_of(&b), a.contains(&b));
+			prop_assert_eq!(a.is_subnet_of(&b), b.contains(&a));
+		}
+
+		#[test]
+		fn contains_its_subnets(
+			(net, subnet) in strategy::netv6addr().prop_flat_map(|net| (Just(net), strategy::contained_netv6addr(net)))
+		) {
+			prop_assert!(net.is_supernet_of(&subnet));
+			prop_assert!(net.overlaps(&subnet));
+		}
+	}
+}
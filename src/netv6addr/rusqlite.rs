@@ -0,0 +1,103 @@
+use super::Netv6Addr;
+use core::convert::TryFrom;
+use core::net::Ipv6Addr;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+/// Stores the network as text, like `"2001:db8::/32"`.
+impl ToSql for Netv6Addr {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		Ok(ToSqlOutput::from(self.to_string()))
+	}
+}
+
+/// Reads the network from text, in any notation accepted by `parse_ascii` or
+/// as a bare address, or from a blob of the sixteen address octets followed by
+/// the sixteen mask octets.
+impl FromSql for Netv6Addr {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		match value {
+			ValueRef::Text(text) => Self::parse_ascii(text)
+				.or_else(|error| crate::parser::ipv6addr(text).map(Self::from).ok_or(error))
+				.map_err(|error| FromSqlError::Other(Box::new(error))),
+			ValueRef::Blob(blob) if blob.len() == 32 => {
+				let addr = <[u8; 16]>::try_from(&blob[..16]).expect("blob has 32 octets");
+				let mask = <[u8; 16]>::try_from(&blob[16..]).expect("blob has 32 octets");
+				Ok(Self::new(Ipv6Addr::from(addr), Ipv6Addr::from(mask)))
+			}
+			ValueRef::Blob(blob) => Err(FromSqlError::InvalidBlobSize {
+				expected_size: 32,
+				blob_size: blob.len(),
+			}),
+			_ => Err(FromSqlError::InvalidType),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rusqlite::Connection;
+
+	fn round_trip(net: &Netv6Addr) -> Netv6Addr {
+		let conn = Connection::open_in_memory().unwrap();
+		conn
+			.execute("CREATE TABLE nets (net TEXT NOT NULL)", [])
+			.unwrap();
+		conn
+			.execute("INSERT INTO nets (net) VALUES (?1)", [net])
+			.unwrap();
+		conn
+			.query_row("SELECT net FROM nets", [], |row| row.get(0))
+			.unwrap()
+	}
+
+	fn select(sql: &str) -> rusqlite::Result<Netv6Addr> {
+		let conn = Connection::open_in_memory().unwrap();
+		conn.query_row(sql, [], |row| row.get(0))
+	}
+
+	#[test]
+	fn round_trips_through_text() {
+		for net in &["2001:db8::/32", "2001:db8::/ffff::ffff"] {
+			let net: Netv6Addr = net.parse().unwrap();
+
+			assert_eq!(round_trip(&net), net);
+		}
+	}
+
+	#[test]
+	fn stores_text() {
+		let conn = Connection::open_in_memory().unwrap();
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+		let text: String = conn
+			.query_row("SELECT ?1", [net], |row| row.get(0))
+			.unwrap();
+
+		assert_eq!(text, "2001:db8::/32");
+	}
+
+	#[test]
+	fn reads_bare_address() {
+		let net: Netv6Addr = "2001:db8::1/128".parse().unwrap();
+
+		assert_eq!(select("SELECT '2001:db8::1'").unwrap(), net);
+	}
+
+	#[test]
+	fn reads_blob() {
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_eq!(
+			select("SELECT x'20010db8000000000000000000000000ffffffff000000000000000000000000'").unwrap(),
+			net
+		);
+	}
+
+	#[test]
+	fn rejects_other_values() {
+		assert!(select("SELECT 'not a network'").is_err());
+		assert!(select("SELECT x'0a000000'").is_err());
+		assert!(select("SELECT 42").is_err());
+		assert!(select("SELECT NULL").is_err());
+	}
+}
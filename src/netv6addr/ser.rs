@@ -1,24 +1,50 @@
 use super::Netv6Addr;
 
-use serde::{Serialize, Serializer};
+use serde::ser::{SerializeTupleVariant, Serializer};
+use serde::Serialize;
 
+/// Serializes the network as a string (e.g. `"2001:db8::/32"`) for
+/// human-readable formats.
+///
+/// For compact formats, the network is instead serialized as the `Cidr`
+/// variant holding the address octets and prefix length when its mask is
+/// contiguous, or as the `Extended` variant holding the address and mask
+/// octets otherwise.
 #[cfg(feature = "serde")]
 impl Serialize for Netv6Addr {
 	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		serializer.serialize_str(&self.to_string())
+		if serializer.is_human_readable() {
+			return serializer.collect_str(self);
+		}
+
+		let mask: u128 = self.mask().into();
+		let ones = mask.count_ones();
+		let cidr_mask: u128 = u128::MAX.checked_shl(128 - ones).unwrap_or(0);
+
+		if mask == cidr_mask {
+			let mut variant = serializer.serialize_tuple_variant("Netv6Addr", 0, "Cidr", 2)?;
+			variant.serialize_field(&self.addr().octets())?;
+			variant.serialize_field(&(ones as u8))?;
+			variant.end()
+		} else {
+			let mut variant = serializer.serialize_tuple_variant("Netv6Addr", 1, "Extended", 2)?;
+			variant.serialize_field(&self.addr().octets())?;
+			variant.serialize_field(&self.mask().octets())?;
+			variant.end()
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::Netv6Addr;
-	use serde_test::{assert_tokens, Token};
+	use serde_test::{assert_ser_tokens, assert_tokens, Configure, Token};
 
 	#[test]
 	fn test_ser_cidr_localhost() {
 		let netaddr: Netv6Addr = "ff02::1/60".parse().unwrap();
 
-		assert_tokens(&netaddr, &[Token::Str("ff02::/60")]);
+		assert_tokens(&netaddr.readable(), &[Token::Str("ff02::/60")]);
 	}
 
 	#[test]
@@ -26,8 +52,104 @@ mod tests {
 		let netaddr: Netv6Addr = "ff02:dead:beef::1/ff02:eeee:eeee::1".parse().unwrap();
 
 		assert_tokens(
-			&netaddr,
+			&netaddr.readable(),
 			&[Token::Str("ff02:ceac:aeee::1/ff02:eeee:eeee::1")],
 		);
 	}
+
+	#[test]
+	fn test_ser_readable_is_string() {
+		let netaddr: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert_ser_tokens(&netaddr.readable(), &[Token::Str("2001:db8::/32")]);
+	}
+
+	#[test]
+	fn test_ser_compact_cidr() {
+		let netaddr: Netv6Addr = "ff02::1/60".parse().unwrap();
+
+		assert_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv6Addr",
+					variant: "Cidr",
+					len: 2,
+				},
+				Token::Tuple { len: 16 },
+				Token::U8(255),
+				Token::U8(2),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::TupleEnd,
+				Token::U8(60),
+				Token::TupleVariantEnd,
+			],
+		);
+	}
+
+	#[test]
+	fn test_ser_compact_non_cidr() {
+		let netaddr: Netv6Addr = "ff02:dead:beef::1/ff02:eeee:eeee::1".parse().unwrap();
+
+		assert_tokens(
+			&netaddr.compact(),
+			&[
+				Token::TupleVariant {
+					name: "Netv6Addr",
+					variant: "Extended",
+					len: 2,
+				},
+				Token::Tuple { len: 16 },
+				Token::U8(255),
+				Token::U8(2),
+				Token::U8(206),
+				Token::U8(172),
+				Token::U8(174),
+				Token::U8(238),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(1),
+				Token::TupleEnd,
+				Token::Tuple { len: 16 },
+				Token::U8(255),
+				Token::U8(2),
+				Token::U8(238),
+				Token::U8(238),
+				Token::U8(238),
+				Token::U8(238),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(0),
+				Token::U8(1),
+				Token::TupleEnd,
+				Token::TupleVariantEnd,
+			],
+		);
+	}
 }
@@ -0,0 +1,82 @@
+use super::Netv6Addr;
+use crate::inet;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{
+	PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+impl Type<Postgres> for Netv6Addr {
+	fn type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_OID))
+	}
+
+	fn compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_OID))
+	}
+}
+
+impl PgHasArrayType for Netv6Addr {
+	fn array_type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+	}
+
+	fn array_compatible(ty: &PgTypeInfo) -> bool {
+		*ty == PgTypeInfo::with_oid(Oid(inet::INET_ARRAY_OID))
+			|| *ty == PgTypeInfo::with_oid(Oid(inet::CIDR_ARRAY_OID))
+	}
+}
+
+impl Encode<'_, Postgres> for Netv6Addr {
+	fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+		buf.extend_from_slice(&inet::encode(&(*self).into(), false)?);
+		Ok(IsNull::No)
+	}
+}
+
+impl<'r> Decode<'r, Postgres> for Netv6Addr {
+	fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+		let net = match value.format() {
+			PgValueFormat::Binary => inet::decode(value.as_bytes()?)?,
+			PgValueFormat::Text => inet::decode_text(value.as_str()?)?,
+		};
+
+		Ok(inet::v6(net)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encodes_captured_bytes() {
+		let mut buf = PgArgumentBuffer::default();
+		let net: Netv6Addr = "2001:db8::/32".parse().unwrap();
+
+		assert!(matches!(net.encode_by_ref(&mut buf), Ok(IsNull::No)));
+		assert_eq!(
+			&buf[..],
+			&[
+				0x03, 0x20, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0x00, 0x00, 0x00,
+			]
+		);
+	}
+
+	#[test]
+	fn compatible_with_inet_and_cidr() {
+		assert!(<Netv6Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(869))
+		));
+		assert!(<Netv6Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(650))
+		));
+		assert!(!<Netv6Addr as Type<Postgres>>::compatible(
+			&PgTypeInfo::with_oid(Oid(25))
+		));
+	}
+}
@@ -0,0 +1,522 @@
+//! A single-pass, allocation-free parser for networks and addresses.
+//!
+//! The address grammar mirrors that of the `core::net` `FromStr`
+//! implementations exactly, but works directly on bytes so that the whole
+//! network can be parsed in one pass without first validating UTF-8 or
+//! splitting into substrings.
+//!
+//! The grammar is written as `const fn`s so that the `net!`, `net4!`, and
+//! `net6!` macros can parse with it at compile time, through [`literal`].
+//! Since `const fn` cannot take closures, the cursor is threaded through by
+//! hand: each function takes the position to start at and gives back the
+//! position after what it read.
+
+use crate::{Error, NetAddr, Netv4Addr, Netv6Addr, PrefixLen, Result};
+use core::convert::TryFrom;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+pub(crate) mod literal;
+
+const fn digit(byte: u8, radix: u32) -> Option<u32> {
+	let value: u32 = match byte {
+		b'0'..=b'9' => (byte - b'0') as u32,
+		b'a'..=b'f' => (byte - b'a') as u32 + 10,
+		b'A'..=b'F' => (byte - b'A') as u32 + 10,
+		_ => return None,
+	};
+
+	if value < radix {
+		Some(value)
+	} else {
+		None
+	}
+}
+
+/// Read a number of at most `max_digits` digits whose value is at most `max`.
+const fn read_number(
+	bytes: &[u8],
+	start: usize,
+	radix: u32,
+	max_digits: usize,
+	max: u32,
+	allow_zero_prefix: bool,
+) -> Option<(u32, usize)> {
+	let mut pos: usize = start;
+	let mut result: u32 = 0;
+
+	while pos < bytes.len() {
+		match digit(bytes[pos], radix) {
+			Some(digit) => {
+				// Widened so that `result` can't overflow before it's checked.
+				let next: u64 = result as u64 * radix as u64 + digit as u64;
+				pos += 1;
+				if pos - start > max_digits || next > max as u64 {
+					return None;
+				}
+				result = next as u32;
+			}
+			None => break,
+		}
+	}
+
+	let digits: usize = pos - start;
+	if digits == 0 || (!allow_zero_prefix && bytes[start] == b'0' && digits > 1) {
+		None
+	} else {
+		Some((result, pos))
+	}
+}
+
+/// Skip over `separator` at `pos`, unless this is the first item.
+const fn read_separator(bytes: &[u8], pos: usize, separator: u8, index: usize) -> Option<usize> {
+	if index == 0 {
+		Some(pos)
+	} else if pos < bytes.len() && bytes[pos] == separator {
+		Some(pos + 1)
+	} else {
+		None
+	}
+}
+
+const fn read_ipv4(bytes: &[u8], start: usize) -> Option<(u32, usize)> {
+	let mut pos: usize = start;
+	let mut bits: u32 = 0;
+	let mut index: usize = 0;
+
+	while index < 4 {
+		pos = match read_separator(bytes, pos, b'.', index) {
+			Some(pos) => pos,
+			None => return None,
+		};
+		let (octet, next) = match read_number(bytes, pos, 10, 3, 255, false) {
+			Some(octet) => octet,
+			None => return None,
+		};
+
+		bits = (bits << 8) | octet;
+		pos = next;
+		index += 1;
+	}
+
+	Some((bits, pos))
+}
+
+/// Read up to `limit` colon-separated groups into `groups`, the last two of
+/// which may be given as an embedded IPv4 address.
+///
+/// Returns the number of groups read, whether an IPv4 address was read, and
+/// the position after the last group.
+const fn read_groups(
+	bytes: &[u8],
+	start: usize,
+	groups: &mut [u16; 8],
+	limit: usize,
+) -> (usize, bool, usize) {
+	let mut pos: usize = start;
+	let mut index: usize = 0;
+
+	while index < limit {
+		if index + 1 < limit {
+			if let Some(after) = read_separator(bytes, pos, b':', index) {
+				if let Some((v4, next)) = read_ipv4(bytes, after) {
+					groups[index] = (v4 >> 16) as u16;
+					groups[index + 1] = v4 as u16;
+					return (index + 2, true, next);
+				}
+			}
+		}
+
+		let after: usize = match read_separator(bytes, pos, b':', index) {
+			Some(after) => after,
+			None => return (index, false, pos),
+		};
+		match read_number(bytes, after, 16, 4, 0xffff, true) {
+			Some((group, next)) => {
+				groups[index] = group as u16;
+				pos = next;
+			}
+			None => return (index, false, pos),
+		}
+
+		index += 1;
+	}
+
+	(limit, false, pos)
+}
+
+const fn read_ipv6(bytes: &[u8], start: usize) -> Option<(u128, usize)> {
+	let mut head: [u16; 8] = [0; 8];
+	let (head_len, head_ipv4, mut pos) = read_groups(bytes, start, &mut head, 8);
+
+	if head_len < 8 {
+		// An embedded IPv4 address must come last, so it can't precede `::`.
+		if head_ipv4 || pos + 1 >= bytes.len() || bytes[pos] != b':' || bytes[pos + 1] != b':' {
+			return None;
+		}
+
+		// The `::` stands for at least one group of zeroes.
+		let mut tail: [u16; 8] = [0; 8];
+		let (tail_len, _, next) = read_groups(bytes, pos + 2, &mut tail, 8 - (head_len + 1));
+
+		let mut index: usize = 0;
+		while index < tail_len {
+			head[8 - tail_len + index] = tail[index];
+			index += 1;
+		}
+		pos = next;
+	}
+
+	let mut bits: u128 = 0;
+	let mut index: usize = 0;
+	while index < 8 {
+		bits = (bits << 16) | head[index] as u128;
+		index += 1;
+	}
+
+	Some((bits, pos))
+}
+
+/// Parse an IPv4 address from exactly the given bytes.
+pub(crate) const fn ipv4addr(bytes: &[u8]) -> Option<Ipv4Addr> {
+	match read_ipv4(bytes, 0) {
+		Some((bits, end)) if end == bytes.len() => Some(Ipv4Addr::from_bits(bits)),
+		_ => None,
+	}
+}
+
+/// Parse an IPv6 address from exactly the given bytes.
+pub(crate) const fn ipv6addr(bytes: &[u8]) -> Option<Ipv6Addr> {
+	match read_ipv6(bytes, 0) {
+		Some((bits, end)) if end == bytes.len() => Some(Ipv6Addr::from_bits(bits)),
+		_ => None,
+	}
+}
+
+/// Find the first `/` or ` ` at or after `start`.
+const fn delimiter(bytes: &[u8], start: usize) -> Option<usize> {
+	let mut at: usize = start;
+
+	while at < bytes.len() {
+		if bytes[at] == b'/' || bytes[at] == b' ' {
+			return Some(at);
+		}
+		at += 1;
+	}
+
+	None
+}
+
+/// Split a network into its address and netmask parts.
+///
+/// The parts are delimited by the first `/` or ` `; anything following a
+/// second delimiter is ignored.
+pub(crate) const fn split(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+	let at: usize = match delimiter(bytes, 0) {
+		Some(at) => at,
+		None => return Err(Error::ParseError("could not split provided input")),
+	};
+	let end: usize = match delimiter(bytes, at + 1) {
+		Some(end) => end,
+		None => bytes.len(),
+	};
+
+	let (lhs, rest) = bytes.split_at(at);
+	let (rhs, _) = rest.split_at(end - at);
+
+	Ok((lhs, rhs.split_at(1).1))
+}
+
+/// Parse a prefix length: an optional `+` followed by decimal digits.
+///
+/// Values which do not fit in a `u32` saturate, which gives the same netmask
+/// as any other out-of-range prefix length.
+const fn prefix_len(bytes: &[u8]) -> Option<u32> {
+	let digits: &[u8] = match bytes {
+		[b'+', digits @ ..] => digits,
+		digits => digits,
+	};
+
+	if digits.is_empty() {
+		return None;
+	}
+
+	let mut len: u32 = 0;
+	let mut index: usize = 0;
+	while index < digits.len() {
+		len = match digit(digits[index], 10) {
+			Some(digit) => len.saturating_mul(10).saturating_add(digit),
+			None => return None,
+		};
+		index += 1;
+	}
+
+	Some(len)
+}
+
+/// Parse the prefix length of a netmask of `bits` bits: decimal digits,
+/// optionally preceded by a `/`.
+///
+/// Unlike the prefix length of a network, this does not saturate; a prefix
+/// length longer than `bits` is an error.  Gives `None` if the input is not a
+/// prefix length at all, so that other notations can be tried.
+pub(crate) fn mask_prefix_len(bytes: &[u8], bits: u32) -> Option<Result<PrefixLen>> {
+	let digits: &[u8] = bytes.strip_prefix(b"/").unwrap_or(bytes);
+
+	if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+		return None;
+	}
+
+	Some(
+		prefix_len(digits)
+			.filter(|len: &u32| *len <= bits)
+			.and_then(|len: u32| PrefixLen::new(u8::try_from(len).ok()?))
+			.ok_or(Error::InvalidPrefixLen),
+	)
+}
+
+/// Parse a netmask of `bits` bits written as a `0x`-prefixed hexadecimal
+/// number.
+pub(crate) fn hex_mask(bytes: &[u8], bits: u32) -> Option<u128> {
+	let digits: &[u8] = bytes
+		.strip_prefix(b"0x")
+		.or_else(|| bytes.strip_prefix(b"0X"))?;
+
+	if digits.is_empty() || digits.len() > (bits / 4) as usize {
+		return None;
+	}
+
+	digits.iter().try_fold(0_u128, |acc: u128, digit: &u8| {
+		Some((acc << 4) | u128::from(char::from(*digit).to_digit(16)?))
+	})
+}
+
+/// Parse a `Netv4Addr`; see its `FromStr` implementation for the format.
+pub(crate) fn netv4addr(bytes: &[u8]) -> Result<Netv4Addr> {
+	let (lhs, rhs) = split(bytes)?;
+	let addr: Ipv4Addr = ipv4addr(lhs).ok_or(Error::ParseError("invalid IPv4 address syntax"))?;
+
+	let mask: Ipv4Addr = match prefix_len(rhs) {
+		Some(len) => (u32::MAX ^ u32::MAX.checked_shr(len).unwrap_or(0)).into(),
+		None => ipv4addr(rhs).unwrap_or_else(|| u32::MAX.into()),
+	};
+
+	Ok(Netv4Addr::new(addr, mask))
+}
+
+/// Parse a `Netv6Addr`; see its `FromStr` implementation for the format.
+pub(crate) fn netv6addr(bytes: &[u8]) -> Result<Netv6Addr> {
+	let (lhs, rhs) = split(bytes)?;
+	netv6addr_parts(lhs, rhs)
+}
+
+/// Parse a `Netv6Addr` from its address and netmask parts, already split.
+pub(crate) fn netv6addr_parts(lhs: &[u8], rhs: &[u8]) -> Result<Netv6Addr> {
+	let addr: Ipv6Addr = ipv6addr(lhs).ok_or(Error::ParseError("invalid IPv6 address syntax"))?;
+
+	let mask: Ipv6Addr = match prefix_len(rhs) {
+		Some(len) => (u128::MAX ^ u128::MAX.checked_shr(len).unwrap_or(0)).into(),
+		None => ipv6addr(rhs).unwrap_or_else(|| u128::MAX.into()),
+	};
+
+	Ok(Netv6Addr::new(addr, mask))
+}
+
+/// Parse a `NetAddr`, choosing the family by whether the address part contains
+/// a `:`.
+pub(crate) fn netaddr(bytes: &[u8]) -> Result<NetAddr> {
+	let (lhs, _) = split(bytes)?;
+
+	if lhs.contains(&b':') {
+		netv6addr(bytes).map(NetAddr::V6)
+	} else {
+		netv4addr(bytes).map(NetAddr::V4)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Inputs which exercise the corners of the `core::net` address grammar.
+	const CASES: &[&str] = &[
+		"",
+		"0.0.0.0",
+		"127.0.0.1",
+		"255.255.255.255",
+		"256.0.0.0",
+		"1.2.3",
+		"1.2.3.4.5",
+		"01.2.3.4",
+		"1.2.3.04",
+		"0.0.0.00",
+		"1..2.3",
+		"1.2.3.4.",
+		".1.2.3.4",
+		"1.2.3.-4",
+		"+1.2.3.4",
+		"1234.1.1.1",
+		"::",
+		":::",
+		"::1",
+		"1::",
+		"1::2",
+		"1:2:3:4:5:6:7:8",
+		"1:2:3:4:5:6:7:8:9",
+		"1:2:3:4:5:6:7::",
+		"::2:3:4:5:6:7:8",
+		"1:2:3:4:5:6:7:8::",
+		"1::2::3",
+		"12345::",
+		"0000:0000::",
+		"00000::",
+		"ffff::FFFF",
+		"g::",
+		"::ffff:1.2.3.4",
+		"::1.2.3.4",
+		"1:2:3:4:5:6:1.2.3.4",
+		"1:2:3:4:5:6:7:1.2.3.4",
+		"1.2.3.4::",
+		"1:2:3:4:5:1.2.3.4",
+		"::1.2.3.4:5",
+		"::ffff:1.2.3.256",
+		"::ffff:01.2.3.4",
+		"fe80::1%eth0",
+		" ::1",
+		"::1 ",
+		":1::",
+		"1:",
+		":1",
+	];
+
+	#[test]
+	fn ipv4addr_matches_core() {
+		for case in CASES {
+			assert_eq!(
+				ipv4addr(case.as_bytes()),
+				case.parse::<Ipv4Addr>().ok(),
+				"{:?}",
+				case
+			);
+		}
+	}
+
+	#[test]
+	fn ipv6addr_matches_core() {
+		for case in CASES {
+			assert_eq!(
+				ipv6addr(case.as_bytes()),
+				case.parse::<Ipv6Addr>().ok(),
+				"{:?}",
+				case
+			);
+		}
+	}
+
+	#[test]
+	fn non_ascii_is_rejected() {
+		assert_eq!(ipv4addr("１.2.3.4".as_bytes()), None);
+		assert_eq!(ipv6addr(&[0xff, b':', b':']), None);
+	}
+
+	#[test]
+	fn split_ignores_trailing_parts() {
+		assert_eq!(split(b"1.2.3.4/8/9"), Ok((&b"1.2.3.4"[..], &b"8"[..])));
+		assert_eq!(split(b"1.2.3.4 8"), Ok((&b"1.2.3.4"[..], &b"8"[..])));
+		assert_eq!(split(b"1.2.3.4/"), Ok((&b"1.2.3.4"[..], &b""[..])));
+	}
+
+	#[test]
+	fn prefix_len_parses_like_u32() {
+		assert_eq!(prefix_len(b"24"), Some(24));
+		assert_eq!(prefix_len(b"+24"), Some(24));
+		assert_eq!(prefix_len(b"0024"), Some(24));
+		assert_eq!(prefix_len(b"99999999999"), Some(u32::MAX));
+		assert_eq!(prefix_len(b""), None);
+		assert_eq!(prefix_len(b"+"), None);
+		assert_eq!(prefix_len(b"-1"), None);
+		assert_eq!(prefix_len(b"2x"), None);
+	}
+
+	#[test]
+	fn mask_prefix_len_is_strict() {
+		assert_eq!(
+			mask_prefix_len(b"/24", 32),
+			Some(Ok(PrefixLen::new(24).unwrap()))
+		);
+		assert_eq!(
+			mask_prefix_len(b"0", 32),
+			Some(Ok(PrefixLen::new(0).unwrap()))
+		);
+		assert_eq!(mask_prefix_len(b"128", 128), Some(Ok(PrefixLen::MAX)));
+		assert_eq!(
+			mask_prefix_len(b"33", 32),
+			Some(Err(Error::InvalidPrefixLen))
+		);
+		assert_eq!(
+			mask_prefix_len(b"99999999999", 128),
+			Some(Err(Error::InvalidPrefixLen))
+		);
+		assert_eq!(mask_prefix_len(b"+24", 32), None);
+		assert_eq!(mask_prefix_len(b"/", 32), None);
+		assert_eq!(mask_prefix_len(b"255.0.0.0", 32), None);
+	}
+
+	#[test]
+	fn hex_mask_fits_bits() {
+		assert_eq!(hex_mask(b"0xfffffc00", 32), Some(0xffff_fc00));
+		assert_eq!(hex_mask(b"0XFF", 32), Some(0xff));
+		assert_eq!(hex_mask(b"0x1ffffffff", 32), None);
+		assert_eq!(hex_mask(b"0x", 32), None);
+		assert_eq!(hex_mask(b"0xfg", 32), None);
+		assert_eq!(hex_mask(b"ff", 32), None);
+	}
+
+	#[test]
+	fn netaddr_dispatches_on_family() {
+		assert!(matches!(netaddr(b"10.0.0.0/8"), Ok(NetAddr::V4(_))));
+		assert!(matches!(netaddr(b"2001:db8::/32"), Ok(NetAddr::V6(_))));
+		assert!(matches!(
+			netaddr(b"::ffff:10.0.0.0/104"),
+			Ok(NetAddr::V6(_))
+		));
+	}
+
+	#[test]
+	fn netaddr_reports_family_error() {
+		assert_eq!(
+			netaddr(b"10.0.0.256/8"),
+			Err(Error::ParseError("invalid IPv4 address syntax"))
+		);
+		assert_eq!(
+			netaddr(b"2001:db8:::/32"),
+			Err(Error::ParseError("invalid IPv6 address syntax"))
+		);
+	}
+
+	mod properties {
+		use super::*;
+		use proptest::prelude::*;
+
+		proptest! {
+			#[test]
+			fn ipv4addr_round_trips(addr in any::<Ipv4Addr>()) {
+				prop_assert_eq!(ipv4addr(addr.to_string().as_bytes()), Some(addr));
+			}
+
+			#[test]
+			fn ipv6addr_round_trips(addr in any::<Ipv6Addr>()) {
+				prop_assert_eq!(ipv6addr(addr.to_string().as_bytes()), Some(addr));
+			}
+
+			#[test]
+			fn ipv4addr_matches_core_on_noise(string in "[0-9.+]{0,20}") {
+				prop_assert_eq!(ipv4addr(string.as_bytes()), string.parse::<Ipv4Addr>().ok());
+			}
+
+			#[test]
+			fn ipv6addr_matches_core_on_noise(string in "[0-9a-fA-F:.]{0,45}") {
+				prop_assert_eq!(ipv6addr(string.as_bytes()), string.parse::<Ipv6Addr>().ok());
+			}
+		}
+	}
+}
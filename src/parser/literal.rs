@@ -0,0 +1,186 @@
+//! The network literals accepted by the `net!`, `net4!`, and `net6!` macros,
+//! which are checked at compile time.
+//!
+//! Literals are read with the same `const` grammar as `FromStr`, but are held
+//! to a stricter standard for the netmask: where `FromStr` falls back to a
+//! `/32` or `/128` for a netmask it cannot read and ignores anything after a
+//! second delimiter, a literal with either of those is almost certainly a
+//! typo, so it is rejected.  Prefix lengths longer than the address are
+//! rejected for the same reason.
+
+use super::{ipv4addr, ipv6addr, prefix_len, split};
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// Split a literal into its address and netmask parts.
+const fn parts(bytes: &[u8]) -> Result<(&[u8], &[u8]), &'static str> {
+	match split(bytes) {
+		Ok(parts) => Ok(parts),
+		Err(_) => Err("could not split provided input"),
+	}
+}
+
+/// Check that the netmask part of a literal runs to its end.
+const fn is_whole(bytes: &[u8], lhs: &[u8], rhs: &[u8]) -> bool {
+	lhs.len() + 1 + rhs.len() == bytes.len()
+}
+
+/// Parse a `Netv4Addr` literal.
+pub const fn netv4addr(string: &str) -> Result<Netv4Addr, &'static str> {
+	let bytes: &[u8] = string.as_bytes();
+	let (lhs, rhs) = match parts(bytes) {
+		Ok(parts) => parts,
+		Err(message) => return Err(message),
+	};
+
+	let addr: Ipv4Addr = match ipv4addr(lhs) {
+		Some(addr) => addr,
+		None => return Err("invalid IPv4 address syntax"),
+	};
+	let mask: Ipv4Addr = match prefix_len(rhs) {
+		_ if !is_whole(bytes, lhs, rhs) => return Err("invalid netmask"),
+		Some(len) if len > 32 => return Err("prefix length is too long"),
+		Some(len) => match u32::MAX.checked_shl(32 - len) {
+			Some(mask) => Ipv4Addr::from_bits(mask),
+			None => Ipv4Addr::UNSPECIFIED,
+		},
+		None => match ipv4addr(rhs) {
+			Some(mask) => mask,
+			None => return Err("invalid netmask"),
+		},
+	};
+
+	Ok(Netv4Addr::new_const(addr, mask))
+}
+
+/// Parse a `Netv6Addr` literal.
+pub const fn netv6addr(string: &str) -> Result<Netv6Addr, &'static str> {
+	let bytes: &[u8] = string.as_bytes();
+	let (lhs, rhs) = match parts(bytes) {
+		Ok(parts) => parts,
+		Err(message) => return Err(message),
+	};
+
+	let addr: Ipv6Addr = match ipv6addr(lhs) {
+		Some(addr) => addr,
+		None => return Err("invalid IPv6 address syntax"),
+	};
+	let mask: Ipv6Addr = match prefix_len(rhs) {
+		_ if !is_whole(bytes, lhs, rhs) => return Err("invalid netmask"),
+		Some(len) if len > 128 => return Err("prefix length is too long"),
+		Some(len) => match u128::MAX.checked_shl(128 - len) {
+			Some(mask) => Ipv6Addr::from_bits(mask),
+			None => Ipv6Addr::UNSPECIFIED,
+		},
+		None => match ipv6addr(rhs) {
+			Some(mask) => mask,
+			None => return Err("invalid netmask"),
+		},
+	};
+
+	Ok(Netv6Addr::new_const(addr, mask))
+}
+
+/// Parse a `NetAddr` literal, choosing the family by whether the address part
+/// contains a `:`.
+pub const fn netaddr(string: &str) -> Result<NetAddr, &'static str> {
+	let (lhs, _) = match parts(string.as_bytes()) {
+		Ok(parts) => parts,
+		Err(message) => return Err(message),
+	};
+
+	let mut index: usize = 0;
+	while index < lhs.len() {
+		if lhs[index] == b':' {
+			return match netv6addr(string) {
+				Ok(net) => Ok(NetAddr::V6(net)),
+				Err(message) => Err(message),
+			};
+		}
+		index += 1;
+	}
+
+	match netv4addr(string) {
+		Ok(net) => Ok(NetAddr::V4(net)),
+		Err(message) => Err(message),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use proptest::prelude::*;
+
+	#[test]
+	fn addresses_match_runtime_parser() {
+		for addr in &[
+			"0.0.0.0",
+			"255.255.255.255",
+			"256.0.0.0",
+			"01.2.3.4",
+			"1.2.3",
+			"::",
+			":::",
+			"1::2",
+			"1:2:3:4:5:6:7:8",
+			"1:2:3:4:5:6:7:8:9",
+			"1:2:3:4:5:6:7::",
+			"::2:3:4:5:6:7:8",
+			"1::2::3",
+			"12345::",
+			"::ffff:1.2.3.4",
+			"1:2:3:4:5:6:1.2.3.4",
+			"1:2:3:4:5:6:7:1.2.3.4",
+			"1.2.3.4::",
+			"::1.2.3.4:5",
+			":1::",
+			"1:",
+		] {
+			let literal = format!("{}/8", addr);
+			let expected = literal.parse::<NetAddr>().map_err(|_| ());
+			let actual = netaddr(&literal).map_err(|_| ());
+
+			assert_eq!(actual, expected, "{:?}", literal);
+		}
+	}
+
+	#[test]
+	fn netmasks() {
+		assert_eq!(netv4addr("10.1.2.3/8"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+		assert_eq!(netv4addr("10.0.0.0 +8"), Ok(pu!("10.0.0.0/8" # Netv4Addr)));
+		assert_eq!(
+			netv4addr("10.0.0.0/255.0.255.0"),
+			Ok(pu!("10.0.0.0/255.0.255.0" # Netv4Addr))
+		);
+		assert_eq!(netv4addr("0.0.0.0/0"), Ok(pu!("0.0.0.0/0" # Netv4Addr)));
+		assert_eq!(netv6addr("::/0"), Ok(pu!("::/0" # Netv6Addr)));
+		assert_eq!(netv6addr("::1/128"), Ok(pu!("::1/128" # Netv6Addr)));
+		assert_eq!(
+			netv6addr("2001:db8::/ffff:0:ffff::"),
+			Ok(pu!("2001:db8::/ffff:0:ffff::" # Netv6Addr))
+		);
+	}
+
+	#[test]
+	fn rejects_what_parse_forgives() {
+		assert_eq!(netv4addr("10.0.0.0"), Err("could not split provided input"));
+		assert_eq!(netv4addr("10.0.0.0/zoop"), Err("invalid netmask"));
+		assert_eq!(netv4addr("10.0.0.0/8/9"), Err("invalid netmask"));
+		assert_eq!(netv4addr("10.0.0.0/"), Err("invalid netmask"));
+		assert_eq!(netv4addr("10.0.0.0/33"), Err("prefix length is too long"));
+		assert_eq!(netv6addr("::/129"), Err("prefix length is too long"));
+		assert_eq!(netaddr("10.0.0.256/8"), Err("invalid IPv4 address syntax"));
+		assert_eq!(
+			netaddr("2001:db8:::/32"),
+			Err("invalid IPv6 address syntax")
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn round_trips(net in strategy::netaddr()) {
+			prop_assert_eq!(netaddr(&net.to_string()), Ok(net));
+		}
+	}
+}
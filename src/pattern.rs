@@ -0,0 +1,195 @@
+//! Regular expressions matching the string forms of networks.
+//!
+//! These are used to describe networks in schemas, so they follow the ECMA-262
+//! dialect used by JSON Schema and avoid anything beyond alternation, grouping,
+//! character classes, and counted repetition.  They match the notation that
+//! `Display` produces and that `FromStr` and deserialization accept: an
+//! address, optionally followed by a `/` (or a space) and either a prefix
+//! length or a netmask.  Prefix lengths may have a leading `+` or zeroes.
+//!
+//! They are deliberately stricter than `FromStr` in one way: where it treats a
+//! prefix length longer than the address or a netmask it cannot read as
+//! covering only the address, the patterns reject those as likely typos.
+
+macro_rules! ipv4 {
+	() => {
+		r"(25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])(\.(25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])){3}"
+	};
+}
+
+macro_rules! hextet {
+	() => {
+		"[0-9a-fA-F]{1,4}"
+	};
+}
+
+macro_rules! ipv6 {
+	() => {
+		concat!(
+			"((",
+			hextet!(),
+			":){7}",
+			hextet!(),
+			"|(",
+			hextet!(),
+			":){1,7}:|(",
+			hextet!(),
+			":){1,6}:",
+			hextet!(),
+			"|(",
+			hextet!(),
+			":){1,5}(:",
+			hextet!(),
+			"){1,2}|(",
+			hextet!(),
+			":){1,4}(:",
+			hextet!(),
+			"){1,3}|(",
+			hextet!(),
+			":){1,3}(:",
+			hextet!(),
+			"){1,4}|(",
+			hextet!(),
+			":){1,2}(:",
+			hextet!(),
+			"){1,5}|",
+			hextet!(),
+			":(:",
+			hextet!(),
+			"){1,6}|:((:",
+			hextet!(),
+			"){1,7}|:)|(",
+			hextet!(),
+			":){6}",
+			ipv4!(),
+			"|((",
+			hextet!(),
+			":){0,5}",
+			hextet!(),
+			")?::(",
+			hextet!(),
+			":){0,5}",
+			ipv4!(),
+			")"
+		)
+	};
+}
+
+macro_rules! netv4addr {
+	() => {
+		concat!(ipv4!(), "([/ ](", ipv4!(), r"|\+?0*(3[0-2]|[12]?[0-9])))?")
+	};
+}
+
+macro_rules! netv6addr {
+	() => {
+		concat!(
+			ipv6!(),
+			"([/ ](",
+			ipv6!(),
+			r"|\+?0*(12[0-8]|1[01][0-9]|[1-9]?[0-9])))?"
+		)
+	};
+}
+
+/// Matches an IPv4 network, like `192.0.2.0/24`, `192.0.2.0/255.255.255.0`, or
+/// `192.0.2.1`.
+pub(crate) const NETV4ADDR: &str = concat!("^", netv4addr!(), "$");
+
+/// Matches an IPv6 network, like `2001:db8::/32`, `2001:db8::/ffff:ffff::`, or
+/// `2001:db8::1`.
+pub(crate) const NETV6ADDR: &str = concat!("^", netv6addr!(), "$");
+
+/// Matches an IPv4 or IPv6 network.
+pub(crate) const NETADDR: &str = concat!("^(", netv4addr!(), "|", netv6addr!(), ")$");
+
+/// Matches a comma-separated list of IPv4 or IPv6 networks, allowing
+/// whitespace around each network and empty entries.
+pub(crate) const NETLIST: &str = concat!(
+	r"^[\s,]*((",
+	netv4addr!(),
+	"|",
+	netv6addr!(),
+	r")(\s*,[\s,]*(",
+	netv4addr!(),
+	"|",
+	netv6addr!(),
+	r"))*)?[\s,]*$"
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::strategy;
+	use proptest::prelude::*;
+	use regex::Regex;
+
+	proptest! {
+		#[test]
+		fn matches_displayed_netv4addr(net in strategy::netv4addr()) {
+			let pattern = Regex::new(NETV4ADDR).unwrap();
+			prop_assert!(pattern.is_match(&net.to_string()));
+		}
+
+		#[test]
+		fn matches_displayed_netv6addr(net in strategy::netv6addr()) {
+			let pattern = Regex::new(NETV6ADDR).unwrap();
+			prop_assert!(pattern.is_match(&net.to_string()));
+		}
+
+		#[test]
+		fn matches_displayed_netaddr(net in strategy::netaddr()) {
+			let pattern = Regex::new(NETADDR).unwrap();
+			prop_assert!(pattern.is_match(&net.to_string()));
+		}
+	}
+
+	#[test]
+	fn matches_other_notations() {
+		let pattern = Regex::new(NETADDR).unwrap();
+
+		for net in &[
+			"10.0.0.0 8",
+			"10.0.0.0/255.0.255.0",
+			"::ffff:192.0.2.0/120",
+			"64:ff9b::192.0.2.0/96",
+			"2001:DB8::/32",
+			"::/0",
+			"192.0.2.1",
+			"2001:db8::1",
+			"10.0.0.0/+8",
+			"10.0.0.0/008",
+			"2001:db8::/+032",
+		] {
+			assert!(pattern.is_match(net), "{}", net);
+			// Deserializing falls back to a bare address where parsing fails.
+			assert!(
+				net.parse::<crate::NetAddr>().is_ok() || net.parse::<core::net::IpAddr>().is_ok(),
+				"{}",
+				net
+			);
+		}
+	}
+
+	#[test]
+	fn rejects_invalid_networks() {
+		let pattern = Regex::new(NETADDR).unwrap();
+
+		for net in &[
+			"10.0.0.0/33",
+			"10.0.0.0/",
+			"10.0.0.0/+",
+			"10.0.0.0/-8",
+			"256.0.0.0/8",
+			"010.0.0.0/8",
+			"10.0.0/8",
+			"::/129",
+			"1:::/8",
+			"1:2:3:4:5:6:7:8:9/64",
+			"2001:db8::g/64",
+			" 10.0.0.0/8",
+		] {
+			assert!(!pattern.is_match(net), "{}", net);
+		}
+	}
+}
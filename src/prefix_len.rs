@@ -0,0 +1,59 @@
+/// The length of a network prefix: the number of leading ones in a
+/// contiguous netmask.
+///
+/// Unlike a [`Netmask`](crate::Netmask) or [`Netmaskv6`](crate::Netmaskv6), a
+/// prefix length can only describe a CIDR network, so it is the type to use
+/// where a non-contiguous netmask would be a mistake.  A `PrefixLen` is at most
+/// 128, the length of an IPv6 address; converting one longer than 32 into a
+/// `Netmask` fails.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::convert::TryFrom;
+/// # use netaddr2::{Netmask, PrefixLen};
+/// let len: PrefixLen = "/22".parse().unwrap();
+/// let mask: Netmask = Netmask::try_from(len).unwrap();
+///
+/// assert_eq!(mask.to_string(), "255.255.252.0");
+/// assert_eq!(mask.wildcard().to_string(), "0.0.3.255");
+/// assert_eq!(format!("{:#x}", mask), "0xfffffc00");
+/// assert_eq!(PrefixLen::try_from(mask), Ok(len));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrefixLen(u8);
+
+impl PrefixLen {
+	/// The longest prefix length, that of a single IPv6 address.
+	pub const MAX: Self = Self(128);
+
+	/// Create a `PrefixLen`, or give `None` if `len` is greater than 128.
+	pub const fn new(len: u8) -> Option<Self> {
+		if len <= Self::MAX.0 {
+			Some(Self(len))
+		} else {
+			None
+		}
+	}
+
+	/// Get the prefix length as a number.
+	pub const fn get(self) -> u8 {
+		self.0
+	}
+}
+
+mod display;
+mod from;
+mod fromstr;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new_is_bounded() {
+		assert_eq!(PrefixLen::new(0).map(PrefixLen::get), Some(0));
+		assert_eq!(PrefixLen::new(128), Some(PrefixLen::MAX));
+		assert_eq!(PrefixLen::new(129), None);
+	}
+}
@@ -0,0 +1,28 @@
+use super::PrefixLen;
+use core::fmt;
+
+impl fmt::Display for PrefixLen {
+	/// Format the prefix length as a number, or with the alternate flag (`{:#}`)
+	/// in CIDR notation, preceded by a `/`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			write!(f, "/{}", self.0)
+		} else {
+			fmt::Display::fmt(&self.0, f)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn plain_and_alternate() {
+		let len: PrefixLen = PrefixLen::new(22).unwrap();
+
+		assert_eq!(format!("{}", len), "22");
+		assert_eq!(format!("{:#}", len), "/22");
+		assert_eq!(format!("{:>3}", len), " 22");
+	}
+}
@@ -0,0 +1,73 @@
+use super::PrefixLen;
+use crate::{Error, Netmask, Netmaskv6};
+use core::convert::TryFrom;
+
+impl TryFrom<u8> for PrefixLen {
+	type Error = Error;
+
+	fn try_from(len: u8) -> Result<Self, Error> {
+		Self::new(len).ok_or(Error::InvalidPrefixLen)
+	}
+}
+
+impl From<PrefixLen> for u8 {
+	fn from(len: PrefixLen) -> u8 {
+		len.get()
+	}
+}
+
+impl From<PrefixLen> for u32 {
+	fn from(len: PrefixLen) -> u32 {
+		len.get().into()
+	}
+}
+
+impl TryFrom<Netmask> for PrefixLen {
+	type Error = Error;
+
+	/// Convert a contiguous netmask into its prefix length.
+	fn try_from(mask: Netmask) -> Result<Self, Error> {
+		mask.prefix_len().ok_or(Error::NonContiguousMask)
+	}
+}
+
+impl TryFrom<Netmaskv6> for PrefixLen {
+	type Error = Error;
+
+	/// Convert a contiguous netmask into its prefix length.
+	fn try_from(mask: Netmaskv6) -> Result<Self, Error> {
+		mask.prefix_len().ok_or(Error::NonContiguousMask)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::net::{Ipv4Addr, Ipv6Addr};
+
+	#[test]
+	fn from_u8() {
+		assert_eq!(PrefixLen::try_from(24).map(u8::from), Ok(24));
+		assert_eq!(PrefixLen::try_from(129), Err(Error::InvalidPrefixLen));
+	}
+
+	#[test]
+	fn from_netmasks() {
+		assert_eq!(
+			PrefixLen::try_from(Netmask::new(Ipv4Addr::new(255, 255, 252, 0))),
+			Ok(PrefixLen::new(22).unwrap())
+		);
+		assert_eq!(
+			PrefixLen::try_from(Netmask::new(Ipv4Addr::new(255, 0, 255, 0))),
+			Err(Error::NonContiguousMask)
+		);
+		assert_eq!(
+			PrefixLen::try_from(Netmaskv6::new(Ipv6Addr::from(u128::MAX))),
+			Ok(PrefixLen::MAX)
+		);
+		assert_eq!(
+			PrefixLen::try_from(Netmaskv6::new(Ipv6Addr::from(1))),
+			Err(Error::NonContiguousMask)
+		);
+	}
+}
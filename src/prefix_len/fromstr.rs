@@ -0,0 +1,55 @@
+use super::PrefixLen;
+use crate::{Error, Netmask, Netmaskv6, Result};
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+impl FromStr for PrefixLen {
+	type Err = Error;
+
+	/// Parse a `PrefixLen` from a string
+	///
+	/// This accepts a prefix length with or without a leading `/`, such as `22`
+	/// or `/22`, or a contiguous IPv4 or IPv6 netmask in any notation accepted
+	/// by [`Netmask`] or [`Netmaskv6`], such as `255.255.252.0`, `0xfffffc00`,
+	/// or `ffff:ffff::`.
+	fn from_str(string: &str) -> Result<Self> {
+		if let Some(len) = crate::parser::mask_prefix_len(string.as_bytes(), 128) {
+			return len;
+		}
+
+		match string.parse::<Netmask>() {
+			Ok(mask) => Self::try_from(mask),
+			Err(_) => Self::try_from(string.parse::<Netmaskv6>()?),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn every_notation() {
+		let len: PrefixLen = PrefixLen::new(22).unwrap();
+
+		assert_eq!("22".parse(), Ok(len));
+		assert_eq!("/22".parse(), Ok(len));
+		assert_eq!("255.255.252.0".parse(), Ok(len));
+		assert_eq!("0xfffffc00".parse(), Ok(len));
+		assert_eq!("ffff:fc00::".parse(), Ok(len));
+		assert_eq!("0xfffffc00000000000000000000000000".parse(), Ok(len));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!("129".parse::<PrefixLen>(), Err(Error::InvalidPrefixLen));
+		assert_eq!(
+			"255.0.255.0".parse::<PrefixLen>(),
+			Err(Error::NonContiguousMask)
+		);
+		assert!(matches!(
+			"zoop".parse::<PrefixLen>(),
+			Err(Error::ParseError(_))
+		));
+	}
+}
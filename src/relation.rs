@@ -0,0 +1,156 @@
+/// How two networks relate to one another, as sets of addresses.
+///
+/// Returned by the `relation` methods on `NetAddr`, `Netv4Addr`, and
+/// `Netv6Addr`.  Exactly one variant describes any pair of networks; they are
+/// checked in the order they are listed here, so that, for instance, two equal
+/// networks are `Equal` rather than `Contains`.
+///
+/// This is independent of the `Ord` implementations, which sort networks by
+/// address and then by mask and say nothing about nesting.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetRelation {
+	/// The networks contain exactly the same addresses.
+	Equal,
+	/// The first network contains every address of the second, and more.
+	Contains,
+	/// The second network contains every address of the first, and more.
+	ContainedBy,
+	/// The networks share some addresses, but each has addresses the other does
+	/// not.  This can only happen with non-contiguous netmasks.
+	Overlapping,
+	/// The networks share no addresses, but an address of one is immediately
+	/// followed by an address of the other.
+	Adjacent,
+	/// The networks share no addresses and are not adjacent, or are of
+	/// different address families.
+	Disjoint,
+}
+
+/// Compute the relation between two networks of the same family, given as
+/// their addresses and masks widened to `u128` and the width of the family in
+/// bits.
+///
+/// A network is the set of addresses `x` with `x & mask == addr`, which works
+/// equally well for non-contiguous masks: one network contains another when
+/// it fixes a subset of the other's bits to the same values, and they overlap
+/// when they agree on the bits they both fix.
+pub(crate) fn relation(
+	(addr, mask): (u128, u128),
+	(other_addr, other_mask): (u128, u128),
+	bits: u32,
+) -> NetRelation {
+	let contains = other_mask & mask == mask && other_addr & mask == addr;
+	let contained = mask & other_mask == other_mask && addr & other_mask == other_addr;
+
+	match (contains, contained) {
+		(true, true) => NetRelation::Equal,
+		(true, false) => NetRelation::Contains,
+		(false, true) => NetRelation::ContainedBy,
+		(false, false) if (addr ^ other_addr) & mask & other_mask == 0 => NetRelation::Overlapping,
+		(false, false)
+			if precedes((addr, mask), (other_addr, other_mask), bits)
+				|| precedes((other_addr, other_mask), (addr, mask), bits) =>
+		{
+			NetRelation::Adjacent
+		}
+		(false, false) => NetRelation::Disjoint,
+	}
+}
+
+/// Determine whether some address `x` of the first network has `x + 1` in the
+/// second.
+///
+/// Adding one to `x` clears its trailing ones and sets the zero bit above
+/// them, so for each possible position `k` of that zero bit, this checks that
+/// both networks allow it: the first must allow ones below `k` and a zero at
+/// `k`, the second zeroes below `k` and a one at `k`, and the two must agree on
+/// every bit above `k` that both fix.
+fn precedes((addr, mask): (u128, u128), (other_addr, other_mask): (u128, u128), bits: u32) -> bool {
+	(0..bits).any(|k| {
+		let low: u128 = (1_u128 << k) - 1;
+		let bit: u128 = 1_u128 << k;
+		let high: u128 = !(low | bit);
+
+		mask & low & !addr == 0
+			&& addr & bit == 0
+			&& other_mask & low & other_addr == 0
+			&& other_mask & bit & !other_addr == 0
+			&& (addr ^ other_addr) & mask & other_mask & high == 0
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn v4(net: &str) -> (u128, u128) {
+		let net: crate::Netv4Addr = net.parse().unwrap();
+		(u32::from(net.addr()).into(), u32::from(net.mask()).into())
+	}
+
+	#[test]
+	fn nested() {
+		assert_eq!(
+			relation(v4("10.0.0.0/8"), v4("10.0.0.0/8"), 32),
+			NetRelation::Equal
+		);
+		assert_eq!(
+			relation(v4("10.0.0.0/8"), v4("10.1.0.0/16"), 32),
+			NetRelation::Contains
+		);
+		assert_eq!(
+			relation(v4("10.1.0.0/16"), v4("10.0.0.0/8"), 32),
+			NetRelation::ContainedBy
+		);
+	}
+
+	#[test]
+	fn adjacent_ranges() {
+		assert_eq!(
+			relation(v4("10.0.0.0/24"), v4("10.0.1.0/24"), 32),
+			NetRelation::Adjacent
+		);
+		assert_eq!(
+			relation(v4("10.0.1.0/24"), v4("10.0.2.0/23"), 32),
+			NetRelation::Adjacent
+		);
+		assert_eq!(
+			relation(v4("10.0.2.0/23"), v4("10.0.1.0/24"), 32),
+			NetRelation::Adjacent
+		);
+		assert_eq!(
+			relation(v4("10.0.0.0/24"), v4("10.0.2.0/24"), 32),
+			NetRelation::Disjoint
+		);
+	}
+
+	#[test]
+	fn extremes_are_not_adjacent() {
+		assert_eq!(
+			relation(v4("0.0.0.0/32"), v4("255.255.255.255/32"), 32),
+			NetRelation::Disjoint
+		);
+	}
+
+	#[test]
+	fn non_contiguous() {
+		// Every address whose third octet is even, and every address whose third
+		// octet is 1 mod 4, interleave with one another.
+		assert_eq!(
+			relation(v4("0.0.0.0/0.0.1.0"), v4("0.0.1.0/0.0.3.0"), 32),
+			NetRelation::Adjacent
+		);
+		assert_eq!(
+			relation(v4("10.0.0.0/255.0.255.0"), v4("10.0.0.0/255.255.0.0"), 32),
+			NetRelation::Overlapping
+		);
+		assert_eq!(
+			relation(
+				v4("10.0.0.0/255.0.255.255"),
+				v4("10.0.1.0/255.0.255.255"),
+				32
+			),
+			NetRelation::Disjoint
+		);
+	}
+}
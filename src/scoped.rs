@@ -0,0 +1,89 @@
+use crate::{Netv6Addr, ZoneId};
+use core::net::Ipv6Addr;
+
+/// An IPv6 address with a zone, like `fe80::1%eth0`.
+///
+/// This is what link-local addresses look like when the link matters: the
+/// same address may be in use on several links at once, and the zone says
+/// which one is meant.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::ScopedIpv6Addr;
+/// let addr: ScopedIpv6Addr = "fe80::1%eth0".parse().unwrap();
+///
+/// assert_eq!(addr.addr(), "fe80::1".parse::<std::net::Ipv6Addr>().unwrap());
+/// assert_eq!(addr.zone().name(), Some("eth0"));
+/// assert_ne!(addr, "fe80::1%eth1".parse().unwrap());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScopedIpv6Addr {
+	addr: Ipv6Addr,
+	zone: ZoneId,
+}
+
+impl ScopedIpv6Addr {
+	/// Create a new `ScopedIpv6Addr` from an address and a zone.
+	pub const fn new(addr: Ipv6Addr, zone: ZoneId) -> Self {
+		Self { addr, zone }
+	}
+
+	/// Get the address, without its zone.
+	pub const fn addr(&self) -> Ipv6Addr {
+		self.addr
+	}
+
+	/// Get the zone.
+	pub const fn zone(&self) -> ZoneId {
+		self.zone
+	}
+}
+
+/// An IPv6 network with a zone, like `fe80::/64%eth0`.
+///
+/// Two scoped networks are only equal if their zones are, and a scoped network
+/// only contains scoped addresses and networks in the same zone.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Contains, ScopedIpv6Addr, ScopedNetv6Addr};
+/// let net: ScopedNetv6Addr = "fe80::/64%eth0".parse().unwrap();
+///
+/// assert_eq!(net.to_string(), "fe80::%eth0/64");
+/// assert!(net.contains(&"fe80::1%eth0".parse::<ScopedIpv6Addr>().unwrap()));
+/// assert!(!net.contains(&"fe80::1%eth1".parse::<ScopedIpv6Addr>().unwrap()));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScopedNetv6Addr {
+	net: Netv6Addr,
+	zone: ZoneId,
+}
+
+impl ScopedNetv6Addr {
+	/// Create a new `ScopedNetv6Addr` from a network and a zone.
+	pub const fn new(net: Netv6Addr, zone: ZoneId) -> Self {
+		Self { net, zone }
+	}
+
+	/// Get the network, without its zone.
+	pub const fn net(&self) -> Netv6Addr {
+		self.net
+	}
+
+	/// Get the zone.
+	pub const fn zone(&self) -> ZoneId {
+		self.zone
+	}
+}
+
+impl From<ScopedIpv6Addr> for ScopedNetv6Addr {
+	fn from(addr: ScopedIpv6Addr) -> Self {
+		Self::new(addr.addr().into(), addr.zone())
+	}
+}
+
+mod contains;
+mod display;
+mod fromstr;
@@ -0,0 +1,38 @@
+use super::{ScopedIpv6Addr, ScopedNetv6Addr};
+use crate::Contains;
+
+impl Contains<ScopedIpv6Addr> for ScopedNetv6Addr {
+	/// Determine whether `other` is in this network and zone.
+	fn contains(&self, other: &ScopedIpv6Addr) -> bool {
+		self.zone() == other.zone() && self.net().contains(&other.addr())
+	}
+}
+
+impl Contains<ScopedNetv6Addr> for ScopedNetv6Addr {
+	/// Determine whether `other` is within this network and in the same zone.
+	fn contains(&self, other: &ScopedNetv6Addr) -> bool {
+		self.zone() == other.zone() && self.net().contains(&other.net())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn same_zone() {
+		assert!(pu!("fe80::/64%eth0" # ScopedNetv6Addr).contains(&pu!("fe80::1%eth0" # ScopedIpv6Addr)));
+		assert!(pu!("fe80::/10%3" # ScopedNetv6Addr).contains(&pu!("fe80::/64%3" # ScopedNetv6Addr)));
+		assert!(!pu!("fe80::/64%eth0" # ScopedNetv6Addr)
+			.contains(&pu!("fe80:0:0:1::1%eth0" # ScopedIpv6Addr)));
+	}
+
+	#[test]
+	fn refuses_other_zones() {
+		assert!(
+			!pu!("fe80::/64%eth0" # ScopedNetv6Addr).contains(&pu!("fe80::1%eth1" # ScopedIpv6Addr))
+		);
+		assert!(!pu!("fe80::/64%eth0" # ScopedNetv6Addr).contains(&pu!("fe80::1%1" # ScopedIpv6Addr)));
+		assert!(!pu!("fe80::/10%3" # ScopedNetv6Addr).contains(&pu!("fe80::/64%4" # ScopedNetv6Addr)));
+	}
+}
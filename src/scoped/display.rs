@@ -0,0 +1,38 @@
+use super::{ScopedIpv6Addr, ScopedNetv6Addr};
+use core::fmt;
+
+impl fmt::Display for ScopedIpv6Addr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}%{}", self.addr(), self.zone())
+	}
+}
+
+impl fmt::Display for ScopedNetv6Addr {
+	/// Write the network with its zone after the address, as in RFC 4007, like
+	/// `fe80::%eth0/64`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let net = self.net();
+
+		match net.netmask().prefix_len() {
+			Some(len) => write!(f, "{}%{}/{}", net.addr(), self.zone(), len),
+			None => write!(f, "{}%{}/{}", net.addr(), self.zone(), net.mask()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn zone_follows_address() {
+		let addr: ScopedIpv6Addr = "fe80::1%eth0".parse().unwrap();
+		assert_eq!(addr.to_string(), "fe80::1%eth0");
+
+		let net: ScopedNetv6Addr = "fe80::1/64%3".parse().unwrap();
+		assert_eq!(net.to_string(), "fe80::%3/64");
+
+		let net: ScopedNetv6Addr = "fe80::%3/ffff:0:ffff::".parse().unwrap();
+		assert_eq!(net.to_string(), "fe80::%3/ffff:0:ffff::");
+	}
+}
@@ -0,0 +1,99 @@
+use super::{ScopedIpv6Addr, ScopedNetv6Addr};
+use crate::parser;
+use crate::{Error, Netv6Addr, Result};
+use core::str::FromStr;
+
+/// Split off the zone, which follows the first `%`.
+fn split_zone(string: &str) -> Result<(&str, &str)> {
+	string
+		.split_once('%')
+		.ok_or(Error::ParseError("missing zone ID"))
+}
+
+impl FromStr for ScopedIpv6Addr {
+	type Err = Error;
+
+	/// Parse a `ScopedIpv6Addr` from an address and a zone separated by a `%`,
+	/// like `fe80::1%eth0` or `fe80::1%3`.
+	fn from_str(string: &str) -> Result<Self> {
+		let (addr, zone) = split_zone(string)?;
+		let addr =
+			parser::ipv6addr(addr.as_bytes()).ok_or(Error::ParseError("invalid IPv6 address syntax"))?;
+
+		Ok(Self::new(addr, zone.parse()?))
+	}
+}
+
+impl FromStr for ScopedNetv6Addr {
+	type Err = Error;
+
+	/// Parse a `ScopedNetv6Addr` from a string
+	///
+	/// The zone may be given after the address, as in RFC 4007, or after the
+	/// whole network, and the netmask may be given in any notation accepted
+	/// by `Netv6Addr`.  Without a netmask, the network is a single address.
+	/// - Zone after the address: `fe80::%eth0/64`
+	/// - Zone after the network: `fe80::/64%eth0`
+	/// - A single address: `fe80::1%3`
+	fn from_str(string: &str) -> Result<Self> {
+		let (lhs, rest) = split_zone(string)?;
+
+		let (net, zone): (Netv6Addr, &str) = match rest.find(['/', ' ']) {
+			Some(at) => {
+				let (_, mask) = parser::split(&rest.as_bytes()[at..])?;
+				(parser::netv6addr_parts(lhs.as_bytes(), mask)?, &rest[..at])
+			}
+			None if lhs.contains(['/', ' ']) => (parser::netv6addr(lhs.as_bytes())?, rest),
+			None => {
+				let addr = parser::ipv6addr(lhs.as_bytes())
+					.ok_or(Error::ParseError("invalid IPv6 address syntax"))?;
+				(addr.into(), rest)
+			}
+		};
+
+		Ok(Self::new(net, zone.parse()?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ZoneId;
+
+	#[test]
+	fn zone_in_either_place() {
+		let expected = ScopedNetv6Addr::new(pu!("fe80::/64" # Netv6Addr), ZoneId::from_index(3));
+
+		assert_eq!("fe80::/64%3".parse(), Ok(expected));
+		assert_eq!("fe80::%3/64".parse(), Ok(expected));
+		assert_eq!("fe80::%3 ffff:ffff:ffff:ffff::".parse(), Ok(expected));
+	}
+
+	#[test]
+	fn single_address() {
+		let net: ScopedNetv6Addr = "fe80::1%eth0".parse().unwrap();
+
+		assert_eq!(net.net(), pu!("fe80::1/128" # Netv6Addr));
+		assert_eq!(net.zone().name(), Some("eth0"));
+		assert_eq!(
+			ScopedNetv6Addr::from("fe80::1%eth0".parse::<ScopedIpv6Addr>().unwrap()),
+			net
+		);
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(
+			"fe80::/64".parse::<ScopedNetv6Addr>(),
+			Err(Error::ParseError("missing zone ID"))
+		);
+		assert_eq!(
+			"fe80::1".parse::<ScopedIpv6Addr>(),
+			Err(Error::ParseError("missing zone ID"))
+		);
+		assert!("fe80::/64%".parse::<ScopedNetv6Addr>().is_err());
+		assert!("fe80::1%".parse::<ScopedIpv6Addr>().is_err());
+		assert!("fe80::1%eth0/64".parse::<ScopedIpv6Addr>().is_err());
+		assert!("10.0.0.0/8%eth0".parse::<ScopedNetv6Addr>().is_err());
+	}
+}
@@ -0,0 +1,257 @@
+//! Helpers for choosing how networks are represented with `serde`.
+//!
+//! By default, `NetAddr`, `Netv4Addr`, and `Netv6Addr` serialize to a string
+//! such as `"10.0.0.0/8"` in human-readable formats.  When deserializing from a
+//! human-readable format, any of the following are accepted:
+//!
+//! - a string in CIDR or extended notation, like `"10.0.0.0/8"` or
+//!   `"10.0.0.0/255.0.0.0"`;
+//! - a bare address, like `"10.0.0.1"`, giving a network of just that address;
+//! - a map with an `address` (or `addr`) and optionally a `prefix` or a `mask`,
+//!   like `{ "address": "10.0.0.0", "prefix": 8 }`;
+//! - a sequence of an address and optionally a prefix or a mask, like
+//!   `["10.0.0.0", 8]`;
+//! - for `Netv4Addr` and `Netv6Addr`, an integer address.
+//!
+//! The modules here can be used with `#[serde(with = "...")]` to choose a
+//! different representation for a single field:
+//!
+//! - [`structured`] serializes to a map with an `address` and either a `prefix`
+//!   or a `mask`;
+//! - [`as_prefix_len`] serializes to a map with an `address` and a `prefix`,
+//!   and rejects networks with non-contiguous masks;
+//! - [`strict_cidr`] serializes to a string in CIDR notation, and only
+//!   deserializes from such strings with no host bits set;
+//! - [`comma_separated`] serializes a `Vec` of networks to a single
+//!   comma-separated string, as is common in environment variables.
+//!
+//! ```
+//! use netaddr2::{NetAddr, Netv4Addr};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Route {
+//!     #[serde(with = "netaddr2::serde::structured")]
+//!     destination: Netv4Addr,
+//!     #[serde(with = "netaddr2::serde::strict_cidr")]
+//!     source: NetAddr,
+//! }
+//!
+//! let route: Route = serde_json::from_str(
+//!     r#"{ "destination": { "addr": "10.0.0.0", "mask": "255.0.0.0" }, "source": "192.0.2.0/24" }"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&route).unwrap(),
+//!     r#"{"destination":{"address":"10.0.0.0","prefix":8},"source":"192.0.2.0/24"}"#,
+//! );
+//!
+//! assert!(serde_json::from_str::<Route>(
+//!     r#"{ "destination": "10.0.0.0/8", "source": "192.0.2.1/24" }"#,
+//! )
+//! .is_err());
+//! ```
+
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+use ::serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use core::net::IpAddr;
+use core::str::FromStr;
+
+pub mod as_prefix_len;
+#[cfg(feature = "std")]
+pub mod comma_separated;
+pub mod strict_cidr;
+pub mod structured;
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl Sealed for crate::NetAddr {}
+	impl Sealed for crate::Netv4Addr {}
+	impl Sealed for crate::Netv6Addr {}
+}
+
+/// A network type which can be used with the helper modules in this module.
+///
+/// This trait is sealed, and is implemented for `NetAddr`, `Netv4Addr`, and
+/// `Netv6Addr`.
+pub trait Network:
+	sealed::Sealed + Copy + Into<NetAddr> + FromStr + Serialize + DeserializeOwned
+{
+}
+
+impl Network for NetAddr {}
+impl Network for Netv4Addr {}
+impl Network for Netv6Addr {}
+
+/// Compute the prefix length of a network, if its mask is contiguous.
+pub(crate) fn prefix_len(net: &NetAddr) -> Option<u32> {
+	if !net.is_cidr() {
+		return None;
+	}
+
+	match net.mask() {
+		IpAddr::V4(mask) => Some(u32::from(mask).count_ones()),
+		IpAddr::V6(mask) => Some(u128::from(mask).count_ones()),
+	}
+}
+
+/// The fields of the map form of a network.
+pub(crate) enum Field {
+	Address,
+	Prefix,
+	Mask,
+}
+
+const FIELDS: &[&str] = &["address", "addr", "prefix", "mask"];
+
+struct FieldVisitor;
+
+impl<'de> de::Visitor<'de> for FieldVisitor {
+	type Value = Field;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("`address`, `addr`, `prefix`, or `mask`")
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match value {
+			"address" | "addr" => Ok(Field::Address),
+			"prefix" => Ok(Field::Prefix),
+			"mask" => Ok(Field::Mask),
+			_ => Err(de::Error::unknown_field(value, FIELDS)),
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Field {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(FieldVisitor)
+	}
+}
+
+/// The right-hand side of a network: either a prefix length or a mask.
+pub(crate) enum Rhs<A> {
+	Prefix(u32),
+	Mask(A),
+}
+
+struct RhsVisitor<A>(PhantomData<A>);
+
+impl<'de, A: FromStr> de::Visitor<'de> for RhsVisitor<A> {
+	type Value = Rhs<A>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a prefix length or a mask")
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		u32::try_from(value)
+			.map(Rhs::Prefix)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(value), &self))
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+		u32::try_from(value)
+			.map(Rhs::Prefix)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(value), &self))
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		if let Ok(prefix) = value.parse::<u32>() {
+			return Ok(Rhs::Prefix(prefix));
+		}
+
+		value
+			.parse::<A>()
+			.map(Rhs::Mask)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+	}
+}
+
+impl<'de, A: FromStr> Deserialize<'de> for Rhs<A> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_any(RhsVisitor(PhantomData))
+	}
+}
+
+/// Visit the map form of a network, returning its address and, if given, its
+/// prefix length or mask.
+pub(crate) fn visit_map<'de, A, M>(mut map: M) -> Result<(A, Option<Rhs<A>>), M::Error>
+where
+	A: Deserialize<'de> + FromStr,
+	M: de::MapAccess<'de>,
+{
+	let mut addr: Option<A> = None;
+	let mut rhs: Option<Rhs<A>> = None;
+
+	while let Some(field) = map.next_key()? {
+		match field {
+			Field::Address if addr.is_some() => return Err(de::Error::duplicate_field("address")),
+			Field::Address => addr = Some(map.next_value()?),
+			Field::Prefix | Field::Mask if rhs.is_some() => {
+				return Err(de::Error::custom(
+					"only one of `prefix` and `mask` may be given",
+				))
+			}
+			// The prefix is read as in the sequence form, but must not be a mask.
+			Field::Prefix => match map.next_value::<Rhs<A>>()? {
+				Rhs::Prefix(prefix) => rhs = Some(Rhs::Prefix(prefix)),
+				Rhs::Mask(_) => {
+					return Err(de::Error::invalid_value(
+						de::Unexpected::Other("a mask"),
+						&"a prefix length",
+					))
+				}
+			},
+			Field::Mask => rhs = Some(Rhs::Mask(map.next_value()?)),
+		}
+	}
+
+	let addr = addr.ok_or_else(|| de::Error::missing_field("address"))?;
+
+	Ok((addr, rhs))
+}
+
+/// Visit the sequence form of a network, returning its address and, if given,
+/// its prefix length or mask.
+pub(crate) fn visit_seq<'de, A, S>(
+	mut seq: S,
+	expected: &dyn de::Expected,
+) -> Result<(A, Option<Rhs<A>>), S::Error>
+where
+	A: Deserialize<'de> + FromStr,
+	S: de::SeqAccess<'de>,
+{
+	let addr: A = seq
+		.next_element()?
+		.ok_or_else(|| de::Error::invalid_length(0, expected))?;
+	let rhs: Option<Rhs<A>> = seq.next_element()?;
+
+	if rhs.is_some() && seq.next_element::<de::IgnoredAny>()?.is_some() {
+		return Err(de::Error::invalid_length(3, expected));
+	}
+
+	Ok((addr, rhs))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn prefix_len_of_cidr() {
+		assert_eq!(prefix_len(&pu!("10.0.0.0/8" # NetAddr)), Some(8));
+		assert_eq!(prefix_len(&pu!("2001:db8::/32" # NetAddr)), Some(32));
+		assert_eq!(prefix_len(&pu!("0.0.0.0/0" # NetAddr)), Some(0));
+	}
+
+	#[test]
+	fn prefix_len_of_non_cidr() {
+		assert_eq!(prefix_len(&pu!("10.0.0.0/255.0.255.0" # NetAddr)), None);
+	}
+}
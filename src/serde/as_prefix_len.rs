@@ -0,0 +1,95 @@
+//! Represent a network as a map of its address and its prefix length.
+//!
+//! In human-readable formats, networks are serialized as
+//! `{ "address": "10.0.0.0", "prefix": 8 }`.  In compact formats, the default
+//! representation is used.
+//!
+//! Deserializing accepts every form that the network type itself accepts, but
+//! fails if the network does not have a contiguous mask.  Likewise, serializing
+//! a network without a contiguous mask fails.
+
+use super::{prefix_len, Network};
+use crate::NetAddr;
+use ::serde::ser::SerializeStruct;
+use ::serde::{de, ser, Deserializer, Serializer};
+
+const NON_CIDR: &str = "network does not have a contiguous mask";
+
+/// Serialize a network as a map of its address and its prefix length.
+pub fn serialize<N: Network, S: Serializer>(net: &N, serializer: S) -> Result<S::Ok, S::Error> {
+	let prefix = prefix_len(&(*net).into()).ok_or_else(|| ser::Error::custom(NON_CIDR))?;
+
+	if !serializer.is_human_readable() {
+		return net.serialize(serializer);
+	}
+
+	let net: NetAddr = (*net).into();
+	let mut state = serializer.serialize_struct("Network", 2)?;
+	state.serialize_field("address", &net.addr())?;
+	state.serialize_field("prefix", &prefix)?;
+	state.end()
+}
+
+/// Deserialize a network with a contiguous mask from any of its accepted
+/// forms.
+pub fn deserialize<'de, N: Network, D: Deserializer<'de>>(deserializer: D) -> Result<N, D::Error> {
+	let net = N::deserialize(deserializer)?;
+
+	if net.into().is_cidr() {
+		Ok(net)
+	} else {
+		Err(de::Error::custom(NON_CIDR))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, Netv4Addr};
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper<N: super::Network> {
+		#[serde(with = "super", bound = "")]
+		net: N,
+	}
+
+	#[test]
+	fn serializes_with_prefix() {
+		let wrapper = Wrapper {
+			net: pu!("2001:db8::/32" # NetAddr),
+		};
+
+		assert_eq!(
+			serde_json::to_string(&wrapper).unwrap(),
+			r#"{"net":{"address":"2001:db8::","prefix":32}}"#
+		);
+	}
+
+	#[test]
+	fn serializing_non_cidr_fails() {
+		let wrapper = Wrapper {
+			net: pu!("10.0.0.0/255.0.255.0" # Netv4Addr),
+		};
+
+		assert!(serde_json::to_string(&wrapper).is_err());
+		assert!(bincode::serialize(&wrapper).is_err());
+	}
+
+	#[test]
+	fn deserializes_masks_as_prefix() {
+		let wrapper: Wrapper<Netv4Addr> =
+			serde_json::from_str(r#"{"net":{"addr":"10.0.0.0","mask":"255.0.0.0"}}"#).unwrap();
+
+		assert_eq!(wrapper.net, pu!("10.0.0.0/8" # Netv4Addr));
+	}
+
+	#[test]
+	fn deserializing_non_cidr_fails() {
+		let error =
+			serde_json::from_str::<Wrapper<Netv4Addr>>(r#"{"net":"10.0.0.0/255.0.255.0"}"#).unwrap_err();
+
+		assert!(error
+			.to_string()
+			.starts_with("network does not have a contiguous mask"));
+	}
+}
@@ -0,0 +1,88 @@
+//! Represent a `Vec` of networks as a comma-separated string.
+//!
+//! In human-readable formats, the networks are serialized as a single string
+//! like `"10.0.0.0/8,2001:db8::/32"`.  Deserializing accepts either such a
+//! string, with whitespace around each network and empty entries ignored, or a
+//! sequence of networks.  This suits configuration loaded from environment
+//! variables, where every value is a string.  In compact formats, a sequence of
+//! networks is used.
+//!
+//! This is the representation of [`NetList`], for fields which
+//! are a plain `Vec`.
+
+use super::Network;
+use crate::{netlist::Joined, Error, NetList};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use core::str::FromStr;
+
+/// Serialize networks as a comma-separated string.
+pub fn serialize<N, S>(nets: &[N], serializer: S) -> Result<S::Ok, S::Error>
+where
+	N: Network + fmt::Display,
+	S: Serializer,
+{
+	if serializer.is_human_readable() {
+		serializer.collect_str(&Joined(nets))
+	} else {
+		nets.serialize(serializer)
+	}
+}
+
+/// Deserialize networks from a comma-separated string or a sequence.
+pub fn deserialize<'de, N, D>(deserializer: D) -> Result<Vec<N>, D::Error>
+where
+	N: Network + FromStr<Err = Error>,
+	D: Deserializer<'de>,
+{
+	NetList::deserialize(deserializer).map(NetList::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, Netv4Addr};
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Config {
+		#[serde(with = "crate::serde::comma_separated")]
+		allowed: Vec<NetAddr>,
+		#[serde(with = "crate::serde::comma_separated")]
+		denied: Vec<Netv4Addr>,
+	}
+
+	#[test]
+	fn string_round_trip() {
+		let config: Config =
+			serde_json::from_str(r#"{ "allowed": "10.0.0.0/8, 2001:db8::/32", "denied": "" }"#).unwrap();
+
+		assert_eq!(
+			config.allowed,
+			vec![pu!("10.0.0.0/8" # NetAddr), pu!("2001:db8::/32" # NetAddr)]
+		);
+		assert!(config.denied.is_empty());
+		assert_eq!(
+			serde_json::to_string(&config).unwrap(),
+			r#"{"allowed":"10.0.0.0/8,2001:db8::/32","denied":""}"#
+		);
+	}
+
+	#[test]
+	fn accepts_sequences() {
+		let config: Config =
+			serde_json::from_str(r#"{ "allowed": [], "denied": ["192.0.2.0/24"] }"#).unwrap();
+
+		assert_eq!(config.denied, vec![pu!("192.0.2.0/24" # Netv4Addr)]);
+	}
+
+	#[test]
+	fn compact_round_trip() {
+		let config = Config {
+			allowed: vec![pu!("2001:db8::/32" # NetAddr)],
+			denied: vec![pu!("10.0.0.0/255.0.255.0" # Netv4Addr)],
+		};
+		let bytes = bincode::serialize(&config).unwrap();
+
+		assert_eq!(bincode::deserialize::<Config>(&bytes).unwrap(), config);
+	}
+}
@@ -0,0 +1,153 @@
+//! Represent a network strictly as a string in CIDR notation.
+//!
+//! In human-readable formats, networks are serialized as a string like
+//! `"10.0.0.0/8"`, and only strings of that form are accepted when
+//! deserializing.  Unlike the default representation, an address with bits set
+//! outside of the mask (like `"10.0.0.1/8"`) or a prefix length longer than
+//! the address (like `"10.0.0.0/33"`) is rejected rather than normalized.  In
+//! compact formats, the default representation is used.
+//!
+//! Networks without a contiguous mask fail to serialize or deserialize.
+
+use super::{prefix_len, Network};
+use crate::NetAddr;
+use ::serde::{de, ser, Deserializer, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+use core::net::IpAddr;
+
+const NON_CIDR: &str = "network does not have a contiguous mask";
+
+/// Serialize a network as a string in CIDR notation.
+pub fn serialize<N: Network, S: Serializer>(net: &N, serializer: S) -> Result<S::Ok, S::Error> {
+	let cidr: NetAddr = (*net).into();
+
+	if !cidr.is_cidr() {
+		Err(ser::Error::custom(NON_CIDR))
+	} else if serializer.is_human_readable() {
+		serializer.collect_str(&cidr)
+	} else {
+		net.serialize(serializer)
+	}
+}
+
+/// Deserialize a network from a string in CIDR notation, with no bits set
+/// outside of its mask.
+pub fn deserialize<'de, N: Network, D: Deserializer<'de>>(deserializer: D) -> Result<N, D::Error> {
+	if deserializer.is_human_readable() {
+		return deserializer.deserialize_str(StrictVisitor(PhantomData));
+	}
+
+	let net = N::deserialize(deserializer)?;
+
+	if net.into().is_cidr() {
+		Ok(net)
+	} else {
+		Err(de::Error::custom(NON_CIDR))
+	}
+}
+
+struct StrictVisitor<N>(PhantomData<N>);
+
+impl<'de, N: Network> de::Visitor<'de> for StrictVisitor<N> {
+	type Value = N;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a network in CIDR notation with no host bits set")
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		let invalid = || de::Error::invalid_value(de::Unexpected::Str(value), &self);
+
+		let (lhs, rhs) = value.split_once('/').ok_or_else(invalid)?;
+		let addr: IpAddr = lhs.parse().map_err(|_| invalid())?;
+		let prefix: u32 = match rhs.bytes().all(|byte| byte.is_ascii_digit()) {
+			true => rhs.parse().map_err(|_| invalid())?,
+			false => return Err(invalid()),
+		};
+		let net: N = value.parse().map_err(|_| invalid())?;
+		let cidr: NetAddr = net.into();
+
+		if cidr.addr() == addr && prefix_len(&cidr) == Some(prefix) {
+			Ok(net)
+		} else {
+			Err(invalid())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, Netv4Addr, Netv6Addr};
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper<N: super::Network> {
+		#[serde(with = "super", bound = "")]
+		net: N,
+	}
+
+	fn de<N: super::Network>(net: &str) -> Option<N> {
+		serde_json::from_value::<Wrapper<N>>(serde_json::json!({ "net": net }))
+			.ok()
+			.map(|wrapper| wrapper.net)
+	}
+
+	#[test]
+	fn round_trips() {
+		for net in &["10.0.0.0/8", "0.0.0.0/0", "2001:db8::/32", "::1/128"] {
+			let wrapper = Wrapper {
+				net: net.parse::<NetAddr>().unwrap(),
+			};
+			let json = serde_json::to_string(&wrapper).unwrap();
+
+			assert_eq!(json, format!(r#"{{"net":"{}"}}"#, net));
+			assert_eq!(
+				serde_json::from_str::<Wrapper<NetAddr>>(&json).unwrap(),
+				wrapper
+			);
+		}
+	}
+
+	#[test]
+	fn rejects_host_bits() {
+		assert_eq!(de::<Netv4Addr>("10.0.0.1/8"), None);
+		assert_eq!(de::<Netv6Addr>("2001:db8::1/32"), None);
+	}
+
+	#[test]
+	fn rejects_long_prefixes() {
+		assert_eq!(de::<Netv4Addr>("10.0.0.0/33"), None);
+		assert_eq!(de::<NetAddr>("::/129"), None);
+	}
+
+	#[test]
+	fn rejects_other_forms() {
+		assert_eq!(de::<Netv4Addr>("10.0.0.0/255.0.0.0"), None);
+		assert_eq!(de::<Netv4Addr>("10.0.0.0 8"), None);
+		assert_eq!(de::<Netv4Addr>("10.0.0.0/+8"), None);
+		assert_eq!(de::<Netv4Addr>("10.0.0.0"), None);
+		assert_eq!(de::<Netv4Addr>("2001:db8::/32"), None);
+	}
+
+	#[test]
+	fn accepts_cidr() {
+		assert_eq!(
+			de::<Netv4Addr>("10.0.0.0/8"),
+			Some(pu!("10.0.0.0/8" # Netv4Addr))
+		);
+		assert_eq!(
+			de::<NetAddr>("2001:db8::/32"),
+			Some(pu!("2001:db8::/32" # NetAddr))
+		);
+	}
+
+	#[test]
+	fn serializing_non_cidr_fails() {
+		let wrapper = Wrapper {
+			net: pu!("10.0.0.0/255.0.255.0" # Netv4Addr),
+		};
+
+		assert!(serde_json::to_string(&wrapper).is_err());
+	}
+}
@@ -0,0 +1,100 @@
+//! Represent a network as a map of its address and its prefix length or mask.
+//!
+//! In human-readable formats, a network with a contiguous mask is serialized
+//! as `{ "address": "10.0.0.0", "prefix": 8 }`, and any other network as
+//! `{ "address": "10.0.0.0", "mask": "255.0.255.0" }`.  In compact formats,
+//! the default representation is used.
+//!
+//! Deserializing accepts every form that the network type itself accepts.
+
+use super::{prefix_len, Network};
+use crate::NetAddr;
+use ::serde::ser::SerializeStruct;
+use ::serde::{Deserializer, Serializer};
+
+/// Serialize a network as a map of its address and its prefix length or mask.
+pub fn serialize<N: Network, S: Serializer>(net: &N, serializer: S) -> Result<S::Ok, S::Error> {
+	if !serializer.is_human_readable() {
+		return net.serialize(serializer);
+	}
+
+	let net: NetAddr = (*net).into();
+	let mut state = serializer.serialize_struct("Network", 2)?;
+	state.serialize_field("address", &net.addr())?;
+	match prefix_len(&net) {
+		Some(prefix) => state.serialize_field("prefix", &prefix)?,
+		None => state.serialize_field("mask", &net.mask())?,
+	}
+	state.end()
+}
+
+/// Deserialize a network from any of its accepted forms.
+pub fn deserialize<'de, N: Network, D: Deserializer<'de>>(deserializer: D) -> Result<N, D::Error> {
+	N::deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{NetAddr, Netv4Addr, Netv6Addr};
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper<N: super::Network> {
+		#[serde(with = "super", bound = "")]
+		net: N,
+	}
+
+	#[test]
+	fn serializes_cidr_with_prefix() {
+		let wrapper = Wrapper {
+			net: pu!("10.0.0.0/8" # Netv4Addr),
+		};
+
+		assert_eq!(
+			serde_json::to_string(&wrapper).unwrap(),
+			r#"{"net":{"address":"10.0.0.0","prefix":8}}"#
+		);
+	}
+
+	#[test]
+	fn serializes_non_cidr_with_mask() {
+		let wrapper = Wrapper {
+			net: pu!("2001:db8::/ffff::ffff" # Netv6Addr),
+		};
+
+		assert_eq!(
+			serde_json::to_string(&wrapper).unwrap(),
+			r#"{"net":{"address":"2001::","mask":"ffff::ffff"}}"#
+		);
+	}
+
+	#[test]
+	fn round_trips() {
+		for net in &["10.0.0.0/8", "10.0.0.0/255.0.255.0", "2001:db8::/32"] {
+			let wrapper = Wrapper {
+				net: net.parse::<NetAddr>().unwrap(),
+			};
+			let json = serde_json::to_string(&wrapper).unwrap();
+
+			assert_eq!(
+				serde_json::from_str::<Wrapper<NetAddr>>(&json).unwrap(),
+				wrapper
+			);
+		}
+	}
+
+	#[test]
+	fn compact_uses_default_representation() {
+		let net: Netv4Addr = pu!("10.0.0.0/8" # Netv4Addr);
+		let wrapper = Wrapper { net };
+
+		assert_eq!(
+			bincode::serialize(&wrapper).unwrap(),
+			bincode::serialize(&net).unwrap()
+		);
+		assert_eq!(
+			bincode::deserialize::<Wrapper<Netv4Addr>>(&bincode::serialize(&wrapper).unwrap()).unwrap(),
+			wrapper
+		);
+	}
+}
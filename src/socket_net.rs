@@ -0,0 +1,217 @@
+use crate::NetAddr;
+
+/// An IP protocol, by its assigned number.
+///
+/// The common transport protocols have constants, and are displayed and parsed
+/// by name; others are displayed and parsed as numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Protocol;
+/// assert_eq!("tcp".parse(), Ok(Protocol::TCP));
+/// assert_eq!("6".parse(), Ok(Protocol::TCP));
+/// assert_eq!(Protocol::new(47).to_string(), "47");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Protocol(u8);
+
+impl Protocol {
+	/// The Transmission Control Protocol.
+	pub const TCP: Self = Self(6);
+	/// The User Datagram Protocol.
+	pub const UDP: Self = Self(17);
+	/// The Stream Control Transmission Protocol.
+	pub const SCTP: Self = Self(132);
+
+	/// Create a `Protocol` from its assigned number.
+	pub const fn new(number: u8) -> Self {
+		Self(number)
+	}
+
+	/// Get the assigned number of the protocol.
+	pub const fn number(self) -> u8 {
+		self.0
+	}
+
+	/// Get the name of the protocol, if it is one with a constant.
+	pub const fn name(self) -> Option<&'static str> {
+		match self {
+			Self::TCP => Some("tcp"),
+			Self::UDP => Some("udp"),
+			Self::SCTP => Some("sctp"),
+			_ => None,
+		}
+	}
+}
+
+/// An inclusive range of ports.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Contains, PortRange};
+/// let ports: PortRange = "1000-2000".parse().unwrap();
+///
+/// assert!(ports.contains(&1500));
+/// assert!(!ports.contains(&443));
+/// assert_eq!(ports.intersection(&"1500-3000".parse().unwrap()), PortRange::new(1500, 2000));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortRange {
+	start: u16,
+	end: u16,
+}
+
+impl PortRange {
+	/// Every port, `0` through `65535`.
+	pub const ANY: Self = Self {
+		start: 0,
+		end: u16::MAX,
+	};
+
+	/// Create a `PortRange` from `start` to `end` inclusive, or give `None` if
+	/// `start` is after `end`.
+	pub const fn new(start: u16, end: u16) -> Option<Self> {
+		if start <= end {
+			Some(Self { start, end })
+		} else {
+			None
+		}
+	}
+
+	/// Create a `PortRange` of a single port.
+	pub const fn single(port: u16) -> Self {
+		Self {
+			start: port,
+			end: port,
+		}
+	}
+
+	/// Get the first port in the range.
+	pub const fn start(&self) -> u16 {
+		self.start
+	}
+
+	/// Get the last port in the range.
+	pub const fn end(&self) -> u16 {
+		self.end
+	}
+
+	/// Determine whether the range covers every port.
+	pub const fn is_any(&self) -> bool {
+		self.start == 0 && self.end == u16::MAX
+	}
+
+	/// Determine whether this range shares any ports with `other`.
+	pub const fn overlaps(&self, other: &Self) -> bool {
+		self.start <= other.end && other.start <= self.end
+	}
+
+	/// Get the ports in both this range and `other`, if there are any.
+	pub fn intersection(&self, other: &Self) -> Option<Self> {
+		Self::new(self.start.max(other.start), self.end.min(other.end))
+	}
+
+	/// Get a single range covering exactly the ports of both this range and
+	/// `other`, if they overlap or are adjacent.
+	pub fn union(&self, other: &Self) -> Option<Self> {
+		let (first, second) = if self.start <= other.start {
+			(self, other)
+		} else {
+			(other, self)
+		};
+
+		if u32::from(second.start) <= u32::from(first.end) + 1 {
+			Self::new(first.start, first.end.max(second.end))
+		} else {
+			None
+		}
+	}
+}
+
+/// A network together with a protocol and a range of ports, as in a firewall
+/// rule.
+///
+/// A `SocketNet` stands for every combination of an address in its network, a
+/// port in its range, and its protocol, or any protocol if it has none.
+///
+/// # Parsing and display
+///
+/// The network may be followed by a `:` and a port range, which is a port, two
+/// ports separated by a `-`, or `*` for any port; the port range may then be
+/// followed by a `/` and a protocol.  An IPv6 network must be enclosed in
+/// brackets if ports follow it.  A network without a netmask is a single
+/// address.
+///
+/// - `10.0.0.0/8` is any port on any protocol
+/// - `10.0.0.0/8:443` and `[2001:db8::/32]:1000-2000` are port ranges on any
+///   protocol
+/// - `192.0.2.1:53/udp` and `10.0.0.0/8:*/tcp` are restricted to a protocol
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{Contains, SocketNet};
+/// # use std::net::SocketAddr;
+/// let https: SocketNet = "10.0.0.0/8:443/tcp".parse().unwrap();
+/// let web: SocketNet = "10.0.0.0/8:80-443".parse().unwrap();
+///
+/// assert!(https.contains(&"10.1.2.3:443".parse::<SocketAddr>().unwrap()));
+/// assert!(https.is_subset_of(&web));
+/// assert_eq!(
+///     "[2001:db8::/32]:1000-2000".parse::<SocketNet>().unwrap().to_string(),
+///     "[2001:db8::/32]:1000-2000"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SocketNet {
+	net: NetAddr,
+	protocol: Option<Protocol>,
+	ports: PortRange,
+}
+
+impl SocketNet {
+	/// Create a `SocketNet` of `net`, on any port and protocol.
+	pub fn new<N: Into<NetAddr>>(net: N) -> Self {
+		Self {
+			net: net.into(),
+			protocol: None,
+			ports: PortRange::ANY,
+		}
+	}
+
+	/// Restrict to the given protocol.
+	pub const fn with_protocol(self, protocol: Protocol) -> Self {
+		Self {
+			protocol: Some(protocol),
+			..self
+		}
+	}
+
+	/// Restrict to the given ports.
+	pub const fn with_ports(self, ports: PortRange) -> Self {
+		Self { ports, ..self }
+	}
+
+	/// Get the network.
+	pub const fn net(&self) -> NetAddr {
+		self.net
+	}
+
+	/// Get the protocol, or `None` for any protocol.
+	pub const fn protocol(&self) -> Option<Protocol> {
+		self.protocol
+	}
+
+	/// Get the port range.
+	pub const fn ports(&self) -> PortRange {
+		self.ports
+	}
+}
+
+mod contains;
+mod display;
+mod fromstr;
+mod merge;
+mod relation;
@@ -0,0 +1,60 @@
+use super::{PortRange, SocketNet};
+use crate::Contains;
+use core::net::SocketAddr;
+
+impl Contains<u16> for PortRange {
+	fn contains(&self, other: &u16) -> bool {
+		self.start <= *other && *other <= self.end
+	}
+}
+
+impl Contains<PortRange> for PortRange {
+	fn contains(&self, other: &PortRange) -> bool {
+		self.start <= other.start && other.end <= self.end
+	}
+}
+
+impl Contains<SocketAddr> for SocketNet {
+	/// Determine whether the address and port of `other` are in this
+	/// `SocketNet`.
+	///
+	/// A `SocketAddr` has no protocol, so the protocol is not considered.
+	fn contains(&self, other: &SocketAddr) -> bool {
+		self.net.contains(&other.ip()) && self.ports.contains(&other.port())
+	}
+}
+
+impl Contains<SocketNet> for SocketNet {
+	/// Determine whether every address, port, and protocol of `other` is in this
+	/// `SocketNet`.
+	fn contains(&self, other: &SocketNet) -> bool {
+		let protocol: bool = self.protocol.is_none() || self.protocol == other.protocol;
+
+		protocol && self.ports.contains(&other.ports) && self.net.contains(&other.net)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn socket_addrs() {
+		assert!(pu!("10.0.0.0/8:443" # SocketNet).contains(&pu!("10.1.2.3:443" # SocketAddr)));
+		assert!(pu!("10.0.0.0/8" # SocketNet).contains(&pu!("10.1.2.3:1" # SocketAddr)));
+		assert!(pu!("[2001:db8::/32]:1000-2000" # SocketNet)
+			.contains(&pu!("[2001:db8::1]:1000" # SocketAddr)));
+		assert!(!pu!("10.0.0.0/8:443" # SocketNet).contains(&pu!("10.1.2.3:80" # SocketAddr)));
+		assert!(!pu!("10.0.0.0/8:443" # SocketNet).contains(&pu!("192.0.2.1:443" # SocketAddr)));
+		assert!(!pu!("10.0.0.0/8:443" # SocketNet).contains(&pu!("[::ffff:10.1.2.3]:443" # SocketAddr)));
+	}
+
+	#[test]
+	fn socket_nets() {
+		assert!(pu!("10.0.0.0/8" # SocketNet).contains(&pu!("10.0.0.0/16:443/tcp" # SocketNet)));
+		assert!(pu!("10.0.0.0/8:*/tcp" # SocketNet).contains(&pu!("10.0.0.0/16:443/tcp" # SocketNet)));
+		assert!(!pu!("10.0.0.0/8:*/udp" # SocketNet).contains(&pu!("10.0.0.0/16:443/tcp" # SocketNet)));
+		assert!(!pu!("10.0.0.0/8:*/tcp" # SocketNet).contains(&pu!("10.0.0.0/16:443" # SocketNet)));
+		assert!(!pu!("10.0.0.0/8:443" # SocketNet).contains(&pu!("10.0.0.0/16:443-444" # SocketNet)));
+	}
+}
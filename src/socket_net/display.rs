@@ -0,0 +1,89 @@
+use super::{PortRange, Protocol, SocketNet};
+use crate::NetAddr;
+use core::fmt;
+
+impl fmt::Display for Protocol {
+	/// Write the name of the protocol, or its number if it has no name.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.name() {
+			Some(name) => f.write_str(name),
+			None => fmt::Display::fmt(&self.number(), f),
+		}
+	}
+}
+
+impl fmt::Display for PortRange {
+	/// Write the range as a single port, two ports separated by a `-`, or `*`
+	/// for every port.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_any() {
+			f.write_str("*")
+		} else if self.start == self.end {
+			write!(f, "{}", self.start)
+		} else {
+			write!(f, "{}-{}", self.start, self.end)
+		}
+	}
+}
+
+impl fmt::Display for SocketNet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let restricted: bool = !self.ports.is_any() || self.protocol.is_some();
+
+		match self.net {
+			NetAddr::V6(net) if restricted => write!(f, "[{}]", net)?,
+			net => write!(f, "{}", net)?,
+		}
+
+		if restricted {
+			write!(f, ":{}", self.ports)?;
+		}
+
+		match self.protocol {
+			Some(protocol) => write!(f, "/{}", protocol),
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn protocols() {
+		assert_eq!(Protocol::TCP.to_string(), "tcp");
+		assert_eq!(Protocol::SCTP.to_string(), "sctp");
+		assert_eq!(Protocol::new(1).to_string(), "1");
+	}
+
+	#[test]
+	fn port_ranges() {
+		assert_eq!(PortRange::ANY.to_string(), "*");
+		assert_eq!(PortRange::single(443).to_string(), "443");
+		assert_eq!(PortRange::new(0, 1023).unwrap().to_string(), "0-1023");
+	}
+
+	#[test]
+	fn socket_nets() {
+		let net = |net: &str| net.parse::<NetAddr>().unwrap();
+
+		assert_eq!(SocketNet::new(net("10.0.0.0/8")).to_string(), "10.0.0.0/8");
+		assert_eq!(
+			SocketNet::new(net("10.0.0.0/8"))
+				.with_ports(PortRange::single(443))
+				.to_string(),
+			"10.0.0.0/8:443"
+		);
+		assert_eq!(
+			SocketNet::new(net("2001:db8::/32"))
+				.with_protocol(Protocol::UDP)
+				.to_string(),
+			"[2001:db8::/32]:*/udp"
+		);
+		assert_eq!(
+			SocketNet::new(net("2001:db8::/32")).to_string(),
+			"2001:db8::/32"
+		);
+	}
+}
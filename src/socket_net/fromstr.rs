@@ -0,0 +1,174 @@
+use super::{PortRange, Protocol, SocketNet};
+use crate::{Error, NetAddr, Result};
+use core::net::IpAddr;
+use core::str::FromStr;
+
+impl FromStr for Protocol {
+	type Err = Error;
+
+	/// Parse a `Protocol` from its name, in any case, or its number.
+	fn from_str(string: &str) -> Result<Self> {
+		for protocol in &[Self::TCP, Self::UDP, Self::SCTP] {
+			if protocol
+				.name()
+				.is_some_and(|name| name.eq_ignore_ascii_case(string))
+			{
+				return Ok(*protocol);
+			}
+		}
+
+		string
+			.parse()
+			.map(Self::new)
+			.map_err(|_| Error::ParseError("invalid protocol"))
+	}
+}
+
+impl FromStr for PortRange {
+	type Err = Error;
+
+	/// Parse a `PortRange` from a single port like `443`, two ports separated by
+	/// a `-` like `1000-2000`, or `*` for every port.
+	fn from_str(string: &str) -> Result<Self> {
+		let port = |port: &str| {
+			port
+				.parse::<u16>()
+				.map_err(|_| Error::ParseError("invalid port"))
+		};
+
+		match string.split_once('-') {
+			_ if string == "*" => Ok(Self::ANY),
+			Some((start, end)) => Self::new(port(start)?, port(end)?)
+				.ok_or(Error::ParseError("port range ends before it starts")),
+			None => port(string).map(Self::single),
+		}
+	}
+}
+
+impl FromStr for SocketNet {
+	type Err = Error;
+
+	/// Parse a `SocketNet`; see its documentation for the format.
+	fn from_str(string: &str) -> Result<Self> {
+		let (net, rest): (&str, Option<&str>) = match string.strip_prefix('[') {
+			Some(inner) => {
+				let (net, rest) = inner
+					.split_once(']')
+					.ok_or(Error::ParseError("missing closing bracket"))?;
+
+				if rest.is_empty() {
+					(net, None)
+				} else {
+					let rest = rest
+						.strip_prefix(':')
+						.ok_or(Error::ParseError("expected ports after closing bracket"))?;
+					(net, Some(rest))
+				}
+			}
+			// Only an IPv4 network can be followed by ports without brackets, and it
+			// has no colons of its own.
+			None if string.matches(':').count() == 1 => match string.split_once(':') {
+				Some((net, rest)) => (net, Some(rest)),
+				None => (string, None),
+			},
+			None => (string, None),
+		};
+
+		let net: NetAddr = if net.contains(['/', ' ']) {
+			net.parse()?
+		} else {
+			net.parse::<IpAddr>()?.into()
+		};
+
+		let mut socket: Self = Self::new(net);
+
+		if let Some(rest) = rest {
+			let (ports, protocol) = match rest.split_once('/') {
+				Some((ports, protocol)) => (ports, Some(protocol.parse()?)),
+				None => (rest, None),
+			};
+
+			socket.ports = ports.parse()?;
+			socket.protocol = protocol;
+		}
+
+		Ok(socket)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn protocols() {
+		assert_eq!("TCP".parse(), Ok(Protocol::TCP));
+		assert_eq!("17".parse(), Ok(Protocol::UDP));
+		assert_eq!("47".parse(), Ok(Protocol::new(47)));
+		assert!("256".parse::<Protocol>().is_err());
+		assert!("zoop".parse::<Protocol>().is_err());
+	}
+
+	#[test]
+	fn port_ranges() {
+		assert_eq!("*".parse(), Ok(PortRange::ANY));
+		assert_eq!("0-65535".parse(), Ok(PortRange::ANY));
+		assert_eq!("443".parse(), Ok(PortRange::single(443)));
+		assert_eq!("1000-2000".parse(), Ok(PortRange::new(1000, 2000).unwrap()));
+		assert!("2000-1000".parse::<PortRange>().is_err());
+		assert!("65536".parse::<PortRange>().is_err());
+		assert!("-1".parse::<PortRange>().is_err());
+		assert!("".parse::<PortRange>().is_err());
+	}
+
+	#[test]
+	fn socket_nets() {
+		let net = |net: &str| net.parse::<NetAddr>().unwrap();
+
+		assert_eq!("10.0.0.0/8".parse(), Ok(SocketNet::new(net("10.0.0.0/8"))));
+		assert_eq!(
+			"10.0.0.0/8:443".parse(),
+			Ok(SocketNet::new(net("10.0.0.0/8")).with_ports(PortRange::single(443)))
+		);
+		assert_eq!(
+			"[2001:db8::/32]:1000-2000".parse(),
+			Ok(SocketNet::new(net("2001:db8::/32")).with_ports(PortRange::new(1000, 2000).unwrap()))
+		);
+		assert_eq!(
+			"192.0.2.1:53/udp".parse(),
+			Ok(
+				SocketNet::new(net("192.0.2.1/32"))
+					.with_ports(PortRange::single(53))
+					.with_protocol(Protocol::UDP)
+			)
+		);
+		assert_eq!(
+			"2001:db8::/32".parse(),
+			Ok(SocketNet::new(net("2001:db8::/32")))
+		);
+		assert_eq!("[::1]".parse(), Ok(SocketNet::new(net("::1/128"))));
+	}
+
+	#[test]
+	fn round_trips() {
+		for string in &[
+			"10.0.0.0/8",
+			"10.0.0.0/8:443",
+			"10.0.0.0/8:*/tcp",
+			"[2001:db8::/32]:1000-2000/sctp",
+			"192.0.2.1/32:53/17",
+		] {
+			let socket: SocketNet = string.parse().unwrap();
+			assert_eq!(socket.to_string(), string.replace("/17", "/udp"));
+		}
+	}
+
+	#[test]
+	fn errors() {
+		assert!("[2001:db8::/32".parse::<SocketNet>().is_err());
+		assert!("[2001:db8::/32]443".parse::<SocketNet>().is_err());
+		assert!("10.0.0.0/8:".parse::<SocketNet>().is_err());
+		assert!("10.0.0.0/8:443/zoop".parse::<SocketNet>().is_err());
+		assert!("zoop:443".parse::<SocketNet>().is_err());
+	}
+}
@@ -0,0 +1,86 @@
+use super::SocketNet;
+use crate::{Contains, Merge, MergeResult};
+
+impl Merge for SocketNet {
+	type Output = Option<Self>;
+
+	/// Merge two `SocketNet`s into one covering exactly both, if there is one.
+	///
+	/// This is the case when one contains the other, when they differ only in
+	/// ports and their ranges overlap or are adjacent, or when they differ only
+	/// in networks and those merge.
+	fn merge(&self, other: &Self) -> Self::Output {
+		if self.contains(other) {
+			Some(*self)
+		} else if other.contains(self) {
+			Some(*other)
+		} else if self.protocol != other.protocol {
+			None
+		} else if self.net == other.net {
+			self
+				.ports
+				.union(&other.ports)
+				.map(|ports| Self { ports, ..*self })
+		} else if self.ports == other.ports {
+			match self.net.merge(&other.net) {
+				MergeResult::Merged(net) => Some(Self { net, ..*self }),
+				_ => None,
+			}
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn containment() {
+		assert_eq!(
+			pu!("10.0.0.0/8:*/tcp" # SocketNet).merge(&pu!("10.1.0.0/16:443/tcp" # SocketNet)),
+			Some(pu!("10.0.0.0/8:*/tcp" # SocketNet))
+		);
+		assert_eq!(
+			pu!("10.1.0.0/16:443/tcp" # SocketNet).merge(&pu!("10.0.0.0/8" # SocketNet)),
+			Some(pu!("10.0.0.0/8" # SocketNet))
+		);
+	}
+
+	#[test]
+	fn ports() {
+		assert_eq!(
+			pu!("10.0.0.0/8:80-443" # SocketNet).merge(&pu!("10.0.0.0/8:444-8080" # SocketNet)),
+			Some(pu!("10.0.0.0/8:80-8080" # SocketNet))
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8:80-443/tcp" # SocketNet).merge(&pu!("10.0.0.0/8:100-8080/tcp" # SocketNet)),
+			Some(pu!("10.0.0.0/8:80-8080/tcp" # SocketNet))
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8:80" # SocketNet).merge(&pu!("10.0.0.0/8:443" # SocketNet)),
+			None
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8:80/tcp" # SocketNet).merge(&pu!("10.0.0.0/8:81/udp" # SocketNet)),
+			None
+		);
+	}
+
+	#[test]
+	fn nets() {
+		assert_eq!(
+			pu!("10.0.0.0/24:53/udp" # SocketNet).merge(&pu!("10.0.1.0/24:53/udp" # SocketNet)),
+			Some(pu!("10.0.0.0/23:53/udp" # SocketNet))
+		);
+		assert_eq!(
+			pu!("10.0.0.0/24:53" # SocketNet).merge(&pu!("10.0.1.0/24:54" # SocketNet)),
+			None
+		);
+		assert_eq!(
+			pu!("10.0.0.0/24:53" # SocketNet).merge(&pu!("[::/120]:53" # SocketNet)),
+			None
+		);
+	}
+}
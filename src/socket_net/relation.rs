@@ -0,0 +1,117 @@
+use super::{Protocol, SocketNet};
+use crate::Contains;
+
+/// Get the protocol common to `a` and `b`, where `None` is any protocol, or
+/// `Err` if they have none in common.
+fn protocol_intersection(a: Option<Protocol>, b: Option<Protocol>) -> Result<Option<Protocol>, ()> {
+	match (a, b) {
+		(Some(a), Some(b)) if a != b => Err(()),
+		(Some(protocol), _) | (_, Some(protocol)) => Ok(Some(protocol)),
+		(None, None) => Ok(None),
+	}
+}
+
+impl SocketNet {
+	/// Determine whether this `SocketNet` shares any address, port, and
+	/// protocol with `other`.
+	pub fn overlaps(&self, other: &Self) -> bool {
+		protocol_intersection(self.protocol, other.protocol).is_ok()
+			&& self.ports.overlaps(&other.ports)
+			&& self.net.overlaps(&other.net)
+	}
+
+	/// Determine whether everything in this `SocketNet` is in `other`.
+	///
+	/// A `SocketNet` is a subset of itself.
+	pub fn is_subset_of(&self, other: &Self) -> bool {
+		other.contains(self)
+	}
+
+	/// Determine whether everything in `other` is in this `SocketNet`.
+	///
+	/// A `SocketNet` is a superset of itself.
+	pub fn is_superset_of(&self, other: &Self) -> bool {
+		self.contains(other)
+	}
+
+	/// Determine whether this `SocketNet` shares nothing with `other`.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		!self.overlaps(other)
+	}
+
+	/// Get the `SocketNet` of everything in both this one and `other`, if they
+	/// overlap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::SocketNet;
+	/// let a: SocketNet = "10.0.0.0/8:1000-2000".parse().unwrap();
+	/// let b: SocketNet = "10.1.0.0/16:1500-3000/tcp".parse().unwrap();
+	///
+	/// assert_eq!(a.intersection(&b), Some("10.1.0.0/16:1500-2000/tcp".parse().unwrap()));
+	/// assert_eq!(a.intersection(&"192.0.2.0/24".parse().unwrap()), None);
+	/// ```
+	pub fn intersection(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			net: self.net.intersection(&other.net)?,
+			protocol: protocol_intersection(self.protocol, other.protocol).ok()?,
+			ports: self.ports.intersection(&other.ports)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn predicates() {
+		let a = pu!("10.0.0.0/8:1000-2000" # SocketNet);
+		let b = pu!("10.1.0.0/16:1500/tcp" # SocketNet);
+		let c = pu!("10.0.0.0/8:3000" # SocketNet);
+		let d = pu!("[2001:db8::/32]:1500" # SocketNet);
+
+		assert!(a.overlaps(&b) && b.overlaps(&a));
+		assert!(b.is_subset_of(&a) && !a.is_subset_of(&b));
+		assert!(a.is_superset_of(&b) && a.is_superset_of(&a));
+		assert!(a.is_disjoint(&c) && a.is_disjoint(&d));
+		assert!(pu!("10.0.0.0/8:*/tcp" # SocketNet).is_disjoint(&pu!("10.0.0.0/8:*/udp" # SocketNet)));
+	}
+
+	#[test]
+	fn intersections() {
+		assert_eq!(
+			pu!("10.0.0.0/255.0.255.0:*/udp" # SocketNet)
+				.intersection(&pu!("10.1.0.0/16:53" # SocketNet)),
+			Some(pu!("10.1.0.0/255.255.255.0:53/udp" # SocketNet))
+		);
+		assert_eq!(
+			pu!("[2001:db8::/32]:1-100" # SocketNet)
+				.intersection(&pu!("[2001:db8:1::/48]:100-200" # SocketNet)),
+			Some(pu!("[2001:db8:1::/48]:100" # SocketNet))
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8:*/tcp" # SocketNet).intersection(&pu!("10.0.0.0/8:*/udp" # SocketNet)),
+			None
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8:1-100" # SocketNet).intersection(&pu!("10.0.0.0/8:200" # SocketNet)),
+			None
+		);
+		assert_eq!(
+			pu!("10.0.0.0/8" # SocketNet).intersection(&pu!("::/0" # SocketNet)),
+			None
+		);
+	}
+
+	#[test]
+	fn intersection_is_contained_by_both() {
+		let a = pu!("10.0.0.0/255.0.255.0:1000-2000" # SocketNet);
+		let b = pu!("10.0.0.0/255.255.0.0:1500-2500/tcp" # SocketNet);
+		let both = a.intersection(&b).unwrap();
+
+		assert!(a.contains(&both) && b.contains(&both));
+		assert!(a.overlaps(&b));
+	}
+}
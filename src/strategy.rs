@@ -0,0 +1,262 @@
+//! [`proptest`] strategies for generating networks and addresses.
+//!
+//! Networks generated by [`netv4addr`], [`netv6addr`], and [`netaddr`] are
+//! split evenly between CIDR and non-CIDR netmasks, since a uniformly random
+//! netmask is almost never CIDR.  The `contained_*` strategies generate values
+//! which are guaranteed to be contained within a given network, including
+//! ranges of addresses between two of its addresses.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use netaddr2::Contains;
+//! use netaddr2::strategy::{cidr_netv4addr, contained_ipv4addr};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn addresses_are_contained(
+//!         (net, addr) in cidr_netv4addr().prop_flat_map(|net| (Just(net), contained_ipv4addr(net)))
+//!     ) {
+//!         prop_assert!(net.contains(&addr));
+//!     }
+//! }
+//! # addresses_are_contained();
+//! ```
+
+use crate::iter::host::Hosts;
+use crate::{NetAddr, Netv4Addr, Netv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::ops::RangeInclusive;
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+/// Generate `Netv4Addr`s with CIDR netmasks (`/0` through `/32`).
+pub fn cidr_netv4addr() -> impl Strategy<Value = Netv4Addr> {
+	(any::<u32>(), 0..=32_u32).prop_map(|(addr, len)| {
+		let mask: u32 = u32::MAX.checked_shl(32 - len).unwrap_or(0);
+		Netv4Addr::new(addr.into(), mask.into())
+	})
+}
+
+/// Generate `Netv4Addr`s with non-CIDR netmasks.
+pub fn non_cidr_netv4addr() -> impl Strategy<Value = Netv4Addr> {
+	(any::<u32>(), any::<u32>())
+		.prop_map(|(addr, mask)| Netv4Addr::new(addr.into(), mask.into()))
+		.prop_filter("netmask must not be CIDR", |net| !net.is_cidr())
+}
+
+/// Generate `Netv4Addr`s with either CIDR or non-CIDR netmasks.
+pub fn netv4addr() -> impl Strategy<Value = Netv4Addr> {
+	prop_oneof![cidr_netv4addr(), non_cidr_netv4addr()]
+}
+
+/// Generate `Netv6Addr`s with CIDR netmasks (`/0` through `/128`).
+pub fn cidr_netv6addr() -> impl Strategy<Value = Netv6Addr> {
+	(any::<u128>(), 0..=128_u32).prop_map(|(addr, len)| {
+		let mask: u128 = u128::MAX.checked_shl(128 - len).unwrap_or(0);
+		Netv6Addr::new(addr.into(), mask.into())
+	})
+}
+
+/// Generate `Netv6Addr`s with non-CIDR netmasks.
+pub fn non_cidr_netv6addr() -> impl Strategy<Value = Netv6Addr> {
+	(any::<u128>(), any::<u128>())
+		.prop_map(|(addr, mask)| Netv6Addr::new(addr.into(), mask.into()))
+		.prop_filter("netmask must not be CIDR", |net| !net.is_cidr())
+}
+
+/// Generate `Netv6Addr`s with either CIDR or non-CIDR netmasks.
+pub fn netv6addr() -> impl Strategy<Value = Netv6Addr> {
+	prop_oneof![cidr_netv6addr(), non_cidr_netv6addr()]
+}
+
+/// Generate `NetAddr`s of either family with CIDR netmasks.
+pub fn cidr_netaddr() -> impl Strategy<Value = NetAddr> {
+	prop_oneof![
+		cidr_netv4addr().prop_map(NetAddr::V4),
+		cidr_netv6addr().prop_map(NetAddr::V6),
+	]
+}
+
+/// Generate `NetAddr`s of either family with non-CIDR netmasks.
+pub fn non_cidr_netaddr() -> impl Strategy<Value = NetAddr> {
+	prop_oneof![
+		non_cidr_netv4addr().prop_map(NetAddr::V4),
+		non_cidr_netv6addr().prop_map(NetAddr::V6),
+	]
+}
+
+/// Generate `NetAddr`s of either family with either CIDR or non-CIDR netmasks.
+pub fn netaddr() -> impl Strategy<Value = NetAddr> {
+	prop_oneof![
+		netv4addr().prop_map(NetAddr::V4),
+		netv6addr().prop_map(NetAddr::V6)
+	]
+}
+
+/// Generate addresses contained within `net`.
+pub fn contained_ipv4addr(net: Netv4Addr) -> impl Strategy<Value = Ipv4Addr> {
+	any::<u32>().prop_map(move |index| net.host(u128::from(index)))
+}
+
+/// Generate addresses contained within `net`.
+pub fn contained_ipv6addr(net: Netv6Addr) -> impl Strategy<Value = Ipv6Addr> {
+	any::<u128>().prop_map(move |index| net.host(index))
+}
+
+/// Generate addresses contained within `net`.
+pub fn contained_ipaddr(net: NetAddr) -> impl Strategy<Value = IpAddr> {
+	any::<u128>().prop_map(move |index| net.host(index))
+}
+
+/// Generate ranges of addresses from one address contained within `net` to a
+/// later one.
+///
+/// For a CIDR `net`, every address of the range is contained within it; for a
+/// non-CIDR one, only the addresses at either end are sure to be.
+pub fn contained_ipv4addr_range(net: Netv4Addr) -> impl Strategy<Value = RangeInclusive<Ipv4Addr>> {
+	(contained_ipv4addr(net), contained_ipv4addr(net)).prop_map(|(a, b)| a.min(b)..=a.max(b))
+}
+
+/// Generate ranges of addresses from one address contained within `net` to a
+/// later one.
+///
+/// See [`contained_ipv4addr_range`] for which addresses are contained.
+pub fn contained_ipv6addr_range(net: Netv6Addr) -> impl Strategy<Value = RangeInclusive<Ipv6Addr>> {
+	(contained_ipv6addr(net), contained_ipv6addr(net)).prop_map(|(a, b)| a.min(b)..=a.max(b))
+}
+
+/// Generate ranges of addresses from one address contained within `net` to a
+/// later one.
+///
+/// See [`contained_ipv4addr_range`] for which addresses are contained.
+pub fn contained_ipaddr_range(net: NetAddr) -> impl Strategy<Value = RangeInclusive<IpAddr>> {
+	(contained_ipaddr(net), contained_ipaddr(net)).prop_map(|(a, b)| a.min(b)..=a.max(b))
+}
+
+/// Generate networks contained within `net`.
+///
+/// The generated networks have netmasks which include every bit of `net`'s
+/// netmask, plus any number of its host bits.
+pub fn contained_netv4addr(net: Netv4Addr) -> impl Strategy<Value = Netv4Addr> {
+	(contained_ipv4addr(net), any::<u32>()).prop_map(move |(addr, extra)| {
+		let mask: u32 = u32::from(net.mask()) | extra;
+		Netv4Addr::new(addr, mask.into())
+	})
+}
+
+/// Generate networks contained within `net`.
+///
+/// The generated networks have netmasks which include every bit of `net`'s
+/// netmask, plus any number of its host bits.
+pub fn contained_netv6addr(net: Netv6Addr) -> impl Strategy<Value = Netv6Addr> {
+	(contained_ipv6addr(net), any::<u128>()).prop_map(move |(addr, extra)| {
+		let mask: u128 = u128::from(net.mask()) | extra;
+		Netv6Addr::new(addr, mask.into())
+	})
+}
+
+/// Generate networks contained within `net`.
+pub fn contained_netaddr(net: NetAddr) -> BoxedStrategy<NetAddr> {
+	match net {
+		NetAddr::V4(v4) => contained_netv4addr(v4).prop_map(NetAddr::V4).boxed(),
+		NetAddr::V6(v6) => contained_netv6addr(v6).prop_map(NetAddr::V6).boxed(),
+	}
+}
+
+impl Arbitrary for Netv4Addr {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+		netv4addr().boxed()
+	}
+}
+
+impl Arbitrary for Netv6Addr {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+		netv6addr().boxed()
+	}
+}
+
+impl Arbitrary for NetAddr {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+		netaddr().boxed()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Contains;
+
+	proptest! {
+		#[test]
+		fn cidr_netv4addr_is_cidr(net in cidr_netv4addr()) {
+			prop_assert!(net.is_cidr());
+		}
+
+		#[test]
+		fn non_cidr_netv4addr_is_not_cidr(net in non_cidr_netv4addr()) {
+			prop_assert!(!net.is_cidr());
+		}
+
+		#[test]
+		fn cidr_netv6addr_is_cidr(net in cidr_netv6addr()) {
+			prop_assert!(net.is_cidr());
+		}
+
+		#[test]
+		fn non_cidr_netv6addr_is_not_cidr(net in non_cidr_netv6addr()) {
+			prop_assert!(!net.is_cidr());
+		}
+
+		#[test]
+		fn cidr_netaddr_is_cidr(net in cidr_netaddr()) {
+			prop_assert!(net.is_cidr());
+		}
+
+		#[test]
+		fn non_cidr_netaddr_is_not_cidr(net in non_cidr_netaddr()) {
+			prop_assert!(!net.is_cidr());
+		}
+
+		#[test]
+		fn contained_netaddr_is_contained(
+			(net, inner) in any::<NetAddr>().prop_flat_map(|net| (Just(net), contained_netaddr(net)))
+		) {
+			prop_assert!(net.contains(&inner));
+		}
+
+		#[test]
+		fn contained_ipaddr_is_contained(
+			(net, addr) in any::<NetAddr>().prop_flat_map(|net| (Just(net), contained_ipaddr(net)))
+		) {
+			prop_assert!(net.contains(&addr));
+		}
+
+		#[test]
+		fn contained_ipaddr_range_is_contained(
+			(net, range) in any::<NetAddr>().prop_flat_map(|net| (Just(net), contained_ipaddr_range(net)))
+		) {
+			prop_assert!(range.start() <= range.end());
+			prop_assert!(net.contains(range.start()));
+			prop_assert!(net.contains(range.end()));
+		}
+
+		#[test]
+		fn cidr_contained_ipv4addr_range_is_contiguous(
+			(net, range) in cidr_netv4addr().prop_flat_map(|net| (Just(net), contained_ipv4addr_range(net)))
+		) {
+			let (start, end): (u32, u32) = ((*range.start()).into(), (*range.end()).into());
+			prop_assert!(net.contains(&Ipv4Addr::from(start + (end - start) / 2)));
+		}
+	}
+}
@@ -1,8 +1,14 @@
 mod broadcast;
 mod contains;
+mod distance;
+mod ip_network;
 mod mask;
 mod merge;
+mod offset;
 pub use broadcast::*;
 pub use contains::*;
+pub use distance::*;
+pub use ip_network::*;
 pub use mask::*;
 pub use merge::*;
+pub use offset::*;
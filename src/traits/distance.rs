@@ -0,0 +1,122 @@
+use core::convert::TryFrom;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Count the addresses from one address to another
+///
+/// `a.distance(&b)` is the signed number of addresses from `a` to `b`; that
+/// is, `b - a`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Distance;
+/// # use std::net::Ipv4Addr;
+/// let a: Ipv4Addr = "192.0.2.0".parse().unwrap();
+/// let b: Ipv4Addr = "192.0.3.4".parse().unwrap();
+/// assert_eq!(a.distance(&b), 260);
+/// assert_eq!(b.distance(&a), -260);
+/// ```
+pub trait Distance {
+	/// The type of the distance
+	type Output;
+
+	/// Compute the distance from this address to `other`
+	fn distance(&self, other: &Self) -> Self::Output;
+}
+
+impl Distance for Ipv4Addr {
+	type Output = i64;
+
+	fn distance(&self, other: &Self) -> i64 {
+		i64::from(u32::from(*other)) - i64::from(u32::from(*self))
+	}
+}
+
+/// Gives `None` if the distance does not fit in an `i128`, which is only the
+/// case for addresses more than `2^127 - 1` apart.
+impl Distance for Ipv6Addr {
+	type Output = Option<i128>;
+
+	fn distance(&self, other: &Self) -> Option<i128> {
+		let (from, to): (u128, u128) = ((*self).into(), (*other).into());
+
+		if to >= from {
+			i128::try_from(to - from).ok()
+		} else {
+			// Computing the magnitude first allows for exactly `i128::MIN`.
+			(from - to)
+				.checked_sub(1)
+				.and_then(|magnitude| i128::try_from(magnitude).ok())
+				.map(|magnitude| -magnitude - 1)
+		}
+	}
+}
+
+/// Gives `None` for addresses of different families, or if the distance does
+/// not fit in an `i128`.
+impl Distance for IpAddr {
+	type Output = Option<i128>;
+
+	fn distance(&self, other: &Self) -> Option<i128> {
+		match (self, other) {
+			(Self::V4(a), Self::V4(b)) => Some(a.distance(b).into()),
+			(Self::V6(a), Self::V6(b)) => a.distance(b),
+			(_, _) => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn v4() {
+		let a: Ipv4Addr = "10.0.0.0".parse().unwrap();
+		let b: Ipv4Addr = "10.0.1.0".parse().unwrap();
+
+		assert_eq!(a.distance(&b), 256);
+		assert_eq!(b.distance(&a), -256);
+		assert_eq!(a.distance(&a), 0);
+		assert_eq!(
+			Ipv4Addr::UNSPECIFIED.distance(&Ipv4Addr::BROADCAST),
+			i64::from(u32::MAX)
+		);
+	}
+
+	#[test]
+	fn v6() {
+		let a: Ipv6Addr = "2001:db8::".parse().unwrap();
+		let b: Ipv6Addr = "2001:db8::1:0".parse().unwrap();
+
+		assert_eq!(a.distance(&b), Some(65536));
+		assert_eq!(b.distance(&a), Some(-65536));
+	}
+
+	#[test]
+	fn v6_extremes() {
+		let min: Ipv6Addr = Ipv6Addr::UNSPECIFIED;
+		let max: Ipv6Addr = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap();
+		let half: Ipv6Addr = "8000::".parse().unwrap();
+
+		assert_eq!(min.distance(&max), None);
+		assert_eq!(max.distance(&min), None);
+		assert_eq!(half.distance(&min), Some(i128::MIN));
+		assert_eq!(min.distance(&half), None);
+		assert_eq!(
+			min.distance(&"7fff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()),
+			Some(i128::MAX)
+		);
+	}
+
+	#[test]
+	fn ipaddr() {
+		let a: IpAddr = "10.0.0.0".parse().unwrap();
+		let b: IpAddr = "10.0.0.10".parse().unwrap();
+		let c: IpAddr = "::1".parse().unwrap();
+
+		assert_eq!(a.distance(&b), Some(10));
+		assert_eq!(a.distance(&c), None);
+		assert_eq!(c.distance(&a), None);
+	}
+}
@@ -0,0 +1,189 @@
+use crate::iter::host::Hosts;
+use crate::{Contains, Error, Merge};
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+use core::str::FromStr;
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl Sealed for crate::Netv4Addr {}
+	impl Sealed for crate::Netv6Addr {}
+}
+
+/// A network of a single address family
+///
+/// This abstracts over `Netv4Addr` and `Netv6Addr`, so that algorithms such as
+/// tries and aggregation can be written once for both.  `Addr` is the address
+/// type (`Ipv4Addr` or `Ipv6Addr`), and `Bits` is the unsigned integer with one
+/// bit per address bit (`u32` or `u128`), which addresses convert to and from.
+///
+/// This trait is sealed, and is implemented for `Netv4Addr` and `Netv6Addr`.
+/// `NetAddr` does not implement it, since its address type is not fixed; match
+/// on its variants to get at a family.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::{IpNetwork, Netv4Addr, Netv6Addr};
+/// /// Find the longest CIDR network containing both addresses.
+/// fn covering<N: IpNetwork>(a: N::Addr, b: N::Addr) -> N {
+///     let diff: N::Bits = a.into() ^ b.into();
+///     let mut prefix_len: u32 = N::BITS;
+///
+///     while prefix_len > 0 && diff >> (N::BITS - prefix_len) != N::Bits::from(0) {
+///         prefix_len -= 1;
+///     }
+///
+///     N::from_prefix_len(a, prefix_len).unwrap()
+/// }
+///
+/// let v4: Netv4Addr = covering("192.0.2.1".parse().unwrap(), "192.0.2.200".parse().unwrap());
+/// assert_eq!(v4, "192.0.2.0/24".parse().unwrap());
+///
+/// let v6: Netv6Addr = covering("2001:db8::1".parse().unwrap(), "2001:db8:1::".parse().unwrap());
+/// assert_eq!(v6, "2001:db8::/47".parse().unwrap());
+/// ```
+pub trait IpNetwork:
+	sealed::Sealed
+	+ Copy
+	+ Eq
+	+ Ord
+	+ Hash
+	+ Debug
+	+ Display
+	+ FromStr<Err = Error>
+	+ From<Self::Addr>
+	+ Contains<Self>
+	+ Contains<Self::Addr>
+	+ Merge<Output = Option<Self>>
+{
+	/// The type of the addresses in the network
+	type Addr: Copy + Eq + Ord + Hash + Debug + Display + From<Self::Bits> + Into<Self::Bits>;
+
+	/// The unsigned integer type with one bit per address bit
+	type Bits: Copy
+		+ Eq
+		+ Ord
+		+ Hash
+		+ Debug
+		+ Display
+		+ From<u8>
+		+ Into<u128>
+		+ TryFrom<u128>
+		+ BitAnd<Output = Self::Bits>
+		+ BitOr<Output = Self::Bits>
+		+ BitXor<Output = Self::Bits>
+		+ Not<Output = Self::Bits>
+		+ Shl<u32, Output = Self::Bits>
+		+ Shr<u32, Output = Self::Bits>;
+
+	/// The number of bits in an address
+	const BITS: u32;
+
+	/// Create a network from an address and a netmask, masking the address
+	fn new(addr: Self::Addr, mask: Self::Addr) -> Self;
+
+	/// Create a CIDR network from an address and a prefix length, masking the
+	/// address
+	///
+	/// Gives `None` if `prefix_len` is greater than [`IpNetwork::BITS`].
+	fn from_prefix_len(addr: Self::Addr, prefix_len: u32) -> Option<Self>;
+
+	/// Get the network address
+	fn addr(&self) -> Self::Addr;
+
+	/// Get the netmask
+	fn mask(&self) -> Self::Addr;
+
+	/// Get the prefix length, if the netmask is contiguous
+	fn prefix_len(&self) -> Option<u32>;
+
+	/// Determine whether the netmask is contiguous
+	fn is_cidr(&self) -> bool;
+
+	/// Get the number of host bits (zeroes) in the netmask
+	fn host_bits(&self) -> u32;
+
+	/// Get the last address in the network, whose host bits are all ones
+	fn last_addr(&self) -> Self::Addr;
+
+	/// Get the number of addresses in the network, or `None` if that does not
+	/// fit in [`IpNetwork::Bits`]
+	fn len(&self) -> Option<Self::Bits>;
+
+	/// Determine whether the network is empty, which it never is
+	fn is_empty(&self) -> bool {
+		false
+	}
+
+	/// Iterate over the addresses in the network, in increasing order
+	///
+	/// This visits every address, including with non-contiguous netmasks.
+	fn addresses(&self) -> Addresses<Self>;
+
+	/// Move forward by `count` networks of the same size, or give `None` if
+	/// that overflows
+	fn checked_add(self, count: Self::Bits) -> Option<Self>;
+
+	/// Move backward by `count` networks of the same size, or give `None` if
+	/// that overflows
+	fn checked_sub(self, count: Self::Bits) -> Option<Self>;
+
+	/// Get the next network of the same size, if there is one
+	fn checked_next(self) -> Option<Self> {
+		self.checked_add(Self::Bits::from(1))
+	}
+
+	/// Get the previous network of the same size, if there is one
+	fn checked_prev(self) -> Option<Self> {
+		self.checked_sub(Self::Bits::from(1))
+	}
+}
+
+/// An iterator over every address of an [`IpNetwork`], in increasing order.
+///
+/// Created by [`IpNetwork::addresses`].
+#[derive(Clone, Debug)]
+pub struct Addresses<N> {
+	net: N,
+	indices: core::ops::RangeInclusive<u128>,
+}
+
+impl<N: IpNetwork> Addresses<N> {
+	pub(crate) fn new(net: N, host_bits: u32) -> Self {
+		let last: u128 = u128::MAX.checked_shr(128 - host_bits).unwrap_or(0);
+
+		Self {
+			net,
+			indices: 0..=last,
+		}
+	}
+}
+
+macro_rules! addresses {
+	($network:ty) => {
+		impl Iterator for Addresses<$network> {
+			type Item = <$network as IpNetwork>::Addr;
+
+			fn next(&mut self) -> Option<Self::Item> {
+				self.indices.next().map(|index| self.net.host(index))
+			}
+
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				self.indices.size_hint()
+			}
+		}
+
+		impl DoubleEndedIterator for Addresses<$network> {
+			fn next_back(&mut self) -> Option<Self::Item> {
+				self.indices.next_back().map(|index| self.net.host(index))
+			}
+		}
+	};
+}
+
+addresses!(crate::Netv4Addr);
+addresses!(crate::Netv6Addr);
@@ -1,5 +1,5 @@
 use super::Mask;
-use std::net::IpAddr;
+use core::net::IpAddr;
 
 impl Mask for IpAddr {
 	type Output = Result<Self, &'static str>;
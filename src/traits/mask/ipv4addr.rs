@@ -1,5 +1,5 @@
 use super::Mask;
-use std::net::Ipv4Addr;
+use core::net::Ipv4Addr;
 
 impl Mask for Ipv4Addr {
 	type Output = Self;
@@ -1,5 +1,5 @@
 use super::Mask;
-use std::net::Ipv6Addr;
+use core::net::Ipv6Addr;
 
 impl Mask for Ipv6Addr {
 	type Output = Self;
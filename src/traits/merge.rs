@@ -6,3 +6,35 @@ pub trait Merge {
 	/// Perform the merging operation
 	fn merge(&self, other: &Self) -> Self::Output;
 }
+
+/// The result of merging two `NetAddr`s, which may be of different families.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MergeResult<T> {
+	/// The networks merged into the given network.
+	Merged(T),
+	/// The networks are of the same family, but cannot be merged into one.
+	NotMergeable,
+	/// The networks are of different families, so cannot be merged.
+	FamilyMismatch,
+}
+
+impl<T> MergeResult<T> {
+	/// Get the merged network, if there is one.
+	pub fn merged(self) -> Option<T> {
+		match self {
+			Self::Merged(merged) => Some(merged),
+			Self::NotMergeable | Self::FamilyMismatch => None,
+		}
+	}
+
+	/// Determine whether the networks were merged.
+	pub fn is_merged(&self) -> bool {
+		matches!(self, Self::Merged(_))
+	}
+}
+
+impl<T> From<MergeResult<T>> for Option<T> {
+	fn from(result: MergeResult<T>) -> Self {
+		result.merged()
+	}
+}
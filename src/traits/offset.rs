@@ -1,10 +1,28 @@
 use core::convert::TryInto;
 
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::{Netv4Addr, Netv6Addr};
 
+/// Move an address by a number of addresses
+///
+/// Offsetting a `Netv4Addr` or `Netv6Addr` moves its network address and then
+/// masks it again, so offsets smaller than the network leave it unchanged.  To
+/// move a network by whole networks, use its `checked_add` and `checked_sub`
+/// methods instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::Offset;
+/// # use std::net::Ipv4Addr;
+/// let addr: Ipv4Addr = "192.0.2.250".parse().unwrap();
+/// assert_eq!(addr.offset(5_u32), Some("192.0.2.255".parse().unwrap()));
+/// assert_eq!(addr.offset(-251_i32), Some("192.0.1.255".parse().unwrap()));
+/// assert_eq!(Ipv4Addr::BROADCAST.offset(1_u32), None);
+/// ```
 pub trait Offset<T>: Sized {
+	/// Move by `offset` addresses, or give `None` if that overflows
 	fn offset(&self, offset: T) -> Option<Self>;
 }
 
@@ -16,8 +34,6 @@ impl Offset<u128> for Ipv6Addr {
 
 impl Offset<i32> for Ipv6Addr {
 	fn offset(&self, offset: i32) -> Option<Self> {
-		use core::i32::{MAX, MIN};
-
 		// Without using weird BigInt types, we need to use absolute
 		// values and checked arithmetic.
 		let offset_abs: Option<u128> = offset.abs().try_into().ok();
@@ -25,8 +41,8 @@ impl Offset<i32> for Ipv6Addr {
 		// This is a simple "if positive, use checked_add, if negative, use checked_sub" conditional
 		offset_abs
 			.and_then(|abs: u128| match offset {
-				0..=MAX => u128::from(*self).checked_add(abs),
-				MIN..=-1 => u128::from(*self).checked_sub(abs),
+				0..=i32::MAX => u128::from(*self).checked_add(abs),
+				i32::MIN..=-1 => u128::from(*self).checked_sub(abs),
 			})
 			.map(Ipv6Addr::from)
 	}
@@ -0,0 +1,327 @@
+//! Parsing networks from command-line arguments with `clap`.
+
+use crate::{Error, NetAddr, NetList, Netv4Addr, Netv6Addr};
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, ValueHint};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use std::ffi::OsStr;
+
+/// A `clap` value parser for networks.
+///
+/// This is what `clap::value_parser!` picks for `NetAddr`, `Netv4Addr`,
+/// `Netv6Addr`, and `NetList`, so arguments of those types work out of the
+/// box.  It accepts exactly what the type's `FromStr` implementation does, and
+/// on failure reports the offending argument and value along with a hint at
+/// the notation that was expected.
+///
+/// A value parser cannot describe its values to shells or in `--help`; use
+/// [`NetworkArg::network`] to also set the value name, value hint, and help.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::NetAddr;
+/// use clap::{value_parser, Arg, Command};
+///
+/// let command = Command::new("route")
+///     .arg(Arg::new("destination").long("to").value_parser(value_parser!(NetAddr)));
+///
+/// let matches = command.clone().get_matches_from(["route", "--to", "10.0.0.0/8"]);
+/// assert_eq!(
+///     matches.get_one::<NetAddr>("destination"),
+///     Some(&"10.0.0.0/8".parse().unwrap()),
+/// );
+///
+/// let error = command.try_get_matches_from(["route", "--to", "zoop"]).unwrap_err();
+/// assert!(error.to_string().contains("invalid value 'zoop' for '--to <destination>'"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NetworkValueParser<N> {
+	what: &'static str,
+	examples: &'static str,
+	value_name: &'static str,
+	network: PhantomData<fn() -> N>,
+}
+
+impl<N> NetworkValueParser<N> {
+	const fn new(what: &'static str, examples: &'static str, value_name: &'static str) -> Self {
+		Self {
+			what,
+			examples,
+			value_name,
+			network: PhantomData,
+		}
+	}
+}
+
+impl<N> TypedValueParser for NetworkValueParser<N>
+where
+	N: FromStr<Err = Error> + Clone + Send + Sync + 'static,
+{
+	type Value = N;
+
+	fn parse_ref(
+		&self,
+		cmd: &Command,
+		arg: Option<&Arg>,
+		value: &OsStr,
+	) -> Result<Self::Value, clap::Error> {
+		let value = value
+			.to_str()
+			.ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+		value.parse().map_err(|error: Error| {
+			let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+
+			clap::Error::raw(
+				ErrorKind::ValueValidation,
+				format!(
+					"invalid value '{}' for '{}': {}\n\n  tip: expected {} like {}\n",
+					value, arg, error, self.what, self.examples
+				),
+			)
+			.with_cmd(cmd)
+		})
+	}
+}
+
+impl ValueParserFactory for NetAddr {
+	type Parser = NetworkValueParser<Self>;
+
+	fn value_parser() -> Self::Parser {
+		NetworkValueParser::new(
+			"a network",
+			"'192.0.2.0/24', '2001:db8::/32', or '10.0.0.0/255.0.0.0'",
+			"NETWORK",
+		)
+	}
+}
+
+impl ValueParserFactory for Netv4Addr {
+	type Parser = NetworkValueParser<Self>;
+
+	fn value_parser() -> Self::Parser {
+		NetworkValueParser::new(
+			"an IPv4 network",
+			"'192.0.2.0/24' or '10.0.0.0/255.0.0.0'",
+			"IPV4_NETWORK",
+		)
+	}
+}
+
+impl ValueParserFactory for Netv6Addr {
+	type Parser = NetworkValueParser<Self>;
+
+	fn value_parser() -> Self::Parser {
+		NetworkValueParser::new(
+			"an IPv6 network",
+			"'2001:db8::/32' or '2001:db8::/ffff:ffff::'",
+			"IPV6_NETWORK",
+		)
+	}
+}
+
+impl<N> ValueParserFactory for NetList<N>
+where
+	N: FromStr<Err = Error> + Clone + Send + Sync + 'static,
+{
+	type Parser = NetworkValueParser<Self>;
+
+	fn value_parser() -> Self::Parser {
+		NetworkValueParser::new(
+			"comma-separated networks",
+			"'10.0.0.0/8,2001:db8::/32'",
+			"NETWORKS",
+		)
+	}
+}
+
+/// Configuring a `clap` argument to take a network.
+pub trait NetworkArg {
+	/// Parse the argument's values as `N`, and describe them: the value name
+	/// says what kind of network is expected, the value hint keeps shells from
+	/// completing file names, and the long help lists example values after any
+	/// help already given.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use netaddr2::{NetAddr, NetworkArg};
+	/// use clap::{Arg, Command, ValueHint};
+	///
+	/// let arg = Arg::new("destination")
+	///     .long("to")
+	///     .help("Where to route")
+	///     .network::<NetAddr>();
+	///
+	/// assert_eq!(arg.get_value_hint(), ValueHint::Other);
+	///
+	/// let help = Command::new("route").arg(arg).render_long_help().to_string();
+	/// assert!(help.contains("--to <NETWORK>"));
+	/// assert!(help.contains("Where to route"));
+	/// assert!(help.contains("Accepts a network like '192.0.2.0/24'"));
+	/// ```
+	fn network<N>(self) -> Self
+	where
+		N: ValueParserFactory<Parser = NetworkValueParser<N>>
+			+ FromStr<Err = Error>
+			+ Clone
+			+ Send
+			+ Sync
+			+ 'static;
+}
+
+impl NetworkArg for Arg {
+	fn network<N>(self) -> Self
+	where
+		N: ValueParserFactory<Parser = NetworkValueParser<N>>
+			+ FromStr<Err = Error>
+			+ Clone
+			+ Send
+			+ Sync
+			+ 'static,
+	{
+		let parser: NetworkValueParser<N> = N::value_parser();
+		let accepts: String = format!("Accepts {} like {}.", parser.what, parser.examples);
+		let help: String = match self.get_long_help().or_else(|| self.get_help()) {
+			Some(help) => format!("{}\n\n{}", help, accepts),
+			None => accepts,
+		};
+
+		self
+			.value_name(parser.value_name)
+			.value_hint(ValueHint::Other)
+			.long_help(help)
+			.value_parser(parser)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::value_parser;
+
+	fn command() -> Command {
+		Command::new("test")
+			.arg(
+				Arg::new("net")
+					.long("net")
+					.value_parser(value_parser!(NetAddr)),
+			)
+			.arg(
+				Arg::new("v4")
+					.long("v4")
+					.value_parser(value_parser!(Netv4Addr)),
+			)
+			.arg(
+				Arg::new("v6")
+					.long("v6")
+					.value_parser(value_parser!(Netv6Addr)),
+			)
+			.arg(
+				Arg::new("list")
+					.long("list")
+					.value_parser(value_parser!(NetList<Netv4Addr>)),
+			)
+	}
+
+	#[test]
+	fn parses_each_type() {
+		let matches = command().get_matches_from([
+			"test",
+			"--net",
+			"2001:db8::/32",
+			"--v4",
+			"10.0.0.0/255.0.255.0",
+			"--v6",
+			"::1/128",
+			"--list",
+			"10.0.0.0/8, 192.0.2.0/24",
+		]);
+
+		assert_eq!(
+			matches.get_one::<NetAddr>("net"),
+			Some(&pu!("2001:db8::/32" # NetAddr))
+		);
+		assert_eq!(
+			matches.get_one::<Netv4Addr>("v4"),
+			Some(&pu!("10.0.0.0/255.0.255.0" # Netv4Addr))
+		);
+		assert_eq!(
+			matches.get_one::<Netv6Addr>("v6"),
+			Some(&pu!("::1/128" # Netv6Addr))
+		);
+		assert_eq!(
+			matches
+				.get_one::<NetList<Netv4Addr>>("list")
+				.map(|list| list.len()),
+			Some(2)
+		);
+	}
+
+	#[test]
+	fn reports_argument_value_and_hint() {
+		let error = command()
+			.try_get_matches_from(["test", "--v6", "10.0.0.0/8"])
+			.unwrap_err();
+		let message = error.to_string();
+
+		assert_eq!(error.kind(), ErrorKind::ValueValidation);
+		assert!(message.contains("invalid value '10.0.0.0/8' for '--v6 <v6>': unable to parse address"));
+		assert!(message.contains("tip: expected an IPv6 network like '2001:db8::/32'"));
+	}
+
+	#[test]
+	fn reports_invalid_list_entries() {
+		let error = command()
+			.try_get_matches_from(["test", "--list", "10.0.0.0/8,zoop"])
+			.unwrap_err();
+
+		assert_eq!(error.kind(), ErrorKind::ValueValidation);
+		assert!(error
+			.to_string()
+			.contains("tip: expected comma-separated networks"));
+	}
+
+	#[test]
+	fn network_args_describe_values() {
+		let command = Command::new("test")
+			.arg(Arg::new("v6").long("v6").network::<Netv6Addr>())
+			.arg(Arg::new("list").long("list").network::<NetList<NetAddr>>());
+
+		let v6 = command.get_arguments().next().unwrap();
+		assert_eq!(v6.get_value_hint(), ValueHint::Other);
+		assert_eq!(
+			v6.get_value_names().map(|names| names[0].as_str()),
+			Some("IPV6_NETWORK")
+		);
+
+		let help = command.clone().render_long_help().to_string();
+		assert!(help.contains("--list <NETWORKS>"));
+		assert!(help.contains("Accepts comma-separated networks like '10.0.0.0/8,2001:db8::/32'."));
+
+		let matches = command.get_matches_from(["test", "--v6", "::1/128"]);
+		assert_eq!(
+			matches.get_one::<Netv6Addr>("v6"),
+			Some(&pu!("::1/128" # Netv6Addr))
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn rejects_invalid_utf8() {
+		use std::os::unix::ffi::OsStrExt;
+
+		let error = command()
+			.try_get_matches_from([
+				OsStr::new("test"),
+				OsStr::new("--net"),
+				OsStr::from_bytes(b"\xff"),
+			])
+			.unwrap_err();
+
+		assert_eq!(error.kind(), ErrorKind::InvalidUtf8);
+	}
+}
@@ -0,0 +1,229 @@
+use crate::{Error, Result};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// The zone of a scoped IPv6 address, which says which link a link-local
+/// address is on.
+///
+/// A zone is identified either by an interface index, like the `3` in
+/// `fe80::1%3`, or by an interface name, like the `eth0` in `fe80::1%eth0`.
+/// Zones are compared as written: an index and a name are always different
+/// zones, even if they name the same interface, since resolving one to the
+/// other depends on the host.
+///
+/// Names are stored inline, so a `ZoneId` is `Copy` and does not allocate;
+/// they may be at most [`ZoneId::MAX_NAME_LEN`] bytes long, which covers the
+/// interface names of common operating systems.
+///
+/// # Examples
+///
+/// ```rust
+/// # use netaddr2::ZoneId;
+/// let index: ZoneId = "3".parse().unwrap();
+/// assert_eq!(index.index(), Some(3));
+///
+/// let name: ZoneId = "eth0".parse().unwrap();
+/// assert_eq!(name.name(), Some("eth0"));
+/// assert_ne!(index, name);
+/// ```
+#[derive(Copy, Clone)]
+pub struct ZoneId(Repr);
+
+#[derive(Copy, Clone)]
+enum Repr {
+	Index(u32),
+	Name {
+		len: u8,
+		bytes: [u8; ZoneId::MAX_NAME_LEN],
+	},
+}
+
+impl ZoneId {
+	/// The longest interface name a `ZoneId` can hold, in bytes.
+	pub const MAX_NAME_LEN: usize = 32;
+
+	/// Create a `ZoneId` from an interface index.
+	pub const fn from_index(index: u32) -> Self {
+		Self(Repr::Index(index))
+	}
+
+	/// Create a `ZoneId` from an interface name.
+	///
+	/// # Errors
+	///
+	/// Fails if `name` is empty, longer than [`ZoneId::MAX_NAME_LEN`] bytes, or
+	/// contains a `%`, `/`, space, or control character, any of which would
+	/// make a scoped address ambiguous to parse.
+	pub fn from_name(name: &str) -> Result<Self> {
+		if name.is_empty() || name.len() > Self::MAX_NAME_LEN {
+			return Err(Error::ParseError("zone ID has an invalid length"));
+		}
+
+		if name
+			.chars()
+			.any(|c| c == '%' || c == '/' || c == ' ' || c.is_control())
+		{
+			return Err(Error::ParseError("zone ID contains an invalid character"));
+		}
+
+		let mut bytes: [u8; Self::MAX_NAME_LEN] = [0; Self::MAX_NAME_LEN];
+		bytes[..name.len()].copy_from_slice(name.as_bytes());
+
+		Ok(Self(Repr::Name {
+			len: name.len() as u8,
+			bytes,
+		}))
+	}
+
+	/// Get the interface index, if the zone is identified by one.
+	pub const fn index(&self) -> Option<u32> {
+		match self.0 {
+			Repr::Index(index) => Some(index),
+			Repr::Name { .. } => None,
+		}
+	}
+
+	/// Get the interface name, if the zone is identified by one.
+	pub fn name(&self) -> Option<&str> {
+		match &self.0 {
+			Repr::Index(_) => None,
+			Repr::Name { len, bytes } => core::str::from_utf8(&bytes[..usize::from(*len)]).ok(),
+		}
+	}
+
+	/// Get a key for comparison, with indices before names.
+	fn key(&self) -> (Option<u32>, Option<&str>) {
+		(self.index(), self.name())
+	}
+}
+
+impl PartialEq for ZoneId {
+	fn eq(&self, other: &Self) -> bool {
+		self.key() == other.key()
+	}
+}
+
+impl Eq for ZoneId {}
+
+impl PartialOrd for ZoneId {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for ZoneId {
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self.index(), other.index()) {
+			(Some(index), Some(other)) => index.cmp(&other),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => self.name().cmp(&other.name()),
+		}
+	}
+}
+
+impl Hash for ZoneId {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.key().hash(state);
+	}
+}
+
+impl fmt::Debug for ZoneId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match (self.index(), self.name()) {
+			(Some(index), _) => f.debug_tuple("ZoneId").field(&index).finish(),
+			(_, name) => f
+				.debug_tuple("ZoneId")
+				.field(&name.unwrap_or_default())
+				.finish(),
+		}
+	}
+}
+
+impl fmt::Display for ZoneId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match (self.index(), self.name()) {
+			(Some(index), _) => fmt::Display::fmt(&index, f),
+			(_, name) => f.write_str(name.unwrap_or_default()),
+		}
+	}
+}
+
+impl FromStr for ZoneId {
+	type Err = Error;
+
+	/// Parse a `ZoneId`, as an interface index if it is a decimal number that
+	/// fits in a `u32`, or as an interface name otherwise.
+	fn from_str(string: &str) -> Result<Self> {
+		let is_index: bool = !string.is_empty() && string.bytes().all(|byte| byte.is_ascii_digit());
+
+		match string.parse::<u32>() {
+			Ok(index) if is_index => Ok(Self::from_index(index)),
+			_ => Self::from_name(string),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_indices_and_names() {
+		assert_eq!("3".parse::<ZoneId>().map(|zone| zone.index()), Ok(Some(3)));
+		assert_eq!(
+			"eth0"
+				.parse::<ZoneId>()
+				.map(|zone| zone.name().map(str::len)),
+			Ok(Some(4))
+		);
+		assert_eq!(
+			"+3".parse::<ZoneId>().map(|zone| zone.index()),
+			Ok(None),
+			"a sign makes it a name"
+		);
+		assert_eq!(
+			"99999999999".parse::<ZoneId>().map(|zone| zone.index()),
+			Ok(None),
+			"too large to be an index"
+		);
+	}
+
+	#[test]
+	fn rejects_invalid_names() {
+		assert!("".parse::<ZoneId>().is_err());
+		assert!("eth0/64".parse::<ZoneId>().is_err());
+		assert!("eth%0".parse::<ZoneId>().is_err());
+		assert!("eth\n".parse::<ZoneId>().is_err());
+		assert!("x".repeat(ZoneId::MAX_NAME_LEN).parse::<ZoneId>().is_ok());
+		assert!("x"
+			.repeat(ZoneId::MAX_NAME_LEN + 1)
+			.parse::<ZoneId>()
+			.is_err());
+	}
+
+	#[test]
+	fn compares() {
+		let zone = |string: &str| string.parse::<ZoneId>().unwrap();
+
+		assert_eq!(zone("eth0"), zone("eth0"));
+		assert_ne!(zone("eth0"), zone("eth1"));
+		assert_ne!(zone("eth0"), zone("ETH0"));
+		assert_eq!(zone("1"), zone("01"));
+		assert!(zone("2") < zone("10"));
+		assert!(zone("10") < zone("eth0"));
+	}
+
+	#[test]
+	fn displays_as_written() {
+		assert_eq!(ZoneId::from_index(3).to_string(), "3");
+		assert_eq!(ZoneId::from_name("wlan0").unwrap().to_string(), "wlan0");
+		assert_eq!(format!("{:?}", ZoneId::from_index(3)), "ZoneId(3)");
+		assert_eq!(
+			format!("{:?}", ZoneId::from_name("wlan0").unwrap()),
+			"ZoneId(\"wlan0\")"
+		);
+	}
+}
@@ -0,0 +1,6 @@
+iptables -A allowed -s 10.0.0.0/8 -j ACCEPT
+iptables -A allowed -s 192.0.2.1/32 -j ACCEPT
+iptables -A allowed -s 198.51.100.0/24 -j ACCEPT
+iptables -A allowed -s 0.0.0.0/0 -j ACCEPT
+ip6tables -A allowed -s 2001:db8::/32 -j ACCEPT
+ip6tables -A allowed -s ::1/128 -j ACCEPT